@@ -0,0 +1,19 @@
+//! Creates constraints from an arbitrary word and color code and parses arbitrary input in
+//! the textual form of `ConstraintSet`.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use prompter::{merge::MergedConstraints, ConstraintSet, Word};
+
+fuzz_target!(|input: (&str, &str)| {
+    let (word, code) = input;
+
+    if let Ok(constraints) = ConstraintSet::try_from((word, code)) {
+        let _ = constraints.validate();
+        let _ = constraints.mismatch(&Word::from(code));
+        let _ = MergedConstraints::new(word.chars().count()).merge(&constraints);
+        let _ = constraints.to_string().parse::<ConstraintSet>();
+    }
+
+    let _ = word.parse::<ConstraintSet>();
+});
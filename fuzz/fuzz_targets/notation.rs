@@ -0,0 +1,16 @@
+//! Parses arbitrary input in the compact notation (`crate=Y_G_G`, `crate/10202`).
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use prompter::notation::Turn;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    // A valid turn is written in a form that is parsed to the same turn.
+    if let Ok(turn) = s.parse::<Turn>() {
+        assert_eq!(turn.to_string().parse::<Turn>(), Ok(turn));
+    }
+});
@@ -0,0 +1,18 @@
+//! Compares arbitrary words, which may differ in length and contain any characters.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use prompter::{ConstraintSet, Word};
+
+fuzz_target!(|input: (&str, &str)| {
+    let (guess, target) = (Word::from(input.0), Word::from(input.1));
+    let constraints = ConstraintSet::from_guess(&guess, &target);
+
+    let _ = guess.match_code(&target);
+    let _ = constraints.validate();
+
+    // The target always matches the feedback for it.
+    if guess.chars().count() == target.chars().count() {
+        assert!(constraints.is_match(&target));
+    }
+});
@@ -0,0 +1,78 @@
+#ifndef PROMPTER_H
+#define PROMPTER_H
+
+/* Generated with cbindgen from src/ffi.rs. Do not edit manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * An opaque handle to a solver.
+ */
+typedef struct PrompterSolver PrompterSolver;
+
+/**
+ * Creates a solver for the preset with the given name (e.g. `wordle`), or for the default
+ * preset if `preset` is `NULL`. Returns `NULL` if the preset is unknown or the solver
+ * cannot be created.
+ *
+ * # Safety
+ *
+ * `preset` must be `NULL` or point to a NUL-terminated string.
+ */
+struct PrompterSolver *prompter_solver_new(const char *preset);
+
+/**
+ * Releases a solver created with [`prompter_solver_new`]. Does nothing if `solver` is
+ * `NULL`.
+ *
+ * # Safety
+ *
+ * `solver` must be `NULL` or a pointer returned by [`prompter_solver_new`] that has not
+ * been released yet.
+ */
+void prompter_solver_free(struct PrompterSolver *solver);
+
+/**
+ * Applies Wordle's color `code` (e.g. `Y_G_G`) for `guess`. Returns 0 on success and -1
+ * if the input is invalid or applying it fails, in which case the solver is unchanged.
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver, `guess` and `code` must point to NUL-terminated
+ * strings.
+ */
+int prompter_solver_apply(struct PrompterSolver *solver, const char *guess, const char *code);
+
+/**
+ * Returns the number of words that are still possible.
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver.
+ */
+size_t prompter_solver_candidate_count(const struct PrompterSolver *solver);
+
+/**
+ * Returns the `n` best-ranked candidates, separated by newlines, or `NULL` if ranking them
+ * fails. The string must be released with [`prompter_string_free`].
+ *
+ * # Safety
+ *
+ * `solver` must be a valid solver.
+ */
+char *prompter_solver_suggest(const struct PrompterSolver *solver, size_t n);
+
+/**
+ * Releases a string returned by the library. Does nothing if `s` is `NULL`.
+ *
+ * # Safety
+ *
+ * `s` must be `NULL` or a string returned by the library that has not been released yet.
+ */
+void prompter_string_free(char *s);
+
+#endif  /* PROMPTER_H */
@@ -0,0 +1,150 @@
+//! Symbols and validity rules for the "words" of a Wordle variant.
+//!
+//! Words and user input are converted to lower case one character at a time with
+//! [`lowercase`] or [`Alphabet::fold`] rather than with [`str::to_lowercase`], which turns
+//! some characters into several (e.g. `İ` into `i` and a combining dot). A word therefore
+//! never changes its length, and the input always uses the same characters as the words.
+use crate::Error;
+
+/// Returns `c` in lower case, or its first character if its lower case has several (`i`
+/// for `İ`).
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::alphabet::lowercase;
+/// assert_eq!(lowercase('A'), 'a');
+/// assert_eq!(lowercase('İ'), 'i');
+/// assert_eq!(lowercase('ß'), 'ß');
+/// ```
+pub fn lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Returns `word` with each character converted with [`lowercase`], so that it has as many
+/// characters as before.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::alphabet::lowercase_word;
+/// assert_eq!(lowercase_word("CRATE"), "crate");
+/// assert_eq!(lowercase_word("İLLER"), "iller");
+/// ```
+pub fn lowercase_word(word: &str) -> String {
+    word.chars().map(lowercase).collect()
+}
+
+/// Returns `c` in upper case, or `c` itself if its upper case has several characters (`ß`,
+/// whose upper case is `SS`).
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::alphabet::uppercase;
+/// assert_eq!(uppercase('g'), 'G');
+/// assert_eq!(uppercase('ß'), 'ß');
+/// ```
+pub fn uppercase(c: char) -> char {
+    let mut upper = c.to_uppercase();
+
+    match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => c,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// The symbols that a guess may consist of, together with a rule that a guess must satisfy
+/// (e.g. being a well-formed and true equation in Nerdle)
+pub struct Alphabet {
+    /// All symbols that may be used in a guess.
+    symbols: &'static str,
+    /// Returns `true` if a guess consisting only of valid symbols is acceptable.
+    rule: fn(&str) -> bool,
+}
+
+impl Alphabet {
+    /// The lowercase letters `a` to `z` without any further rules.
+    pub const LATIN: Alphabet = Alphabet::new("abcdefghijklmnopqrstuvwxyz", |_| true);
+
+    /// Creates an alphabet from the given `symbols` and validity `rule`.
+    pub const fn new(symbols: &'static str, rule: fn(&str) -> bool) -> Self {
+        Self { symbols, rule }
+    }
+
+    /// Returns all symbols of the alphabet.
+    pub fn symbols(&self) -> &'static str {
+        self.symbols
+    }
+
+    /// Returns `true` if `c` is a symbol of the alphabet.
+    pub fn contains(&self, c: char) -> bool {
+        self.symbols.contains(c)
+    }
+
+    /// Returns the symbol of the alphabet that `c` stands for: `c` itself if it is a symbol,
+    /// otherwise its [`lowercase`]. In alphabets with the dotless `ı` (as in Turkish), `I`
+    /// stands for `ı` and `İ` for `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::alphabet::Alphabet;
+    /// let turkish = Alphabet::new("abcçdefgğhıijklmnoöprsştuüvyz", |_| true);
+    /// assert_eq!(turkish.fold('İ'), 'i');
+    /// assert_eq!(turkish.fold('I'), 'ı');
+    /// assert_eq!(Alphabet::LATIN.fold('I'), 'i');
+    /// ```
+    pub fn fold(&self, c: char) -> char {
+        if self.contains(c) {
+            return c;
+        }
+
+        match lowercase(c) {
+            'i' if c == 'I' && self.contains('ı') => 'ı',
+            lower => lower,
+        }
+    }
+
+    /// Returns `word` with each character replaced by the symbol it stands for (see
+    /// [`Alphabet::fold`]), so that it has as many characters as before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::alphabet::Alphabet;
+    /// assert_eq!(Alphabet::LATIN.normalize("CRATE"), "crate");
+    /// assert_eq!(Alphabet::LATIN.normalize("İLLER").chars().count(), 5);
+    /// ```
+    pub fn normalize(&self, word: &str) -> String {
+        word.chars().map(|c| self.fold(c)).collect()
+    }
+
+    /// Checks that `guess` only consists of symbols of the alphabet and satisfies the rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::alphabet::Alphabet;
+    /// assert!(Alphabet::LATIN.validate("crate").is_ok());
+    /// assert!(Alphabet::LATIN.validate("cr4te").is_err());
+    /// ```
+    pub fn validate(&self, guess: &str) -> Result<(), Error> {
+        if let Some(c) = guess.chars().find(|c| !self.contains(*c)) {
+            return Err(Error::InvalidSymbol(c));
+        }
+
+        if !(self.rule)(guess) {
+            return Err(Error::InvalidGuess(guess.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Self::LATIN
+    }
+}
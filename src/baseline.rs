@@ -0,0 +1,192 @@
+//! Results of simulations saved with `simulate --save-baseline FILE`, against which later
+//! runs are compared with `simulate --baseline FILE` to catch regressions, e.g. after
+//! changing a strategy.
+//!
+//! The file contains the results for each start word:
+//!
+//! ```toml
+//! [[openers]]
+//! start = "crate"
+//! games = 2314
+//! won = 2298
+//! rounds = 8114
+//! ```
+//!
+//! `rounds` is the total number of rounds of the games that were won.
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// The results of a simulation for each start word
+pub struct Baseline {
+    /// The results in the order in which the start words were played.
+    pub openers: Vec<Summary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// The results of all games with one start word
+pub struct Summary {
+    /// The start word.
+    pub start: String,
+    /// Number of games played.
+    pub games: usize,
+    /// Number of games won.
+    pub won: usize,
+    /// Total number of rounds of the games won.
+    pub rounds: usize,
+}
+
+impl Summary {
+    /// Returns the number of games lost.
+    pub fn lost(&self) -> usize {
+        self.games - self.won
+    }
+
+    /// Returns the average number of rounds of the games won.
+    pub fn avg_rounds(&self) -> f32 {
+        self.rounds as f32 / self.won as f32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A start word for which the results are worse than in the baseline
+pub enum Regression<'a> {
+    /// More games were lost.
+    Losses {
+        before: &'a Summary,
+        after: &'a Summary,
+    },
+    /// The games that were won needed more rounds on average.
+    AvgRounds {
+        before: &'a Summary,
+        after: &'a Summary,
+    },
+    /// A different number of games was played, so the results cannot be compared.
+    Games {
+        before: &'a Summary,
+        after: &'a Summary,
+    },
+}
+
+impl Baseline {
+    /// Loads the baseline saved at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Saves the baseline to `path`, replacing the file if it exists.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+        let content = toml::to_string(self).map_err(|e| e.to_string())?;
+
+        fs::write(path, content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Returns the start words in `openers` whose results are worse than in the baseline.
+    /// Start words that are not in the baseline are skipped.
+    pub fn compare<'a>(&'a self, openers: &'a [Summary]) -> Vec<Regression<'a>> {
+        let mut regressions = vec![];
+
+        for after in openers {
+            let Some(before) = self.openers.iter().find(|s| s.start == after.start) else {
+                continue;
+            };
+
+            if before.games != after.games {
+                regressions.push(Regression::Games { before, after });
+                continue;
+            }
+
+            if after.lost() > before.lost() {
+                regressions.push(Regression::Losses { before, after });
+            }
+
+            // The averages are compared as fractions to avoid rounding errors.
+            if after.rounds * before.won > before.rounds * after.won {
+                regressions.push(Regression::AvgRounds { before, after });
+            }
+        }
+
+        regressions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(start: &str, games: usize, won: usize, rounds: usize) -> Summary {
+        Summary {
+            start: start.to_string(),
+            games,
+            won,
+            rounds,
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let baseline = Baseline {
+            openers: vec![summary("crate", 2314, 2298, 8124)],
+        };
+
+        let content = toml::to_string(&baseline).unwrap();
+
+        assert!(content.contains("[[openers]]\nstart = \"crate\""));
+        assert_eq!(toml::from_str::<Baseline>(&content).unwrap(), baseline);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline {
+            openers: vec![
+                summary("crate", 100, 98, 350),
+                summary("trace", 100, 99, 350),
+                summary("slate", 100, 99, 350),
+            ],
+        };
+
+        let openers = [
+            // Better in both respects.
+            summary("crate", 100, 99, 350),
+            // One game more lost, but fewer rounds on average.
+            summary("trace", 100, 98, 340),
+            // Played against a different number of words.
+            summary("slate", 50, 50, 175),
+            // Not in the baseline.
+            summary("roate", 100, 90, 400),
+        ];
+
+        let regressions = baseline.compare(&openers);
+
+        assert_eq!(
+            regressions,
+            [
+                Regression::Losses {
+                    before: &baseline.openers[1],
+                    after: &openers[1]
+                },
+                Regression::Games {
+                    before: &baseline.openers[2],
+                    after: &openers[2]
+                }
+            ]
+        );
+
+        let worse = [summary("crate", 100, 98, 351)];
+
+        assert_eq!(
+            baseline.compare(&worse),
+            [Regression::AvgRounds {
+                before: &baseline.openers[0],
+                after: &worse[0]
+            }]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! A cache of precomputed tables: the rankings of many candidates, e.g. of all words for the
+//! first guess, and the pattern matrices written by `prompter matrix`.
+//!
+//! Each table is stored in a file of its own in the cache directory
+//! (`~/.cache/prompter` or the platform's equivalent). The name of the file is a [`Key`]: a
+//! hash of everything the table depends on, e.g. the words of the wordlist and the strategy.
+//! A table is therefore computed again as soon as one of its inputs changes. Each file
+//! starts with a header line with the version of the engine that wrote it. The version is
+//! not part of the key, so a table written by another version is treated as stale and
+//! overwritten in the same file.
+#[cfg(feature = "std-fs")]
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::Wordlist;
+
+/// Version of the engine, written in the header of every table.
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Initial value of the FNV-1a hash.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Multiplier of the FNV-1a hash.
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Continues the 64-bit FNV-1a hash `hash` with `bytes`.
+fn fnv1a64_extend(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Returns the 64-bit FNV-1a hash of `bytes`. It is fast and stable across platforms and
+/// versions, but not suitable for cryptography.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::cache::fnv1a64;
+/// assert_eq!(fnv1a64(b""), 0xcbf2_9ce4_8422_2325);
+/// assert_eq!(fnv1a64(b"a"), 0xaf63_dc4c_8601_ec8c);
+/// ```
+pub fn fnv1a64(bytes: &[u8]) -> u64 {
+    fnv1a64_extend(FNV_OFFSET, bytes)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The name of a table in the cache, made from its kind and a hash of its inputs
+pub struct Key {
+    /// Kind of the table, e.g. `ranking`.
+    kind: &'static str,
+    /// Hash of the inputs.
+    hash: u64,
+}
+
+impl Key {
+    /// Returns the key of a table of `kind` without inputs (yet).
+    pub fn new(kind: &'static str) -> Self {
+        Self {
+            kind,
+            hash: FNV_OFFSET,
+        }
+    }
+
+    /// Adds an input of the table, e.g. the name of the strategy.
+    pub fn with(mut self, input: &str) -> Self {
+        // The separator keeps e.g. ("ab", "c") and ("a", "bc") apart.
+        self.hash = fnv1a64_extend(self.hash, input.as_bytes());
+        self.hash = fnv1a64_extend(self.hash, &[0xff]);
+        self
+    }
+
+    /// Adds the words of `wordlist` in their order as an input of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{cache::Key, Word, Wordlist};
+    /// let words: Wordlist = ["crate", "trace"].iter().map(Word::from).collect();
+    /// let reversed: Wordlist = ["trace", "crate"].iter().map(Word::from).collect();
+    ///
+    /// assert_eq!(Key::new("ranking").with_words(&words), Key::new("ranking").with_words(&words));
+    /// assert_ne!(Key::new("ranking").with_words(&words), Key::new("ranking").with_words(&reversed));
+    /// assert_ne!(Key::new("ranking").with_words(&words), Key::new("matrix").with_words(&words));
+    /// ```
+    pub fn with_words(self, wordlist: &Wordlist) -> Self {
+        wordlist.iter().fold(self, |key, w| key.with(&w.0))
+    }
+
+    /// Returns the name of the file of the table.
+    pub fn file_name(&self) -> String {
+        format!("{}-{:016x}", self.kind, self.hash)
+    }
+}
+
+#[cfg(feature = "std-fs")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The directory in which the tables are cached
+pub struct Cache {
+    /// The cache directory.
+    dir: PathBuf,
+}
+
+#[cfg(feature = "std-fs")]
+impl Cache {
+    /// Returns the default cache directory.
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| dir.join("prompter"))
+    }
+
+    /// Returns the cache in `dir`.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the header line of the files written by this version of the engine.
+    fn header() -> String {
+        format!("prompter-cache {}\n", ENGINE_VERSION)
+    }
+
+    /// Returns the table with `key`, or `None` if it has not been cached yet, cannot be
+    /// read or is stale.
+    pub fn load(&self, key: &Key) -> Option<String> {
+        let mut content = fs::read_to_string(self.dir.join(key.file_name())).ok()?;
+        let header = Self::header();
+
+        if !content.starts_with(&header) {
+            return None;
+        }
+
+        // The header is removed in place, as tables such as matrices can be large.
+        content.drain(..header.len());
+        Some(content)
+    }
+
+    /// Stores `table` with `key`, replacing a stale one. The table is written to a
+    /// temporary file first, so that it is never read while it is only partially written.
+    pub fn store(&self, key: &Key, table: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let path = self.dir.join(key.file_name());
+        let tmp = path.with_extension("tmp");
+
+        fs::write(&tmp, Self::header() + table)?;
+        fs::rename(tmp, path)
+    }
+
+    /// Removes all cached tables and returns their number.
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+
+        let mut removed = 0;
+
+        for entry in entries {
+            let path = entry?.path();
+
+            if path.is_file() {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+}
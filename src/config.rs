@@ -0,0 +1,240 @@
+//! Defaults for the command line options, read from a TOML file.
+//!
+//! The file is located at `~/.config/prompter/config.toml` (or the platform's equivalent),
+//! or at `~/.config/prompter/profiles/NAME.toml` with `--profile NAME`, and may contain any
+//! of the following keys:
+//!
+//! ```toml
+//! preset = "wordle"
+//! list = "nyt"
+//! spelling = "british"
+//! strategy = "lookahead:k=5"
+//! suggest = "mixed"
+//! tiebreak = "likely"
+//! demote_inflections = true
+//! family_friendly = true
+//! wordlist = "/path/to/words.txt"
+//! sort_wordlist = true
+//! exclude_tags = ["archaic"]
+//! frequencies = "/path/to/frequencies.txt"
+//! answer_weight = 10.0
+//! used_answers = "/path/to/used.txt"
+//! repeat_policy = "downweight"
+//! lang = "de"
+//! hard = true
+//! record = "/path/to/transcripts"
+//! suggestions = 5
+//! ascii = true
+//! gray = "BX-.0"
+//!
+//! [alias]
+//! s = "suggest --strategy entropy --top 5"
+//! ```
+//!
+//! Options given on the command line take precedence over the file. An alias stands for a
+//! subcommand with options, which are separated by whitespace; it is only used if there is
+//! no subcommand of the same name.
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use prompter::{
+    i18n::Lang,
+    preset::{AnswerList, Preset},
+    spelling::Spelling,
+    strategy::{Pool, Strategy, TieBreak},
+};
+use serde::{de, Deserialize, Deserializer};
+
+use crate::RepeatPolicy;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+/// The settings in the config file
+pub struct Config {
+    /// Variant of Wordle.
+    #[serde(deserialize_with = "from_str")]
+    pub preset: Option<Preset>,
+    /// Revision of Wordle's answers.
+    #[serde(deserialize_with = "from_str")]
+    pub list: Option<AnswerList>,
+    /// Spelling of the words.
+    #[serde(deserialize_with = "from_str")]
+    pub spelling: Option<Spelling>,
+    /// Whether to try to find or to avoid the word.
+    #[serde(deserialize_with = "from_str")]
+    pub strategy: Option<Strategy>,
+    /// Words that may be suggested.
+    #[serde(deserialize_with = "from_str")]
+    pub suggest: Option<Pool>,
+    /// Order of equally good suggestions.
+    #[serde(deserialize_with = "from_str")]
+    pub tiebreak: Option<TieBreak>,
+    /// Whether inflected forms come last among equally good suggestions.
+    pub demote_inflections: bool,
+    /// Whether offensive words are left out of the suggestions.
+    pub family_friendly: bool,
+    /// File with the words to use instead of the preset's wordlist.
+    pub wordlist: Option<PathBuf>,
+    /// Whether to sort the words of the wordlist file.
+    pub sort_wordlist: bool,
+    /// Tags of the words to leave out of the wordlist file.
+    pub exclude_tags: Vec<String>,
+    /// File with the weight of each word.
+    pub frequencies: Option<PathBuf>,
+    /// Factor by which the weight of the preset's likely answers is multiplied.
+    #[serde(deserialize_with = "positive")]
+    pub answer_weight: Option<f64>,
+    /// File with the answers of previous games.
+    pub used_answers: Option<PathBuf>,
+    /// How the answers of previous games are treated.
+    pub repeat_policy: Option<RepeatPolicy>,
+    /// Language of the messages.
+    #[serde(deserialize_with = "from_str")]
+    pub lang: Option<Lang>,
+    /// Whether to only accept guesses that match all previous hints.
+    pub hard: bool,
+    /// Directory in which the transcripts of the games are recorded.
+    pub record: Option<PathBuf>,
+    /// Number of words suggested in each round.
+    pub suggestions: Option<usize>,
+    /// Whether to mark the feedback with symbols instead of colors.
+    pub ascii: bool,
+    /// Characters accepted for gray in color codes besides `_`.
+    pub gray: Option<String>,
+    /// Names that stand for a subcommand with options, e.g. `s = "suggest --top 5"`.
+    pub alias: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Returns the default location of the config file, or that of the given profile.
+    pub fn default_path(profile: Option<&str>) -> Option<PathBuf> {
+        let dir = dirs::config_dir()?.join("prompter");
+
+        Some(match profile {
+            Some(name) => dir.join("profiles").join(format!("{}.toml", name)),
+            None => dir.join("config.toml"),
+        })
+    }
+
+    /// Loads the config file at `path`. A missing file results in the default config.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    }
+
+    /// Returns the command line `args` with the alias `name` replaced by the arguments it
+    /// stands for, or `None` if there is no such alias.
+    pub fn expand_alias(&self, args: &[OsString], name: &str) -> Option<Vec<OsString>> {
+        let expansion = self.alias.get(name)?;
+        // The first argument is the name of the program.
+        let position = args.iter().skip(1).position(|arg| arg == name)? + 1;
+
+        let mut expanded = args[..position].to_vec();
+        expanded.extend(expansion.split_whitespace().map(OsString::from));
+        expanded.extend_from_slice(&args[position + 1..]);
+
+        Some(expanded)
+    }
+}
+
+/// Deserializes a value from a string with its `FromStr` implementation.
+fn from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let s = String::deserialize(deserializer)?;
+
+    s.parse().map(Some).map_err(de::Error::custom)
+}
+
+/// Deserializes a number that has to be positive.
+fn positive<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match f64::deserialize(deserializer)? {
+        n if n.is_finite() && n > 0.0 => Ok(Some(n)),
+        n => Err(de::Error::custom(format!("{} is not a positive number", n))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config: Config = toml::from_str(
+            r#"
+            preset = "wordle6"
+            list = "nyt"
+            spelling = "american"
+            strategy = "survive"
+            suggest = "probes"
+            tiebreak = "likely"
+            lang = "de"
+            hard = true
+            suggestions = 5
+            answer_weight = 10.0
+            repeat_policy = "downweight"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.preset, Some(Preset::Wordle6));
+        assert_eq!(config.list, Some(AnswerList::Nyt));
+        assert_eq!(config.spelling, Some(Spelling::American));
+        assert_eq!(config.strategy, Some(Strategy::Survive));
+        assert_eq!(config.suggest, Some(Pool::Probes));
+        assert_eq!(config.tiebreak, Some(TieBreak::Likely));
+        assert_eq!(config.lang, Some(Lang::De));
+        assert!(config.hard);
+        assert_eq!(config.suggestions, Some(5));
+        assert_eq!(config.answer_weight, Some(10.0));
+        assert_eq!(config.repeat_policy, Some(RepeatPolicy::Downweight));
+        assert!(!config.ascii);
+        assert_eq!(config.wordlist, None);
+
+        assert!(config.alias.is_empty());
+
+        assert!(toml::from_str::<Config>(r#"preset = "scrabble""#).is_err());
+        assert!(toml::from_str::<Config>("colour = true").is_err());
+        assert!(toml::from_str::<Config>("answer_weight = -1.0").is_err());
+    }
+
+    #[test]
+    fn test_expand_alias() {
+        let config: Config = toml::from_str(
+            r#"
+            [alias]
+            s = "suggest  --strategy entropy --top 5"
+            "#,
+        )
+        .unwrap();
+
+        let args = |args: &str| -> Vec<OsString> { args.split(' ').map(OsString::from).collect() };
+
+        assert_eq!(
+            config.expand_alias(&args("prompter --lang de s crate=Y_G__"), "s"),
+            Some(args(
+                "prompter --lang de suggest --strategy entropy --top 5 crate=Y_G__"
+            ))
+        );
+        assert_eq!(config.expand_alias(&args("prompter p"), "p"), None);
+        assert_eq!(config.expand_alias(&args("s"), "s"), None);
+    }
+}
@@ -0,0 +1,175 @@
+//! Sets of opening words that together cover as many letters as possible.
+//!
+//! Some players always start with the same two or three words before looking at the
+//! feedback. The words of a good opening share few letters and cover the letters that
+//! occur in many candidates.
+use std::collections::{BTreeMap, HashSet};
+
+use itertools::Itertools;
+
+use crate::{Word, Wordlist};
+
+/// Number of partial openings kept in each step of the search.
+const BEAM_WIDTH: usize = 100;
+
+/// Maximum number of different characters that are taken into account. Rarer characters
+/// are ignored.
+const MAX_CHARS: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The value of the characters covered by an opening
+pub enum Weighting {
+    #[default]
+    /// Each different character counts once.
+    Distinct,
+    /// Each different character counts as often as there are words in the wordlist that
+    /// contain it.
+    Frequency,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A set of words to be played one after another at the start of a game
+pub struct Opening<'a> {
+    /// The words in the order in which they were chosen, best first.
+    pub words: Vec<&'a Word>,
+    /// Number of different characters in the words.
+    pub chars: usize,
+    /// Value of the different characters in the words according to the [`Weighting`].
+    pub score: usize,
+}
+
+/// Returns the best `n` openings of `k` words from `wordlist` by the value of the
+/// characters they cover.
+///
+/// Trying all combinations is too slow for real wordlists, so the openings are built word
+/// by word and only the best partial openings are kept in each step (a beam search). The
+/// result is therefore not guaranteed to be optimal, but it is close in practice.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{coverage::{best_openings, Weighting}, Word, Wordlist};
+/// let wordlist: Wordlist = ["crate", "trace", "sloth", "pious"].iter().map(Word::from).collect();
+/// let openings = best_openings(&wordlist, 2, Weighting::Distinct, 1);
+///
+/// assert_eq!(openings[0].chars, 10);
+/// ```
+pub fn best_openings(
+    wordlist: &Wordlist,
+    k: usize,
+    weighting: Weighting,
+    n: usize,
+) -> Vec<Opening<'_>> {
+    let words: Vec<_> = wordlist.iter().collect();
+
+    // The number of words that contain each character.
+    let frequencies = words
+        .iter()
+        .flat_map(|w| w.chars().unique())
+        .counts()
+        .into_iter()
+        .sorted_by(|(c1, n1), (c2, n2)| n2.cmp(n1).then(c1.cmp(c2)))
+        .take(MAX_CHARS);
+
+    let mut bits = BTreeMap::new();
+    let mut weights = vec![];
+
+    for (i, (c, frequency)) in frequencies.enumerate() {
+        bits.insert(c, i);
+        weights.push(match weighting {
+            Weighting::Distinct => 1,
+            Weighting::Frequency => frequency,
+        });
+    }
+
+    let masks: Vec<u64> = words
+        .iter()
+        .map(|w| {
+            w.chars()
+                .filter_map(|c| bits.get(&c))
+                .fold(0, |mask, i| mask | 1 << i)
+        })
+        .collect();
+
+    let score = |mask: u64| -> usize {
+        weights
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| mask & 1 << i != 0)
+            .map(|(_, w)| w)
+            .sum()
+    };
+
+    let masks = &masks;
+    let mut beam: Vec<(Vec<usize>, u64)> = vec![(vec![], 0)];
+
+    for _ in 0..k.min(words.len()) {
+        let mut seen = HashSet::new();
+
+        beam = beam
+            .iter()
+            .flat_map(|(set, mask)| {
+                (0..words.len())
+                    .filter(move |i| !set.contains(i))
+                    .map(move |i| {
+                        let mut set = set.clone();
+                        set.push(i);
+                        (set, mask | masks[i])
+                    })
+            })
+            .map(|(set, mask)| (score(mask), set, mask))
+            .sorted_by(|(s1, set1, _), (s2, set2, _)| s2.cmp(s1).then(set1.cmp(set2)))
+            .filter(|(_, set, _)| seen.insert(set.iter().copied().sorted().collect_vec()))
+            .take(BEAM_WIDTH.max(n))
+            .map(|(_, set, mask)| (set, mask))
+            .collect();
+    }
+
+    beam.into_iter()
+        .take(n)
+        .map(|(set, mask)| Opening {
+            words: set.iter().map(|&i| words[i]).collect(),
+            chars: mask.count_ones() as usize,
+            score: score(mask),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wordlist(words: &[&str]) -> Wordlist {
+        words.iter().map(Word::from).collect()
+    }
+
+    #[test]
+    fn test_best_openings() {
+        let words = wordlist(&["crate", "trace", "sloth", "pious", "mummy"]);
+
+        let openings = best_openings(&words, 2, Weighting::Distinct, 3);
+
+        assert_eq!(openings.len(), 3);
+        assert_eq!(
+            openings[0].words,
+            [&Word::from("crate"), &Word::from("pious")]
+        );
+        assert_eq!(openings[0].chars, 10);
+        assert!(openings.windows(2).all(|o| o[0].score >= o[1].score));
+
+        let openings = best_openings(&words, 3, Weighting::Frequency, 1);
+
+        assert_eq!(openings[0].words.len(), 3);
+        assert!(openings[0].score > openings[0].chars);
+    }
+
+    #[test]
+    fn test_small_wordlist() {
+        let words = wordlist(&["crate"]);
+
+        let openings = best_openings(&words, 2, Weighting::Distinct, 5);
+
+        assert_eq!(openings.len(), 1);
+        assert_eq!(openings[0].words, [&Word::from("crate")]);
+    }
+}
@@ -0,0 +1,29 @@
+//! Numbers of the daily puzzles.
+//!
+//! Wordle publishes one puzzle per day, numbered from 0 on 19 June 2021. The number is
+//! computed from the date alone, without network access. Days start at midnight UTC (see
+//! [`stats::today`](crate::stats::today)), so in other time zones the number may be off by
+//! one around midnight.
+use crate::preset::Preset;
+
+/// The day of Wordle's first puzzle (19 June 2021), counted in days since the Unix epoch.
+pub const WORDLE_FIRST_DAY: u64 = 18797;
+
+/// Returns the number of the daily puzzle of `preset` on `day` (counted in days since the
+/// Unix epoch), or `None` if there is no daily puzzle of the preset on that day.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{daily, preset::Preset};
+/// // 1 January 2024
+/// assert_eq!(daily::puzzle_number(Preset::Wordle, 19723), Some(926));
+/// assert_eq!(daily::puzzle_number(Preset::Wordle, 18796), None);
+/// assert_eq!(daily::puzzle_number(Preset::Primel, 19723), None);
+/// ```
+pub fn puzzle_number(preset: Preset, day: u64) -> Option<u64> {
+    match preset {
+        Preset::Wordle => day.checked_sub(WORDLE_FIRST_DAY),
+        _ => None,
+    }
+}
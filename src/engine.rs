@@ -0,0 +1,235 @@
+//! A long-running engine that is driven by a line-delimited JSON protocol on stdin and
+//! stdout, similar to the UCI protocol of chess engines.
+//!
+//! Each line on stdin is a request object with a `cmd` field, each request is answered by
+//! exactly one line on stdout. An optional `id` in the request is copied to the response.
+//!
+//! * `{"cmd": "newgame", "preset": "wordle"}` starts a new game (`preset` is optional).
+//! * `{"cmd": "apply", "guess": "crate", "code": "_Y__G"}` applies Wordle's feedback.
+//! * `{"cmd": "suggest", "limit": 10}` returns the best-ranked candidates.
+//! * `{"cmd": "buckets", "word": "crate"}` returns the buckets of the candidates for a word.
+//! * `{"cmd": "quit"}` ends the engine.
+//!
+//! Responses contain `"ok": true` and the requested data or `"ok": false` and an `error`.
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::{self, BufRead, Write},
+};
+
+use prompter::{
+    alphabet::lowercase_word,
+    feedback::{FeedbackRule, WordleRule},
+    preset::Preset,
+    solver::Solver,
+    Word, Wordlist,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// Number of suggestions returned if the request does not specify it.
+const DEFAULT_LIMIT: usize = 10;
+
+#[derive(Deserialize)]
+#[serde(tag = "cmd", rename_all = "lowercase")]
+enum Request {
+    NewGame { preset: Option<String> },
+    Apply { guess: String, code: String },
+    Suggest { limit: Option<usize> },
+    Buckets { word: String },
+    Quit,
+}
+
+/// The state of the engine, which is kept between requests
+pub struct Engine {
+    preset: Preset,
+    solver: Solver,
+    /// Wordlists of the presets used so far.
+    wordlists: HashMap<Preset, Wordlist>,
+    /// Ranking of the current candidates, computed when it is first requested.
+    ranking: Option<Vec<(String, usize)>>,
+}
+
+impl Engine {
+    /// Creates an engine with a new game for `preset`.
+    pub fn new(preset: Preset) -> Self {
+        let wordlist = preset.wordlist();
+
+        Self {
+            preset,
+            solver: Solver::new(wordlist.clone(), preset.word_len()),
+            wordlists: HashMap::from([(preset, wordlist)]),
+            ranking: None,
+        }
+    }
+
+    /// Handles one request line and returns the response line, or `None` if the engine
+    /// should quit.
+    pub fn handle(&mut self, line: &str) -> Option<String> {
+        let value: Value = match serde_json::from_str(line) {
+            Ok(value) => value,
+            Err(error) => return Some(error_response(None, &error.to_string())),
+        };
+
+        let id = value.get("id").cloned();
+
+        let response = match serde_json::from_value(value) {
+            Ok(Request::Quit) => return None,
+            Ok(request) => self.respond(request),
+            Err(error) => Err(error.to_string()),
+        };
+
+        let line = match response {
+            Ok(mut response) => {
+                response["ok"] = json!(true);
+
+                if let Some(id) = id {
+                    response["id"] = id;
+                }
+
+                response.to_string()
+            }
+            Err(error) => error_response(id, &error),
+        };
+
+        Some(line)
+    }
+
+    fn respond(&mut self, request: Request) -> Result<Value, String> {
+        match request {
+            Request::NewGame { preset } => {
+                if let Some(preset) = preset {
+                    self.preset = preset.parse()?;
+                }
+
+                let preset = self.preset;
+                let wordlist = self
+                    .wordlists
+                    .entry(preset)
+                    .or_insert_with(|| preset.wordlist());
+
+                self.solver = Solver::new(wordlist.clone(), preset.word_len());
+                self.ranking = None;
+
+                Ok(json!({ "preset": preset.name(), "candidates": wordlist.len() }))
+            }
+            Request::Apply { guess, code } => {
+                self.solver
+                    .apply(&guess, &code)
+                    .map_err(|e| e.to_string())?;
+                self.ranking = None;
+
+                Ok(json!({
+                    "candidates": self.solver.candidates().len(),
+                    "solved": self.solver.is_solved(),
+                }))
+            }
+            Request::Suggest { limit } => {
+                let solver = &self.solver;
+                let ranking = self.ranking.get_or_insert_with(|| {
+                    solver
+                        .candidates()
+                        .rank_words()
+                        .map(|(w, score)| (w.to_string(), score))
+                        .collect()
+                });
+
+                let suggestions: Vec<_> = ranking
+                    .iter()
+                    .take(limit.unwrap_or(DEFAULT_LIMIT))
+                    .map(|(word, score)| json!({ "word": word, "score": score }))
+                    .collect();
+
+                Ok(json!({ "suggestions": suggestions }))
+            }
+            Request::Buckets { word } => {
+                let word = Word::from(lowercase_word(&word));
+                let buckets: BTreeMap<_, Vec<_>> = WordleRule
+                    .buckets(&word, self.solver.candidates())
+                    .into_iter()
+                    .map(|(code, words)| {
+                        let words = words.iter().map(|w| w.to_string()).collect();
+                        (code.to_string(), words)
+                    })
+                    .collect();
+
+                Ok(json!({ "word": word.to_string(), "buckets": buckets }))
+            }
+            Request::Quit => unreachable!("handled before"),
+        }
+    }
+}
+
+fn error_response(id: Option<Value>, error: &str) -> String {
+    let mut response = json!({ "ok": false, "error": error });
+
+    if let Some(id) = id {
+        response["id"] = id;
+    }
+
+    response.to_string()
+}
+
+/// Runs the engine until stdin is closed or a `quit` request is received.
+pub fn run(preset: Preset) -> io::Result<()> {
+    let mut engine = Engine::new(preset);
+    let mut stdout = io::stdout().lock();
+
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match engine.handle(&line) {
+            Some(response) => {
+                writeln!(stdout, "{}", response)?;
+                stdout.flush()?;
+            }
+            None => break,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(engine: &mut Engine, line: &str) -> Value {
+        serde_json::from_str(&engine.handle(line).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_handle() {
+        let mut engine = Engine::new(Preset::Wordle);
+
+        let response = request(&mut engine, r#"{"cmd": "newgame", "id": 1}"#);
+        assert_eq!(response["ok"], true);
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["candidates"], 2314);
+
+        let response = request(
+            &mut engine,
+            r#"{"cmd": "apply", "guess": "crate", "code": "_Y__G"}"#,
+        );
+        assert_eq!(response["candidates"], 40);
+        assert_eq!(response["solved"], false);
+
+        let response = request(&mut engine, r#"{"cmd": "suggest", "limit": 2}"#);
+        assert_eq!(response["suggestions"][0]["word"], "reuse");
+        assert_eq!(response["suggestions"].as_array().unwrap().len(), 2);
+
+        let response = request(&mut engine, r#"{"cmd": "buckets", "word": "reuse"}"#);
+        assert_eq!(response["buckets"]["GGGGG"][0], "reuse");
+
+        let response = request(&mut engine, r#"{"cmd": "apply", "guess": "crate"}"#);
+        assert_eq!(response["ok"], false);
+
+        let response = request(&mut engine, "not json");
+        assert_eq!(response["ok"], false);
+
+        assert!(engine.handle(r#"{"cmd": "quit"}"#).is_none());
+    }
+}
@@ -0,0 +1,72 @@
+//! Information-theoretic measures of how well a guess sorts the candidates into buckets.
+//!
+//! If each of the `n` candidates is equally likely to be the mystery word, finding it takes
+//! log2(n) bits of information. A guess whose buckets have the sizes `k` provides the
+//! entropy of the bucket probabilities `k / n` and leaves, on average, the remaining
+//! (conditional) entropy of the words within the bucket. Both add up to log2(n).
+
+/// Returns the probability that the feedback falls into a bucket of `size` words out of
+/// `total` candidates.
+pub fn probability(size: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+
+    size as f64 / total as f64
+}
+
+/// Returns the contribution of a bucket of `size` words out of `total` candidates to the
+/// entropy of a guess in bits, i.e. `-p * log2(p)`.
+pub fn contribution(size: usize, total: usize) -> f64 {
+    let p = probability(size, total);
+
+    if p == 0.0 {
+        return 0.0;
+    }
+
+    -p * p.log2()
+}
+
+/// Returns the entropy in bits of a guess whose buckets have the given `sizes`.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::entropy;
+/// // Four equally large buckets provide two bits.
+/// assert_eq!(entropy::entropy(&[3, 3, 3, 3]), 2.0);
+/// assert_eq!(entropy::remaining(&[3, 3, 3, 3]), 3f64.log2());
+/// ```
+pub fn entropy(sizes: &[usize]) -> f64 {
+    let total = sizes.iter().sum();
+
+    sizes.iter().map(|&k| contribution(k, total)).sum()
+}
+
+/// Returns the entropy in bits that is expected to remain after a guess whose buckets have
+/// the given `sizes`.
+pub fn remaining(sizes: &[usize]) -> f64 {
+    let total = sizes.iter().sum();
+
+    sizes
+        .iter()
+        .filter(|&&k| k > 0)
+        .map(|&k| probability(k, total) * (k as f64).log2())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entropy() {
+        let sizes = [1, 3, 1, 5];
+        let total = (10f64).log2();
+
+        assert!((entropy(&sizes) + remaining(&sizes) - total).abs() < 1e-9);
+        assert_eq!(entropy(&[7]), 0.0);
+        assert_eq!(entropy(&[]), 0.0);
+        assert_eq!(remaining(&[1, 1, 1]), 0.0);
+    }
+}
@@ -0,0 +1,551 @@
+//! Rules that determine the feedback for a guess.
+//!
+//! Implement [`FeedbackRule`] to use `prompter` for a variant whose feedback differs from
+//! Wordle's color codes. Filtering, bucketing, ranking and simulating are provided on top
+//! of the rule's [`code`](FeedbackRule::code) function, and every [`Strategy`] and the
+//! [`Solver`](crate::solver::Solver) accept any rule.
+//!
+//! Wordle's feedback for a single character is a [`Feedback`], and for a whole guess a
+//! [`Pattern`], which is computed without allocating. Color codes like `Y_G_G` are only used
+//! for input and output.
+use std::{collections::HashMap, fmt, hash::Hash, thread};
+
+use crate::{
+    prior::Prior,
+    scratch::{self, Scratch},
+    strategy::Strategy,
+    Error, Word, Wordlist,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The color that Wordle shows for a single character of a guess
+pub enum Feedback {
+    /// The character is at this position in the mystery word.
+    Green,
+    /// The character is in the mystery word, but at another position.
+    Yellow,
+    /// The character is not in the mystery word (or not as often as in the guess).
+    Gray,
+}
+
+impl Feedback {
+    /// Returns the square that Wordle's share button uses for the color.
+    pub fn emoji(self) -> char {
+        match self {
+            Feedback::Green => '🟩',
+            Feedback::Yellow => '🟨',
+            Feedback::Gray => '⬛',
+        }
+    }
+}
+
+impl From<Feedback> for char {
+    /// Returns the character for the color in a color code: `G`, `Y` or `_`.
+    fn from(feedback: Feedback) -> Self {
+        match feedback {
+            Feedback::Green => 'G',
+            Feedback::Yellow => 'Y',
+            Feedback::Gray => '_',
+        }
+    }
+}
+
+impl TryFrom<char> for Feedback {
+    type Error = Error;
+
+    /// Converts a character of a color code (`G`, `Y` or `_` in any case) or one of Wordle's
+    /// squares (`🟩`, `🟨`, `⬛` or `⬜`) to the color.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'G' | 'g' | '🟩' => Ok(Feedback::Green),
+            'Y' | 'y' | '🟨' => Ok(Feedback::Yellow),
+            '_' | '⬛' | '⬜' => Ok(Feedback::Gray),
+            c => Err(Error::InvalidColorCode {
+                input: c,
+                gray_aliases: String::new(),
+            }),
+        }
+    }
+}
+
+/// Returns the color code with `G`, `Y` and `_` for the feedback of a guess.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{feedback::{from_code, to_code, Feedback}, Error};
+/// let feedback = from_code("Y_G_G").unwrap();
+///
+/// assert_eq!(feedback[0], Feedback::Yellow);
+/// assert_eq!(to_code(&feedback), "Y_G_G");
+/// assert_eq!(from_code("🟨⬛🟩⬛🟩"), Ok(feedback));
+/// assert!(from_code("Y_Q_G").is_err());
+/// ```
+pub fn to_code(feedback: &[Feedback]) -> String {
+    feedback.iter().map(|&f| char::from(f)).collect()
+}
+
+/// Parses a color code (see [`to_code`]) or a row of Wordle's squares.
+pub fn from_code(code: &str) -> Result<Vec<Feedback>, Error> {
+    code.chars().map(Feedback::try_from).collect()
+}
+
+/// Maximum length of the words whose feedback fits into a [`Pattern`].
+pub const MAX_PATTERN_LEN: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+/// Wordle's feedback for a whole guess (see [`Word::pattern`]) as bitmasks of the green and
+/// of the yellow positions
+///
+/// Unlike a `Vec<Feedback>`, a pattern is a plain value that needs no allocation, so that
+/// bucketing and ranking words only compare and hash integers.
+pub struct Pattern {
+    green: u64,
+    yellow: u64,
+    len: u8,
+}
+
+impl Pattern {
+    /// Returns the number of characters.
+    pub fn len(self) -> usize {
+        usize::from(self.len)
+    }
+
+    /// Returns `true` if the pattern is the feedback for an empty guess.
+    pub fn is_empty(self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the color at `index`, or `None` if the pattern is shorter.
+    pub fn get(self, index: usize) -> Option<Feedback> {
+        if index >= self.len() {
+            None
+        } else if self.green & 1 << index != 0 {
+            Some(Feedback::Green)
+        } else if self.yellow & 1 << index != 0 {
+            Some(Feedback::Yellow)
+        } else {
+            Some(Feedback::Gray)
+        }
+    }
+
+    /// Returns an iterator over the colors from the first character to the last.
+    pub fn iter(self) -> impl Iterator<Item = Feedback> {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+
+    /// Returns the pattern as a number in base 3 with one digit per character, the first
+    /// character being the most significant one: `2` is Green, `1` is Yellow and `0` is Gray
+    /// (the pattern number of the [`matrix`](crate::matrix) module).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::Word;
+    /// assert_eq!(Word::from("crate").pattern(&Word::from("space")).number(), 101);
+    /// ```
+    pub fn number(self) -> u128 {
+        self.iter().fold(0, |n, feedback| {
+            n * 3
+                + match feedback {
+                    Feedback::Green => 2,
+                    Feedback::Yellow => 1,
+                    Feedback::Gray => 0,
+                }
+        })
+    }
+
+    /// Returns `true` if all characters are green.
+    pub fn is_correct(self) -> bool {
+        self.green.count_ones() == u32::from(self.len)
+    }
+
+    /// Appends the color of the next character. Characters beyond [`MAX_PATTERN_LEN`] are
+    /// left out.
+    pub(crate) fn push(&mut self, feedback: Feedback) {
+        if self.len() == MAX_PATTERN_LEN {
+            return;
+        }
+
+        match feedback {
+            Feedback::Green => self.green |= 1 << self.len,
+            Feedback::Yellow => self.yellow |= 1 << self.len,
+            Feedback::Gray => {}
+        }
+
+        self.len += 1;
+    }
+}
+
+impl FromIterator<Feedback> for Pattern {
+    /// Collects the colors of a guess into a pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::feedback::{from_code, Feedback, Pattern};
+    /// let pattern: Pattern = from_code("Y_G_G").unwrap().into_iter().collect();
+    ///
+    /// assert_eq!(pattern.len(), 5);
+    /// assert_eq!(pattern.get(0), Some(Feedback::Yellow));
+    /// assert_eq!(pattern.to_string(), "Y_G_G");
+    /// assert!(!pattern.is_correct());
+    /// ```
+    fn from_iter<I: IntoIterator<Item = Feedback>>(iter: I) -> Self {
+        let mut pattern = Pattern::default();
+
+        for feedback in iter {
+            pattern.push(feedback);
+        }
+
+        pattern
+    }
+}
+
+impl fmt::Display for Pattern {
+    /// Writes the color code with `G`, `Y` and `_` (see [`to_code`]).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter()
+            .try_for_each(|feedback| write!(f, "{}", char::from(feedback)))
+    }
+}
+
+/// A function that computes the feedback for a guess and the mystery word
+pub trait FeedbackRule {
+    /// The feedback for a whole guess.
+    type Code: Clone + Eq + Hash;
+
+    /// Returns the feedback that the game would present for the guess `guess` if `target`
+    /// is the mystery word.
+    fn code(&self, guess: &Word, target: &Word) -> Self::Code;
+
+    /// Returns a small number for `code` that no other code gets, or `None` if there is
+    /// none. Rankings keep the words of codes with such an index in an array instead of
+    /// hashing the codes (see [`Buckets`](crate::scratch::Buckets)).
+    fn index(&self, _code: &Self::Code) -> Option<usize> {
+        None
+    }
+
+    /// Sorts the words in `wordlist` into buckets of words that get the same feedback for
+    /// `guess`.
+    fn buckets<'a>(
+        &self,
+        guess: &Word,
+        wordlist: &'a Wordlist,
+    ) -> HashMap<Self::Code, Vec<&'a Word>> {
+        let mut map = HashMap::new();
+
+        for w in wordlist {
+            map.entry(self.code(guess, w))
+                .or_insert_with(Vec::new)
+                .push(w);
+        }
+
+        map
+    }
+
+    /// Sorts the words in `wordlist` into buckets like [`buckets`](FeedbackRule::buckets),
+    /// but on up to `jobs` threads, each of which handles a part of the list. The words of
+    /// each bucket keep their order in `wordlist`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{feedback::{FeedbackRule, WordleRule}, Word, Wordlist};
+    /// let words: Wordlist = ["crate", "trace", "react", "spoon"].iter().map(Word::from).collect();
+    /// let guess = Word::from("caret");
+    ///
+    /// assert_eq!(WordleRule.par_buckets(&guess, &words, 3), WordleRule.buckets(&guess, &words));
+    /// ```
+    fn par_buckets<'a>(
+        &self,
+        guess: &Word,
+        wordlist: &'a Wordlist,
+        jobs: usize,
+    ) -> HashMap<Self::Code, Vec<&'a Word>>
+    where
+        Self: Sync,
+        Self::Code: Send,
+    {
+        let words: Vec<&Word> = wordlist.iter().collect();
+        let chunk_len = words.len().div_ceil(jobs.max(1)).max(1);
+
+        let parts: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = words
+                .chunks(chunk_len)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        let mut map = HashMap::new();
+
+                        for &w in chunk {
+                            map.entry(self.code(guess, w))
+                                .or_insert_with(Vec::new)
+                                .push(w);
+                        }
+
+                        map
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("computing the codes does not panic"))
+                .collect()
+        });
+
+        // The parts are merged in the order of the list.
+        let mut map: HashMap<_, Vec<_>> = HashMap::new();
+
+        for part in parts {
+            for (code, words) in part {
+                map.entry(code).or_default().extend(words);
+            }
+        }
+
+        map
+    }
+
+    /// Computes the number of different codes that `guess` gets when matched against every
+    /// word in `wordlist`.
+    fn filter_potential(&self, guess: &Word, wordlist: &Wordlist) -> usize
+    where
+        Self: Sized,
+    {
+        self.filter_potential_with(guess, wordlist, &mut Scratch::default())
+    }
+
+    /// Computes the [`filter_potential`](FeedbackRule::filter_potential) with the buffers
+    /// in `scratch`, so that scoring many words allocates them only once.
+    fn filter_potential_with(
+        &self,
+        guess: &Word,
+        wordlist: &Wordlist,
+        scratch: &mut Scratch<Self::Code>,
+    ) -> usize
+    where
+        Self: Sized,
+    {
+        scratch.counts.clear();
+
+        for w in wordlist {
+            scratch.counts.add(self, self.code(guess, w), 1);
+        }
+
+        scratch.counts.len()
+    }
+
+    /// Returns the words in `wordlist` that would get the feedback `code` for `guess`.
+    fn filter(&self, wordlist: Wordlist, guess: &Word, code: &Self::Code) -> Wordlist {
+        wordlist
+            .into_iter()
+            .filter(|w| &self.code(guess, w) == code)
+            .collect()
+    }
+
+    /// Ranks the words in `wordlist` by their filter potential and returns pairs of word
+    /// references and scores sorted by the score in descending order. Two words with the
+    /// same score are sorted lexicographically (see [`Strategy::Solve`]).
+    fn rank_words<'a>(&self, wordlist: &'a Wordlist) -> Vec<(&'a Word, usize)>
+    where
+        Self: Sized,
+    {
+        Strategy::Solve.rank_guesses_by(self, wordlist, wordlist, &Prior::uniform())
+    }
+
+    /// Simulates a game with the words in `wordlist` that starts with `start` and then
+    /// always guesses the best-ranked word. Returns the number of rounds needed to find
+    /// `target` or `None` if it was not found within `rounds` rounds.
+    fn simulate(
+        &self,
+        wordlist: &Wordlist,
+        start: &Word,
+        target: &Word,
+        rounds: usize,
+    ) -> Option<usize>
+    where
+        Self: Sized,
+    {
+        let mut wordlist = wordlist.clone();
+        let mut guess = start.clone();
+        let mut scratch = Scratch::default();
+        let prior = Prior::uniform();
+
+        for i in 1..=rounds {
+            if &guess == target {
+                return Some(i);
+            }
+
+            let code = self.code(&guess, target);
+            wordlist = self.filter(wordlist, &guess, &code);
+            wordlist.remove(&guess.to_string());
+
+            guess = Strategy::Solve
+                .best_guess_by(self, &wordlist, &wordlist, &prior, &mut scratch)?
+                .0
+                .clone();
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+/// Wordle's feedback (see [`Word::pattern`])
+pub struct WordleRule;
+
+impl FeedbackRule for WordleRule {
+    type Code = Pattern;
+
+    fn code(&self, guess: &Word, target: &Word) -> Self::Code {
+        guess.pattern(target)
+    }
+
+    fn index(&self, code: &Self::Code) -> Option<usize> {
+        scratch::index(*code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Only reveals the characters at the correct position.
+    struct GreenOnly;
+
+    impl FeedbackRule for GreenOnly {
+        type Code = String;
+
+        fn code(&self, guess: &Word, target: &Word) -> String {
+            guess
+                .chars()
+                .zip(target.chars())
+                .map(|(c1, c2)| if c1 == c2 { 'G' } else { '_' })
+                .collect()
+        }
+    }
+
+    fn wordlist() -> Wordlist {
+        ["crate", "trace", "react", "caret", "spoon"]
+            .iter()
+            .map(Word::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_feedback_chars() {
+        for feedback in [Feedback::Green, Feedback::Yellow, Feedback::Gray] {
+            assert_eq!(Feedback::try_from(char::from(feedback)), Ok(feedback));
+            assert_eq!(Feedback::try_from(feedback.emoji()), Ok(feedback));
+        }
+
+        assert_eq!(
+            Feedback::try_from('x'),
+            Err(Error::InvalidColorCode {
+                input: 'x',
+                gray_aliases: String::new()
+            })
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        let words = wordlist();
+
+        for guess in &words {
+            for target in &words {
+                let pattern = guess.pattern(target);
+
+                assert_eq!(pattern.iter().collect::<Vec<_>>(), guess.feedback(target));
+                assert_eq!(pattern.is_correct(), guess == target);
+            }
+        }
+
+        let pattern = Word::from("speed").pattern(&Word::from("abide"));
+        assert_eq!(pattern.to_string(), "__Y_Y");
+        assert_eq!(pattern.get(5), None);
+
+        let long: Pattern = std::iter::repeat(Feedback::Green).take(100).collect();
+        assert_eq!(long.len(), MAX_PATTERN_LEN);
+        assert!(long.is_correct());
+        assert!(Pattern::default().is_empty());
+    }
+
+    #[test]
+    fn test_buckets() {
+        let words = wordlist();
+        let guess = Word::from("crate");
+
+        assert_eq!(
+            WordleRule.filter_potential(&guess, &words),
+            guess.filter_potential(&words)
+        );
+        assert_eq!(
+            GreenOnly.buckets(&guess, &words).len(),
+            GreenOnly.filter_potential(&guess, &words)
+        );
+        assert_eq!(
+            WordleRule.buckets(&guess, &words)[&from_code("GGGGG").unwrap().into_iter().collect()],
+            [&guess]
+        );
+        assert_eq!(
+            GreenOnly.buckets(&guess, &words)["_____"],
+            [&Word::from("spoon")]
+        );
+
+        for jobs in [1, 2, 10] {
+            assert_eq!(
+                WordleRule.par_buckets(&guess, &words, jobs),
+                WordleRule.buckets(&guess, &words)
+            );
+        }
+        assert_eq!(
+            GreenOnly.par_buckets(&guess, &Wordlist::default(), 4),
+            HashMap::new()
+        );
+    }
+
+    #[test]
+    fn test_rank_words() {
+        let words = wordlist();
+
+        let expected: Vec<_> = words.rank_words().collect();
+        assert_eq!(WordleRule.rank_words(&words), expected);
+
+        let ranked = GreenOnly.rank_words(&words);
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+
+    #[test]
+    fn test_strategies() {
+        let words = wordlist();
+        let prior = Prior::uniform();
+
+        for strategy in Strategy::ALL {
+            assert_eq!(
+                strategy.rank_guesses_by(&WordleRule, &words, &words, &prior),
+                strategy.rank_guesses(&words, &words, &prior)
+            );
+
+            let ranked = strategy.rank_guesses_by(&GreenOnly, &words, &words, &prior);
+            let best =
+                strategy.best_guess_by(&GreenOnly, &words, &words, &prior, &mut Scratch::default());
+
+            assert_eq!(ranked.len(), words.len());
+            assert_eq!(best, ranked.first().copied());
+        }
+    }
+
+    #[test]
+    fn test_simulate() {
+        let words = wordlist();
+        let start = Word::from("spoon");
+
+        for target in &words {
+            assert!(WordleRule.simulate(&words, &start, target, 6).is_some());
+            assert!(GreenOnly.simulate(&words, &start, target, 6).is_some());
+        }
+
+        assert_eq!(WordleRule.simulate(&words, &start, &start, 6), Some(1));
+    }
+}
@@ -0,0 +1,207 @@
+//! C API for embedding the solver in applications written in other languages.
+//!
+//! The declarations are available in the header `include/prompter.h`, which is generated
+//! with [cbindgen](https://github.com/mozilla/cbindgen):
+//!
+//! ```text
+//! cbindgen --config cbindgen.toml --output include/prompter.h
+//! ```
+//!
+//! All strings are NUL-terminated and UTF-8 encoded. Strings returned by the library must
+//! be released with [`prompter_string_free`], solvers with [`prompter_solver_free`].
+//!
+//! A panic never unwinds into the calling code, which would be undefined behavior. The
+//! functions return `NULL`, -1 or 0 instead, as for invalid input.
+use std::{
+    ffi::{c_char, c_int, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+use crate::{preset::Preset, solver::Solver};
+
+/// An opaque handle to a solver.
+pub struct PrompterSolver(Solver);
+
+/// Returns the result of `f`, or `fallback` if it panics. `f` must not leave a solver
+/// half-changed when it panics (see [`prompter_solver_apply`]).
+fn guard<T>(fallback: T, f: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(fallback)
+}
+
+/// Creates a solver for the preset with the given name (e.g. `wordle`), or for the default
+/// preset if `preset` is `NULL`. Returns `NULL` if the preset is unknown or the solver
+/// cannot be created.
+///
+/// # Safety
+///
+/// `preset` must be `NULL` or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn prompter_solver_new(preset: *const c_char) -> *mut PrompterSolver {
+    guard(ptr::null_mut(), || {
+        let preset = if preset.is_null() {
+            Preset::default()
+        } else {
+            match CStr::from_ptr(preset).to_str().map(str::parse::<Preset>) {
+                Ok(Ok(preset)) => preset,
+                _ => return ptr::null_mut(),
+            }
+        };
+
+        let solver = Solver::new(preset.wordlist(), preset.word_len());
+
+        Box::into_raw(Box::new(PrompterSolver(solver)))
+    })
+}
+
+/// Releases a solver created with [`prompter_solver_new`]. Does nothing if `solver` is
+/// `NULL`.
+///
+/// # Safety
+///
+/// `solver` must be `NULL` or a pointer returned by [`prompter_solver_new`] that has not
+/// been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn prompter_solver_free(solver: *mut PrompterSolver) {
+    if !solver.is_null() {
+        guard((), || drop(Box::from_raw(solver)));
+    }
+}
+
+/// Applies Wordle's color `code` (e.g. `Y_G_G`) for `guess`. Returns 0 on success and -1
+/// if the input is invalid or applying it fails, in which case the solver is unchanged.
+///
+/// # Safety
+///
+/// `solver` must be a valid solver, `guess` and `code` must point to NUL-terminated
+/// strings.
+#[no_mangle]
+pub unsafe extern "C" fn prompter_solver_apply(
+    solver: *mut PrompterSolver,
+    guess: *const c_char,
+    code: *const c_char,
+) -> c_int {
+    if solver.is_null() || guess.is_null() || code.is_null() {
+        return -1;
+    }
+
+    guard(-1, || {
+        let (Ok(guess), Ok(code)) = (
+            CStr::from_ptr(guess).to_str(),
+            CStr::from_ptr(code).to_str(),
+        ) else {
+            return -1;
+        };
+
+        // The code is applied to a copy, so that a panic cannot leave the solver
+        // half-updated.
+        let mut next = (*solver).0.clone();
+
+        match next.apply(guess, code) {
+            Ok(()) => {
+                (*solver).0 = next;
+                0
+            }
+            Err(_) => -1,
+        }
+    })
+}
+
+/// Returns the number of words that are still possible.
+///
+/// # Safety
+///
+/// `solver` must be a valid solver.
+#[no_mangle]
+pub unsafe extern "C" fn prompter_solver_candidate_count(solver: *const PrompterSolver) -> usize {
+    if solver.is_null() {
+        return 0;
+    }
+
+    guard(0, || (*solver).0.candidates().len())
+}
+
+/// Returns the `n` best-ranked candidates, separated by newlines, or `NULL` if ranking them
+/// fails. The string must be released with [`prompter_string_free`].
+///
+/// # Safety
+///
+/// `solver` must be a valid solver.
+#[no_mangle]
+pub unsafe extern "C" fn prompter_solver_suggest(
+    solver: *const PrompterSolver,
+    n: usize,
+) -> *mut c_char {
+    if solver.is_null() {
+        return ptr::null_mut();
+    }
+
+    guard(ptr::null_mut(), || {
+        let words: Vec<_> = (*solver)
+            .0
+            .suggest(n)
+            .into_iter()
+            .map(|(w, _)| w.to_string())
+            .collect();
+
+        CString::new(words.join("\n")).map_or(ptr::null_mut(), CString::into_raw)
+    })
+}
+
+/// Releases a string returned by the library. Does nothing if `s` is `NULL`.
+///
+/// # Safety
+///
+/// `s` must be `NULL` or a string returned by the library that has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn prompter_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        guard((), || drop(CString::from_raw(s)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solver() {
+        unsafe {
+            let preset = CString::new("wordle").unwrap();
+            let solver = prompter_solver_new(preset.as_ptr());
+            assert!(!solver.is_null());
+            assert_eq!(prompter_solver_candidate_count(solver), 2314);
+
+            let guess = CString::new("crate").unwrap();
+            let code = CString::new("_Y__G").unwrap();
+            assert_eq!(
+                prompter_solver_apply(solver, guess.as_ptr(), code.as_ptr()),
+                0
+            );
+
+            let count = prompter_solver_candidate_count(solver);
+            let invalid = CString::new("_Y_").unwrap();
+            assert_eq!(
+                prompter_solver_apply(solver, guess.as_ptr(), invalid.as_ptr()),
+                -1
+            );
+            assert_eq!(prompter_solver_candidate_count(solver), count);
+
+            let suggestions = prompter_solver_suggest(solver, 3);
+            let words = CStr::from_ptr(suggestions).to_str().unwrap();
+            assert_eq!(words.lines().count(), 3.min(count));
+            prompter_string_free(suggestions);
+
+            prompter_solver_free(solver);
+
+            let unknown = CString::new("unknown").unwrap();
+            assert!(prompter_solver_new(unknown.as_ptr()).is_null());
+        }
+    }
+
+    #[test]
+    fn test_guard() {
+        assert_eq!(guard(-1, || 0), 0);
+        assert_eq!(guard(-1, || panic!("not caught")), -1);
+    }
+}
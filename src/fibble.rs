@@ -0,0 +1,149 @@
+//! Games of Fibble, where exactly one of the rows of feedback in a game is a lie.
+//!
+//! As long as the lie has not been identified, every row could be the one that lied, or
+//! the lie might still be to come. Each of these hypotheses leads to a different set of
+//! candidate words.
+use crate::{ConstraintSet, Word, Wordlist};
+
+#[derive(Debug, Clone)]
+/// A game of Fibble that keeps track of the feedback of all rounds
+pub struct Fibble {
+    /// Words that can be the mystery word if the feedback is ignored.
+    wordlist: Wordlist,
+    /// Guesses and the feedback for them.
+    history: Vec<(Word, ConstraintSet)>,
+}
+
+impl Fibble {
+    /// Creates a game in which the mystery word is one of the words in `wordlist`.
+    pub fn new(wordlist: &Wordlist) -> Self {
+        Self {
+            wordlist: wordlist.clone(),
+            history: vec![],
+        }
+    }
+
+    /// Adds the feedback for `guess`.
+    pub fn apply(&mut self, guess: &str, constraints: ConstraintSet) {
+        self.history.push((Word::from(guess), constraints));
+    }
+
+    /// Returns the feedback of all rounds so far.
+    pub fn history(&self) -> impl Iterator<Item = &ConstraintSet> {
+        self.history.iter().map(|(_, c)| c)
+    }
+
+    /// Returns the candidate words for each hypothesis about the lie: `None` if none of the
+    /// rows so far lied, `Some(i)` if the row with index `i` lied. The candidates for a lying
+    /// row must contradict this row and match all the others. Hypotheses without any
+    /// candidates are left out.
+    pub fn hypotheses(&self) -> Vec<(Option<usize>, Wordlist)> {
+        let rows = self.history.len();
+
+        (0..=rows)
+            .map(|lie| (lie < rows).then_some(lie))
+            .map(|lie| {
+                let candidates: Wordlist = self
+                    .wordlist
+                    .iter()
+                    .filter(|w| {
+                        self.history.iter().enumerate().all(|(i, (guess, c))| {
+                            // A guess that was the mystery word would have ended the game.
+                            let is_match = c.is_match(w) && (c.correct_word() || *w != guess);
+                            is_match != (Some(i) == lie)
+                        })
+                    })
+                    .cloned()
+                    .collect();
+
+                (lie, candidates)
+            })
+            .filter(|(_, candidates)| !candidates.is_empty())
+            .collect()
+    }
+
+    /// Returns the words that are candidates under any hypothesis, in the order of the
+    /// wordlist.
+    pub fn candidates(&self) -> Wordlist {
+        let hypotheses = self.hypotheses();
+
+        self.wordlist
+            .iter()
+            .filter(|w| hypotheses.iter().any(|(_, c)| c.contains(&w.to_string())))
+            .cloned()
+            .collect()
+    }
+
+    /// Ranks the words in `guesses` by their [`filter_potential`] over the candidates of
+    /// all hypotheses and returns pairs of word references and scores sorted by the score
+    /// in descending order. Words that can be the mystery word come first among words with
+    /// the same score, then the words are sorted alphabetically.
+    ///
+    /// [`filter_potential`]: Word::filter_potential
+    pub fn rank_words<'a>(&self, guesses: &'a Wordlist) -> Vec<(&'a Word, usize)> {
+        let candidates = self.candidates();
+
+        let mut ranked: Vec<_> = guesses
+            .iter()
+            .map(|w| {
+                let hit = candidates.contains(&w.to_string());
+                (w, w.filter_potential(&candidates), hit)
+            })
+            .collect();
+
+        ranked.sort_by_key(|&(w, score, hit)| (std::cmp::Reverse((score, hit)), w));
+
+        ranked.into_iter().map(|(w, score, _)| (w, score)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constraints(guess: &str, target: &str) -> ConstraintSet {
+        ConstraintSet::from_guess(&Word::from(guess), &Word::from(target))
+    }
+
+    #[test]
+    fn test_hypotheses() {
+        let words: Wordlist = ["crate", "trace", "spoon", "lumpy"]
+            .iter()
+            .map(Word::from)
+            .collect();
+        let mut game = Fibble::new(&words);
+
+        assert_eq!(game.hypotheses().len(), 1);
+        assert_eq!(game.candidates().len(), 4);
+
+        // The mystery word is "trace", so the feedback for "lumpy" is a lie.
+        game.apply(
+            "lumpy",
+            ConstraintSet::try_from(("lumpy", "Y____")).unwrap(),
+        );
+        game.apply("spoon", constraints("spoon", "trace"));
+
+        let hypotheses = game.hypotheses();
+
+        assert_eq!(hypotheses.len(), 1);
+        assert_eq!(hypotheses[0].0, Some(0));
+        assert!(hypotheses[0].1.contains("trace"));
+        assert!(!hypotheses[0].1.contains("spoon"));
+        assert!(!hypotheses[0].1.contains("lumpy"));
+    }
+
+    #[test]
+    fn test_rank_words() {
+        let words: Wordlist = ["crate", "trace", "spoon", "lumpy"]
+            .iter()
+            .map(Word::from)
+            .collect();
+        let mut game = Fibble::new(&words);
+        game.apply("spoon", constraints("spoon", "trace"));
+
+        let ranked = game.rank_words(&words);
+
+        assert_eq!(ranked.len(), 4);
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+}
@@ -0,0 +1,73 @@
+//! Letter frequencies of the candidate words, overall and per position.
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+
+use crate::Wordlist;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// How many words of a wordlist contain each character
+pub struct Frequencies {
+    /// Number of words counted.
+    pub words: usize,
+    /// Number of words that contain each character at least once.
+    pub overall: BTreeMap<char, usize>,
+    /// Number of words that have each character at each position.
+    pub positional: Vec<BTreeMap<char, usize>>,
+}
+
+impl Frequencies {
+    /// Counts the characters of the words in `wordlist`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{freq::Frequencies, Word, Wordlist};
+    /// let wordlist: Wordlist = ["geese", "crate"].iter().map(Word::from).collect();
+    /// let freq = Frequencies::new(&wordlist);
+    ///
+    /// assert_eq!(freq.overall[&'e'], 2);
+    /// assert_eq!(freq.positional[1].get(&'e'), Some(&1));
+    /// assert_eq!(freq.ranked()[0], ('e', 2));
+    /// ```
+    pub fn new(wordlist: &Wordlist) -> Self {
+        let mut freq = Self::default();
+
+        for word in wordlist {
+            freq.words += 1;
+
+            for c in word.chars().unique() {
+                *freq.overall.entry(c).or_default() += 1;
+            }
+
+            for (i, c) in word.chars().enumerate() {
+                if freq.positional.len() <= i {
+                    freq.positional.push(BTreeMap::new());
+                }
+
+                *freq.positional[i].entry(c).or_default() += 1;
+            }
+        }
+
+        freq
+    }
+
+    /// Returns the characters and the number of words that contain them, most frequent
+    /// first. Characters with the same frequency are sorted alphabetically.
+    pub fn ranked(&self) -> Vec<(char, usize)> {
+        self.overall
+            .iter()
+            .map(|(&c, &n)| (c, n))
+            .sorted_by(|(c1, n1), (c2, n2)| n2.cmp(n1).then(c1.cmp(c2)))
+            .collect()
+    }
+
+    /// Returns the number of words that have `c` at position `i`.
+    pub fn at(&self, i: usize, c: char) -> usize {
+        self.positional
+            .get(i)
+            .and_then(|counts| counts.get(&c))
+            .copied()
+            .unwrap_or(0)
+    }
+}
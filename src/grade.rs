@@ -0,0 +1,156 @@
+//! Grades of the guesses of a game, split into skill and luck.
+//!
+//! Finding the word in two rounds is not necessarily good play: the first guess may have
+//! been poor and the feedback lucky. The grade of a guess therefore separates how good the
+//! guess was compared to the best candidate (skill) from how many candidates the feedback
+//! actually left compared to the number expected for the guess (luck).
+use crate::{
+    feedback::{FeedbackRule, WordleRule},
+    ConstraintSet, Word, Wordlist,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+/// The grade of one guess
+pub struct Grade {
+    /// The word guessed.
+    pub guess: Word,
+    /// Number of words that were possible before the guess.
+    pub candidates: usize,
+    /// Number of buckets into which the guess sorts the candidates.
+    pub buckets: usize,
+    /// The best-ranked word, i.e. the one that sorts the candidates into the most buckets.
+    /// This is the guess itself if no candidate is better.
+    pub best: Word,
+    /// Number of buckets into which `best` sorts the candidates.
+    pub best_buckets: usize,
+    /// Number of candidates expected to be left after the guess, if each candidate is
+    /// equally likely to be the mystery word.
+    pub expected_left: f64,
+    /// Number of candidates actually left after the guess.
+    pub left: usize,
+}
+
+impl Grade {
+    /// Returns the skill of the guess as the ratio of its buckets to those of the best
+    /// word, between 0 and 1.
+    pub fn skill(&self) -> f64 {
+        if self.best_buckets == 0 {
+            return 1.0;
+        }
+
+        self.buckets as f64 / self.best_buckets as f64
+    }
+
+    /// Returns the luck of the guess in bits: positive if fewer candidates are left than
+    /// expected, negative if more are left.
+    pub fn luck(&self) -> f64 {
+        if self.left == 0 {
+            return 0.0;
+        }
+
+        (self.expected_left / self.left as f64).log2()
+    }
+}
+
+/// Grades each guess in `turns`, starting with the words in `wordlist` as candidates.
+/// Grading stops when no candidate is left.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{grade, ConstraintSet, Word, Wordlist};
+/// let wordlist: Wordlist = ["crate", "trace", "react", "spoon"].iter().map(Word::from).collect();
+/// let turns = [ConstraintSet::try_from(("spoon", "GGGGG")).unwrap()];
+///
+/// let grades = grade::grade(&wordlist, &turns);
+///
+/// // "crate" would have told all words apart, but "spoon" happened to be right.
+/// assert_eq!(grades[0].best, Word::from("crate"));
+/// assert!(grades[0].skill() < 1.0);
+/// assert!(grades[0].luck() > 0.0);
+/// ```
+pub fn grade(wordlist: &Wordlist, turns: &[ConstraintSet]) -> Vec<Grade> {
+    let mut candidates = wordlist.clone();
+    let mut grades = vec![];
+
+    for constraints in turns {
+        if candidates.is_empty() {
+            break;
+        }
+
+        let guess = Word::from(constraints.word());
+        let n = candidates.len();
+
+        let sizes: Vec<_> = WordleRule
+            .buckets(&guess, &candidates)
+            .into_values()
+            .map(|words| words.len())
+            .collect();
+        let expected_left = sizes.iter().map(|&k| (k * k) as f64).sum::<f64>() / n as f64;
+
+        let (best, best_buckets) = match candidates.rank_words().next() {
+            Some((w, score)) if score > sizes.len() => (w.clone(), score),
+            _ => (guess.clone(), sizes.len()),
+        };
+
+        candidates = Wordlist::from_iter(candidates.filter(constraints));
+
+        grades.push(Grade {
+            guess,
+            candidates: n,
+            buckets: sizes.len(),
+            best,
+            best_buckets,
+            expected_left,
+            left: candidates.len(),
+        });
+    }
+
+    grades
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wordlist(words: &[&str]) -> Wordlist {
+        words.iter().map(Word::from).collect()
+    }
+
+    #[test]
+    fn test_grade() {
+        let words = wordlist(&["batch", "catch", "hatch", "latch", "crate"]);
+        let turns = [
+            ConstraintSet::try_from(("batch", "_GGGG")).unwrap(),
+            ConstraintSet::try_from(("latch", "GGGGG")).unwrap(),
+        ];
+
+        let grades = grade(&words, &turns);
+
+        assert_eq!(grades.len(), 2);
+        assert_eq!(grades[0].candidates, 5);
+        assert_eq!(grades[0].left, 3);
+        // The buckets have the sizes 1, 3 and 1.
+        assert!((grades[0].expected_left - 11.0 / 5.0).abs() < 1e-9);
+        assert!(grades[0].luck() < 0.0);
+
+        assert_eq!(grades[1].candidates, 3);
+        assert_eq!(grades[1].left, 1);
+        assert_eq!(grades[1].skill(), 1.0);
+    }
+
+    #[test]
+    fn test_no_candidates_left() {
+        let words = wordlist(&["crate"]);
+        let turns = [
+            ConstraintSet::try_from(("spoon", "G____")).unwrap(),
+            ConstraintSet::try_from(("crate", "GGGGG")).unwrap(),
+        ];
+
+        let grades = grade(&words, &turns);
+
+        assert_eq!(grades.len(), 1);
+        assert_eq!(grades[0].left, 0);
+        assert_eq!(grades[0].luck(), 0.0);
+    }
+}
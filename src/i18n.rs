@@ -0,0 +1,1382 @@
+//! Translations of the messages shown to the user.
+use std::{env, fmt, str::FromStr};
+
+use itertools::Itertools;
+
+use crate::{color_symbols, join_alternatives, Cleanup, Constraint, Error, Mismatch};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// A language in which messages can be shown
+pub enum Lang {
+    #[default]
+    /// English
+    En,
+    /// German
+    De,
+}
+
+impl Lang {
+    /// All supported languages.
+    pub const ALL: [Lang; 2] = [Lang::En, Lang::De];
+
+    /// Returns the language code used to select the language.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::De => "de",
+        }
+    }
+
+    /// Determines the language from the locale environment variables (`LC_ALL`,
+    /// `LC_MESSAGES`, `LANG`). Falls back to English if no supported language is set.
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| value.get(..2).and_then(|code| code.parse().ok()))
+            .unwrap_or_default()
+    }
+
+    /// Returns the text of `msg` in this language.
+    pub fn tr(&self, msg: Message) -> String {
+        use Message::*;
+
+        match self {
+            Lang::En => match msg {
+                Welcome => "Welcome! Let's play Wordle.".to_string(),
+                Round(i) => format!("Round #{}", i),
+                CandidatesLeft(n) => format!("{} candidate word{} left.", n, plural_en(n)),
+                TopCandidates(n) => format!("Top candidate word{}:", plural_en(n)),
+                Probabilities => "Probability of each candidate word:".to_string(),
+                TopCandidate(w) => format!("Top candidate word: {}", w),
+                EnterWord(1) => "Please enter your first word.".to_string(),
+                EnterWord(_) => "Please enter your next word.".to_string(),
+                EnterCode => {
+                    "Please enter Wordle's answer. (G = Green, Y = Yellow, _ = Gray)".to_string()
+                }
+                Won(i) => format!("Congratulations! You won after {} round{}.", i, plural_en(i)),
+                SolverWon(i) => format!("I won after {} round{}.", i, plural_en(i)),
+                GameOver => "Game over.".to_string(),
+                NoWordsLeft => "Something went wrong. There are no matching words left.".to_string(),
+                LikelyTypo {
+                    round,
+                    position,
+                    code,
+                    candidates,
+                } => format!(
+                    "Maybe the color at position {} in round {} is wrong: with {} there would be {} candidate word{}.",
+                    position,
+                    round,
+                    code,
+                    candidates,
+                    plural_en(candidates)
+                ),
+                NoTypoFound => "No single wrong color explains this.".to_string(),
+                RecoveryOptions { word, fix: true } => format!(
+                    "Enter `undo` to undo the round, `retry` to enter the colors for '{}' again, `fix` to correct the color or nothing to end the game.",
+                    word
+                ),
+                RecoveryOptions { word, fix: false } => format!(
+                    "Enter `undo` to undo the round, `retry` to enter the colors for '{}' again or nothing to end the game.",
+                    word
+                ),
+                Fixed(round) => format!("The colors of round {} have been corrected.", round),
+                NothingToUndo => "There is no round to undo.".to_string(),
+                Undone(i) => format!("Round {} has been undone.", i),
+                EnterSaveFile => "Enter the file to save the game to:".to_string(),
+                GameSaved(path) => format!(
+                    "The game has been saved to '{}'. Continue it with `prompter play --resume {}`.",
+                    path, path
+                ),
+                CustomWordsNotSaved => "Games with words from --wordlist or --used-answers cannot be saved or resumed, as the save file does not record them.".to_string(),
+                TopChanged(n) => format!("From now on, {} word{} will be suggested.", n, plural_en(n)),
+                InvalidTop => "Enter the number of words to suggest, e.g. `:top 5`.".to_string(),
+                GameResumed(rounds) => format!(
+                    "The saved game has been resumed after {} {}.",
+                    rounds,
+                    if rounds == 1 { "round" } else { "rounds" }
+                ),
+                ReplayStart { preset, rounds, hard } => format!(
+                    "Replay of a game of {}{} with {} recorded {}.",
+                    preset,
+                    if hard { " in hard mode" } else { "" },
+                    rounds,
+                    if rounds == 1 { "round" } else { "rounds" }
+                ),
+                Guessed(w) => format!("Guess: {}", w),
+                PressEnter => "Press Enter to see the next round.".to_string(),
+                GameUnfinished => "The game was not finished.".to_string(),
+                RandomSeed(seed) => format!("Random seed: {} (repeat this run with `--seed {}`)", seed, seed),
+                Error(e) => format!("Error: {}", e),
+                InvalidInput(e) => format!("Error: {}", self.error(e)),
+                Warning(e) => format!("Warning: {}", self.error(e)),
+                Help { gray_aliases } => format!(
+                    "Commands:\n  \
+                     :help       show this help\n  \
+                     :quit       end the game (like Ctrl+D)\n\
+                     Instead of a word in `play`:\n  \
+                     list        show all words that are still possible\n  \
+                     undo        go back to the previous round\n  \
+                     :top N      suggest N words in each round\n  \
+                     :save FILE  save the game to FILE\n\
+                     Color codes have one character per letter: G = Green, Y = Yellow and _ = Gray{}, e.g. _Y__G.",
+                    gray_aliases_note(gray_aliases, "also", "or")
+                ),
+                Bye => "Bye.".to_string(),
+                Hint(code) => format!("Wordle hint: {}", code),
+                GameWon(s, t, i) => format!("{} -> {}: Won after {} round{}", s, t, i, plural_en(i)),
+                GameLost(s, t) => format!("{} -> {}: Lost", s, t),
+                Results {
+                    start,
+                    won,
+                    games,
+                    percentage,
+                    avg_rounds,
+                } => format!(
+                    "With start word \"{}\", I won {} / {} games ({:.2} %) in on average {:.2} rounds.",
+                    start, won, games, percentage, avg_rounds
+                ),
+                SpeedRun => "Speed run".to_string(),
+                RoundTime(i, time) => format!("Round #{}: {}", i, time),
+                BestTime(time) => format!("(best: {})", time),
+                TotalTime(time) => format!("Total time: {}", time),
+                NewPersonalBest(Some(time)) => format!("New personal best! (previous: {})", time),
+                NewPersonalBest(None) => "New personal best!".to_string(),
+                PersonalBest(time) => format!("Personal best: {}", time),
+                AverageTime(n, time) => {
+                    format!("Average time of {} won game{}: {}", n, plural_en(n), time)
+                }
+                StatsUnavailable => "Could not determine the location of the stats file.".to_string(),
+                Statistics => "Statistics".to_string(),
+                StatsSummary {
+                    played,
+                    win_percentage,
+                    current_streak,
+                    max_streak,
+                } => format!(
+                    "Played: {}\nWin %: {}\nCurrent streak: {}\nMax streak: {}",
+                    played, win_percentage, current_streak, max_streak
+                ),
+                GuessDistribution => "Guess distribution".to_string(),
+                NoGamesPlayed => "No games have been played yet.".to_string(),
+                DailyPuzzle(title, n) => format!("Today's {} is #{}.", title, n),
+                PuzzleAlreadyPlayed(title, n) => {
+                    format!("You have already played {} #{}.", title, n)
+                }
+                NoDailyPuzzle(title) => format!("The numbers of the daily {} puzzles are unknown.", title),
+                CacheCleared(n, dir) => format!(
+                    "{} cached table{} removed from '{}'.",
+                    n,
+                    plural_en(n),
+                    dir
+                ),
+                DamagedRecords(n) => format!(
+                    "{} damaged line{} of the stats file skipped.",
+                    n,
+                    plural_en(n)
+                ),
+                GamesImported { imported, skipped } => format!(
+                    "{} game{} imported, {} already recorded.",
+                    imported,
+                    plural_en(imported),
+                    skipped
+                ),
+                Buckets(w, n) => format!("\"{}\" has {} Wordle buckets.", w, n),
+                Bucket(code, n) => format!("{} ({} word{})", code, n, plural_en(n)),
+                BucketEntropy {
+                    code,
+                    words,
+                    probability,
+                    bits,
+                } => format!(
+                    "{} ({} word{}, p = {:.2} %, {:.3} bits)",
+                    code,
+                    words,
+                    plural_en(words),
+                    probability * 100.0,
+                    bits
+                ),
+                Entropy {
+                    entropy,
+                    remaining,
+                    total,
+                } => format!(
+                    "Entropy: {:.2} bits, remaining: {:.2} bits (of {:.2} bits)",
+                    entropy, remaining, total
+                ),
+                Board(k, n) => format!("Board #{} ({} candidate word{} left)", k, n, plural_en(n)),
+                BoardSolved(k, i) => format!("Board #{} (solved in round {})", k, i),
+                EnterBoardCode(k) => format!(
+                    "Please enter Wordle's answer for board #{}. (G = Green, Y = Yellow, _ = Gray)",
+                    k
+                ),
+                AllBoardsSolved(i) => format!(
+                    "Congratulations! You solved all boards after {} round{}.",
+                    i,
+                    plural_en(i)
+                ),
+                BoardsSolved(k, n) => format!("{} / {} boards solved.", k, n),
+                RoundsLeft(n) => format!("{} round{} left.", n, plural_en(n)),
+                Survived(i) => format!("Game over. You survived {} round{}.", i, plural_en(i)),
+                SolverSurvived(i) => format!("I survived {} round{}.", i, plural_en(i)),
+                GameSurvived(s, t, i) => {
+                    format!("{} -> {}: Survived {} round{}", s, t, i, plural_en(i))
+                }
+                PairsLeft(n) => format!("{} candidate pair{} left.", n, plural_en(n)),
+                Hypothesis(None, n) => {
+                    format!("No lie so far: {} candidate word{}", n, plural_en(n))
+                }
+                Hypothesis(Some(i), n) => format!(
+                    "Round #{} lied: {} candidate word{}",
+                    i,
+                    n,
+                    plural_en(n)
+                ),
+                WordFound(w) => format!("Found \"{}\"! One word to go.", w),
+                BothWordsFound(i) => format!(
+                    "Congratulations! You found both words after {} round{}.",
+                    i,
+                    plural_en(i)
+                ),
+                SurvivalResults {
+                    start,
+                    games,
+                    avg_rounds,
+                } => format!(
+                    "With start word \"{}\", I survived {} games for on average {:.2} rounds.",
+                    start, games, avg_rounds
+                ),
+                BaselineSaved(path) => {
+                    format!("The results have been saved as the baseline '{}'.", path)
+                }
+                MoreLosses {
+                    start,
+                    before,
+                    after,
+                } => format!(
+                    "{}: {} game{} lost instead of {}",
+                    start,
+                    after,
+                    plural_en(after),
+                    before
+                ),
+                WorseAverage {
+                    start,
+                    before,
+                    after,
+                } => format!(
+                    "{}: on average {:.3} rounds instead of {:.3}",
+                    start, after, before
+                ),
+                GamesChanged {
+                    start,
+                    before,
+                    after,
+                } => format!(
+                    "{}: {} game{} played instead of {}, so the results cannot be compared",
+                    start,
+                    after,
+                    plural_en(after),
+                    before
+                ),
+                BaselineResults {
+                    compared,
+                    regressions: 0,
+                } => format!(
+                    "No regressions compared to the baseline ({} start word{} compared).",
+                    compared,
+                    plural_en(compared)
+                ),
+                BaselineResults {
+                    compared,
+                    regressions,
+                } => format!(
+                    "{} regression{} compared to the baseline ({} start word{} compared).",
+                    regressions,
+                    plural_en(regressions),
+                    compared,
+                    plural_en(compared)
+                ),
+                BestOpenings(k) => format!("Best sets of {} opening word{}:", k, plural_en(k)),
+                Frequencies(n) => format!(
+                    "Number of the {} candidate word{} that contain each letter:",
+                    n,
+                    plural_en(n)
+                ),
+                AllPositions => "all".to_string(),
+                DuelGame {
+                    target,
+                    names,
+                    rounds,
+                } => format!(
+                    "{}: {} {}, {} {}",
+                    target, names[0], rounds[0], names[1], rounds[1]
+                ),
+                GuessGrade {
+                    round,
+                    guess,
+                    skill,
+                    best,
+                    luck,
+                    expected_left,
+                    left,
+                } => format!(
+                    "Round #{} {}: skill {:.0} % (best: {}), luck {:+.2} bits ({:.1} words expected, {} left)",
+                    round,
+                    guess,
+                    skill * 100.0,
+                    best,
+                    luck,
+                    expected_left,
+                    left
+                ),
+                GameGrade { skill, luck } => format!(
+                    "Skill: {:.0} % on average, luck: {:+.2} bits in total",
+                    skill * 100.0,
+                    luck
+                ),
+                DuelResults {
+                    names,
+                    starts,
+                    wins,
+                    ties,
+                    avg_rounds,
+                } => format!(
+                    "{} (\"{}\"): {} win{}, on average {:.2} rounds\n{} (\"{}\"): {} win{}, on average {:.2} rounds\n{} tie{}",
+                    names[0],
+                    starts[0],
+                    wins[0],
+                    plural_en(wins[0]),
+                    avg_rounds[0],
+                    names[1],
+                    starts[1],
+                    wins[1],
+                    plural_en(wins[1]),
+                    avg_rounds[1],
+                    ties,
+                    plural_en(ties)
+                ),
+                VerifyFailure { target, guesses } => {
+                    format!("{}: not found with {}", target, guesses)
+                }
+                VerifyResults {
+                    start,
+                    words,
+                    failures: 0,
+                    rounds,
+                } => format!(
+                    "All {} word{} found within {} rounds, starting with '{}'.",
+                    words,
+                    plural_en(words),
+                    rounds,
+                    start
+                ),
+                VerifyResults {
+                    start,
+                    words,
+                    failures,
+                    rounds,
+                } => format!(
+                    "{} of {} word{} not found within {} rounds, starting with '{}'.",
+                    failures,
+                    words,
+                    plural_en(words),
+                    rounds,
+                    start
+                ),
+                WordlistInfo {
+                    name,
+                    word_len,
+                    words,
+                } => format!("{}: {} word{} with {} characters", name, words, plural_en(words), word_len),
+                WordlistCleaned { path, cleanup } => format!(
+                    "Cleaned up the wordlist '{}': {} word{} trimmed or lowercased, {} blank line{} and {} duplicate{} removed",
+                    path,
+                    cleanup.normalized,
+                    plural_en(cleanup.normalized),
+                    cleanup.blank,
+                    plural_en(cleanup.blank),
+                    cleanup.duplicates,
+                    plural_en(cleanup.duplicates)
+                ),
+                ExpectedTurns(turns) => format!("expected rounds: {:.2}", turns),
+                Obscure => "obscure".to_string(),
+                StillCandidate(w) => format!("\"{}\" is still a candidate.", w),
+                MatchesAllHints(w) => format!("\"{}\" matches all hints.", w),
+                NotInWordlist(w) => format!("\"{}\" is not in the wordlist.", w),
+                Eliminated {
+                    word,
+                    turn,
+                    mismatch,
+                } => format!(
+                    "\"{}\" is ruled out by {}: {}.",
+                    word,
+                    turn,
+                    mismatch_en(mismatch)
+                ),
+            },
+            Lang::De => match msg {
+                Welcome => "Willkommen! Lass uns Wordle spielen.".to_string(),
+                Round(i) => format!("Runde {}", i),
+                CandidatesLeft(1) => "1 mögliches Wort übrig.".to_string(),
+                CandidatesLeft(n) => format!("{} mögliche Wörter übrig.", n),
+                TopCandidates(1) => "Bester Kandidat:".to_string(),
+                TopCandidates(_) => "Beste Kandidaten:".to_string(),
+                Probabilities => "Wahrscheinlichkeit jedes möglichen Wortes:".to_string(),
+                TopCandidate(w) => format!("Bester Kandidat: {}", w),
+                EnterWord(1) => "Bitte gib dein erstes Wort ein.".to_string(),
+                EnterWord(_) => "Bitte gib dein nächstes Wort ein.".to_string(),
+                EnterCode => {
+                    "Bitte gib Wordles Antwort ein. (G = Grün, Y = Gelb, _ = Grau)".to_string()
+                }
+                Won(1) => "Herzlichen Glückwunsch! Du hast nach 1 Runde gewonnen.".to_string(),
+                Won(i) => format!("Herzlichen Glückwunsch! Du hast nach {} Runden gewonnen.", i),
+                SolverWon(1) => "Ich habe nach 1 Runde gewonnen.".to_string(),
+                SolverWon(i) => format!("Ich habe nach {} Runden gewonnen.", i),
+                GameOver => "Spiel vorbei.".to_string(),
+                NoWordsLeft => "Etwas ist schiefgelaufen. Es passt kein Wort mehr.".to_string(),
+                LikelyTypo {
+                    round,
+                    position,
+                    code,
+                    candidates: 1,
+                } => format!(
+                    "Vielleicht ist die Farbe an Position {} in Runde {} falsch: mit {} gäbe es 1 mögliches Wort.",
+                    position, round, code
+                ),
+                LikelyTypo {
+                    round,
+                    position,
+                    code,
+                    candidates,
+                } => format!(
+                    "Vielleicht ist die Farbe an Position {} in Runde {} falsch: mit {} gäbe es {} mögliche Wörter.",
+                    position, round, code, candidates
+                ),
+                NoTypoFound => "Keine einzelne falsche Farbe erklärt das.".to_string(),
+                RecoveryOptions { word, fix: true } => format!(
+                    "Gib `undo` ein, um die Runde rückgängig zu machen, `retry`, um die Farben für '{}' erneut einzugeben, `fix`, um die Farbe zu korrigieren, oder nichts, um das Spiel zu beenden.",
+                    word
+                ),
+                RecoveryOptions { word, fix: false } => format!(
+                    "Gib `undo` ein, um die Runde rückgängig zu machen, `retry`, um die Farben für '{}' erneut einzugeben, oder nichts, um das Spiel zu beenden.",
+                    word
+                ),
+                Fixed(round) => format!("Die Farben von Runde {} wurden korrigiert.", round),
+                NothingToUndo => "Es gibt keine Runde zum Rückgängigmachen.".to_string(),
+                Undone(i) => format!("Runde {} wurde rückgängig gemacht.", i),
+                EnterSaveFile => "Gib die Datei ein, in der das Spiel gespeichert werden soll:"
+                    .to_string(),
+                GameSaved(path) => format!(
+                    "Das Spiel wurde in '{}' gespeichert. Setze es mit `prompter play --resume {}` fort.",
+                    path, path
+                ),
+                CustomWordsNotSaved => "Spiele mit Wörtern aus --wordlist oder --used-answers können weder gespeichert noch fortgesetzt werden, da die Datei sie nicht enthält.".to_string(),
+                TopChanged(n) => format!(
+                    "Ab jetzt {} {} vorgeschlagen.",
+                    if n == 1 { "wird" } else { "werden" },
+                    if n == 1 { "1 Wort".to_string() } else { format!("{} Wörter", n) }
+                ),
+                InvalidTop => {
+                    "Gib die Anzahl der vorgeschlagenen Wörter ein, z. B. `:top 5`.".to_string()
+                }
+                GameResumed(rounds) => format!(
+                    "Das gespeicherte Spiel wird nach {} {} fortgesetzt.",
+                    rounds,
+                    if rounds == 1 { "Runde" } else { "Runden" }
+                ),
+                ReplayStart { preset, rounds, hard } => format!(
+                    "Wiederholung einer Partie {}{} mit {} aufgezeichneten {}.",
+                    preset,
+                    if hard { " im schweren Modus" } else { "" },
+                    rounds,
+                    if rounds == 1 { "Runde" } else { "Runden" }
+                ),
+                Guessed(w) => format!("Geraten: {}", w),
+                PressEnter => "Drücke Enter, um die nächste Runde zu sehen.".to_string(),
+                GameUnfinished => "Das Spiel wurde nicht beendet.".to_string(),
+                RandomSeed(seed) => format!(
+                    "Zufallswert: {} (wiederhole diesen Lauf mit `--seed {}`)",
+                    seed, seed
+                ),
+                Error(e) => format!("Fehler: {}", e),
+                InvalidInput(e) => format!("Fehler: {}", self.error(e)),
+                Warning(e) => format!("Warnung: {}", self.error(e)),
+                Help { gray_aliases } => format!(
+                    "Befehle:\n  \
+                     :help       diese Hilfe anzeigen\n  \
+                     :quit       das Spiel beenden (wie Strg+D)\n\
+                     Statt eines Worts in `play`:\n  \
+                     list        alle noch möglichen Wörter anzeigen\n  \
+                     undo        zur vorherigen Runde zurückgehen\n  \
+                     :top N      in jeder Runde N Wörter vorschlagen\n  \
+                     :save FILE  das Spiel in FILE speichern\n\
+                     Farbcodes haben ein Zeichen pro Buchstabe: G = Grün, Y = Gelb und _ = Grau{}, z. B. _Y__G.",
+                    gray_aliases_note(gray_aliases, "auch", "oder")
+                ),
+                Bye => "Tschüss.".to_string(),
+                Hint(code) => format!("Wordles Hinweis: {}", code),
+                GameWon(s, t, 1) => format!("{} -> {}: Gewonnen nach 1 Runde", s, t),
+                GameWon(s, t, i) => format!("{} -> {}: Gewonnen nach {} Runden", s, t, i),
+                GameLost(s, t) => format!("{} -> {}: Verloren", s, t),
+                Results {
+                    start,
+                    won,
+                    games,
+                    percentage,
+                    avg_rounds,
+                } => format!(
+                    "Mit dem Startwort \"{}\" habe ich {} / {} Spiele ({:.2} %) in durchschnittlich {:.2} Runden gewonnen.",
+                    start, won, games, percentage, avg_rounds
+                ),
+                SpeedRun => "Speedrun".to_string(),
+                RoundTime(i, time) => format!("Runde {}: {}", i, time),
+                BestTime(time) => format!("(Bestzeit: {})", time),
+                TotalTime(time) => format!("Gesamtzeit: {}", time),
+                NewPersonalBest(Some(time)) => format!("Neue Bestzeit! (bisher: {})", time),
+                NewPersonalBest(None) => "Neue Bestzeit!".to_string(),
+                PersonalBest(time) => format!("Bestzeit: {}", time),
+                AverageTime(1, time) => format!("Durchschnittszeit von 1 gewonnenen Spiel: {}", time),
+                AverageTime(n, time) => {
+                    format!("Durchschnittszeit von {} gewonnenen Spielen: {}", n, time)
+                }
+                StatsUnavailable => "Der Speicherort der Statistikdatei ist unbekannt.".to_string(),
+                Statistics => "Statistik".to_string(),
+                StatsSummary {
+                    played,
+                    win_percentage,
+                    current_streak,
+                    max_streak,
+                } => format!(
+                    "Gespielt: {}\nGewonnen %: {}\nAktuelle Serie: {}\nLängste Serie: {}",
+                    played, win_percentage, current_streak, max_streak
+                ),
+                GuessDistribution => "Verteilung der Versuche".to_string(),
+                NoGamesPlayed => "Es wurden noch keine Spiele gespielt.".to_string(),
+                DailyPuzzle(title, n) => format!("Das heutige {} ist Nr. {}.", title, n),
+                PuzzleAlreadyPlayed(title, n) => format!("Du hast {} Nr. {} schon gespielt.", title, n),
+                NoDailyPuzzle(title) => {
+                    format!("Die Nummern der täglichen Rätsel von {} sind unbekannt.", title)
+                }
+                CacheCleared(n, dir) => format!(
+                    "{} {} aus '{}' entfernt.",
+                    n,
+                    if n == 1 { "Tabelle" } else { "Tabellen" },
+                    dir
+                ),
+                DamagedRecords(n) => format!(
+                    "{} beschädigte {} der Statistikdatei übersprungen.",
+                    n,
+                    if n == 1 { "Zeile" } else { "Zeilen" }
+                ),
+                GamesImported { imported, skipped } => format!(
+                    "{} {} importiert, {} schon vorhanden.",
+                    imported,
+                    if imported == 1 { "Spiel" } else { "Spiele" },
+                    skipped
+                ),
+                Buckets(w, n) => format!("\"{}\" hat {} Wordle-Gruppen.", w, n),
+                Bucket(code, 1) => format!("{} (1 Wort)", code),
+                Bucket(code, n) => format!("{} ({} Wörter)", code, n),
+                BucketEntropy {
+                    code,
+                    words,
+                    probability,
+                    bits,
+                } => format!(
+                    "{} ({} {}, p = {:.2} %, {:.3} Bit)",
+                    code,
+                    words,
+                    if words == 1 { "Wort" } else { "Wörter" },
+                    probability * 100.0,
+                    bits
+                ),
+                Entropy {
+                    entropy,
+                    remaining,
+                    total,
+                } => format!(
+                    "Entropie: {:.2} Bit, verbleibend: {:.2} Bit (von {:.2} Bit)",
+                    entropy, remaining, total
+                ),
+                Board(k, 1) => format!("Brett {} (1 mögliches Wort übrig)", k),
+                Board(k, n) => format!("Brett {} ({} mögliche Wörter übrig)", k, n),
+                BoardSolved(k, i) => format!("Brett {} (gelöst in Runde {})", k, i),
+                EnterBoardCode(k) => format!(
+                    "Bitte gib Wordles Antwort für Brett {} ein. (G = Grün, Y = Gelb, _ = Grau)",
+                    k
+                ),
+                AllBoardsSolved(1) => {
+                    "Herzlichen Glückwunsch! Du hast alle Bretter nach 1 Runde gelöst.".to_string()
+                }
+                AllBoardsSolved(i) => format!(
+                    "Herzlichen Glückwunsch! Du hast alle Bretter nach {} Runden gelöst.",
+                    i
+                ),
+                BoardsSolved(k, n) => format!("{} / {} Bretter gelöst.", k, n),
+                RoundsLeft(1) => "1 Runde übrig.".to_string(),
+                RoundsLeft(n) => format!("{} Runden übrig.", n),
+                Survived(1) => "Spiel vorbei. Du hast 1 Runde überlebt.".to_string(),
+                Survived(i) => format!("Spiel vorbei. Du hast {} Runden überlebt.", i),
+                SolverSurvived(1) => "Ich habe 1 Runde überlebt.".to_string(),
+                SolverSurvived(i) => format!("Ich habe {} Runden überlebt.", i),
+                GameSurvived(s, t, 1) => format!("{} -> {}: 1 Runde überlebt", s, t),
+                GameSurvived(s, t, i) => format!("{} -> {}: {} Runden überlebt", s, t, i),
+                PairsLeft(1) => "1 mögliches Wortpaar übrig.".to_string(),
+                Hypothesis(None, 1) => "Bisher keine Lüge: 1 mögliches Wort".to_string(),
+                Hypothesis(None, n) => format!("Bisher keine Lüge: {} mögliche Wörter", n),
+                Hypothesis(Some(i), 1) => format!("Runde {} hat gelogen: 1 mögliches Wort", i),
+                Hypothesis(Some(i), n) => {
+                    format!("Runde {} hat gelogen: {} mögliche Wörter", i, n)
+                }
+                PairsLeft(n) => format!("{} mögliche Wortpaare übrig.", n),
+                WordFound(w) => format!("\"{}\" gefunden! Noch ein Wort.", w),
+                BothWordsFound(1) => {
+                    "Herzlichen Glückwunsch! Du hast beide Wörter nach 1 Runde gefunden.".to_string()
+                }
+                BothWordsFound(i) => format!(
+                    "Herzlichen Glückwunsch! Du hast beide Wörter nach {} Runden gefunden.",
+                    i
+                ),
+                SurvivalResults {
+                    start,
+                    games,
+                    avg_rounds,
+                } => format!(
+                    "Mit dem Startwort \"{}\" habe ich {} Spiele durchschnittlich {:.2} Runden überlebt.",
+                    start, games, avg_rounds
+                ),
+                BaselineSaved(path) => format!(
+                    "Die Ergebnisse wurden als Vergleichsbasis '{}' gespeichert.",
+                    path
+                ),
+                MoreLosses {
+                    start,
+                    before,
+                    after,
+                } => format!(
+                    "{}: {} {} verloren statt {}",
+                    start,
+                    after,
+                    if after == 1 { "Spiel" } else { "Spiele" },
+                    before
+                ),
+                WorseAverage {
+                    start,
+                    before,
+                    after,
+                } => format!(
+                    "{}: durchschnittlich {:.3} Runden statt {:.3}",
+                    start, after, before
+                ),
+                GamesChanged {
+                    start,
+                    before,
+                    after,
+                } => format!(
+                    "{}: {} {} statt {} gespielt, daher sind die Ergebnisse nicht vergleichbar",
+                    start,
+                    after,
+                    if after == 1 { "Spiel" } else { "Spiele" },
+                    before
+                ),
+                BaselineResults {
+                    compared,
+                    regressions: 0,
+                } => format!(
+                    "Keine Verschlechterungen gegenüber der Vergleichsbasis ({} {} verglichen).",
+                    compared,
+                    if compared == 1 { "Startwort" } else { "Startwörter" }
+                ),
+                BaselineResults {
+                    compared,
+                    regressions,
+                } => format!(
+                    "{} {} gegenüber der Vergleichsbasis ({} {} verglichen).",
+                    regressions,
+                    if regressions == 1 {
+                        "Verschlechterung"
+                    } else {
+                        "Verschlechterungen"
+                    },
+                    compared,
+                    if compared == 1 { "Startwort" } else { "Startwörter" }
+                ),
+                BestOpenings(1) => "Beste Startwörter:".to_string(),
+                BestOpenings(k) => format!("Beste Kombinationen aus {} Startwörtern:", k),
+                Frequencies(n) => format!(
+                    "Anzahl der {} möglichen Wörter, die jeden Buchstaben enthalten:",
+                    n
+                ),
+                AllPositions => "alle".to_string(),
+                DuelGame {
+                    target,
+                    names,
+                    rounds,
+                } => format!(
+                    "{}: {} {}, {} {}",
+                    target, names[0], rounds[0], names[1], rounds[1]
+                ),
+                GuessGrade {
+                    round,
+                    guess,
+                    skill,
+                    best,
+                    luck,
+                    expected_left,
+                    left,
+                } => format!(
+                    "Runde {} {}: Können {:.0} % (bestes Wort: {}), Glück {:+.2} Bit ({:.1} Wörter erwartet, {} übrig)",
+                    round,
+                    guess,
+                    skill * 100.0,
+                    best,
+                    luck,
+                    expected_left,
+                    left
+                ),
+                GameGrade { skill, luck } => format!(
+                    "Können: {:.0} % im Durchschnitt, Glück: {:+.2} Bit insgesamt",
+                    skill * 100.0,
+                    luck
+                ),
+                DuelResults {
+                    names,
+                    starts,
+                    wins,
+                    ties,
+                    avg_rounds,
+                } => format!(
+                    "{} (\"{}\"): {} Mal besser, durchschnittlich {:.2} Runden\n{} (\"{}\"): {} Mal besser, durchschnittlich {:.2} Runden\n{} Mal unentschieden",
+                    names[0],
+                    starts[0],
+                    wins[0],
+                    avg_rounds[0],
+                    names[1],
+                    starts[1],
+                    wins[1],
+                    avg_rounds[1],
+                    ties
+                ),
+                VerifyFailure { target, guesses } => {
+                    format!("{}: nicht gefunden mit {}", target, guesses)
+                }
+                VerifyResults {
+                    start,
+                    words,
+                    failures: 0,
+                    rounds,
+                } => format!(
+                    "{} in höchstens {} Runden gefunden, beginnend mit '{}'.",
+                    if words == 1 {
+                        "Das eine Wort".to_string()
+                    } else {
+                        format!("Alle {} Wörter", words)
+                    },
+                    rounds,
+                    start
+                ),
+                VerifyResults {
+                    start,
+                    words,
+                    failures,
+                    rounds,
+                } => format!(
+                    "{} von {} {} nicht in höchstens {} Runden gefunden, beginnend mit '{}'.",
+                    failures,
+                    words,
+                    if words == 1 { "Wort" } else { "Wörtern" },
+                    rounds,
+                    start
+                ),
+                WordlistInfo {
+                    name,
+                    word_len,
+                    words,
+                } => format!(
+                    "{}: {} {} mit {} Zeichen",
+                    name,
+                    words,
+                    if words == 1 { "Wort" } else { "Wörter" },
+                    word_len
+                ),
+                WordlistCleaned { path, cleanup } => format!(
+                    "Wortliste '{}' bereinigt: {} {} gekürzt oder kleingeschrieben, {} {} und {} {} entfernt",
+                    path,
+                    cleanup.normalized,
+                    if cleanup.normalized == 1 { "Wort" } else { "Wörter" },
+                    cleanup.blank,
+                    if cleanup.blank == 1 { "Leerzeile" } else { "Leerzeilen" },
+                    cleanup.duplicates,
+                    if cleanup.duplicates == 1 { "Duplikat" } else { "Duplikate" }
+                ),
+                ExpectedTurns(turns) => format!("erwartete Runden: {:.2}", turns),
+                Obscure => "selten".to_string(),
+                StillCandidate(w) => format!("\"{}\" ist noch möglich.", w),
+                MatchesAllHints(w) => format!("\"{}\" passt zu allen Hinweisen.", w),
+                NotInWordlist(w) => format!("\"{}\" steht nicht in der Wortliste.", w),
+                Eliminated {
+                    word,
+                    turn,
+                    mismatch,
+                } => format!(
+                    "\"{}\" ist durch {} ausgeschlossen: {}.",
+                    word,
+                    turn,
+                    mismatch_de(mismatch)
+                ),
+            },
+        }
+    }
+
+    /// Returns the text of the error `e` in this language.
+    pub fn error(&self, e: &Error) -> String {
+        use Error::*;
+
+        match self {
+            Lang::En => e.to_string(),
+            Lang::De => match e {
+                InvalidColorCode {
+                    input,
+                    gray_aliases,
+                } => format!(
+                    "Ungültiges Zeichen '{}' im Farbcode (erlaubt: {})",
+                    input,
+                    join_alternatives(&color_symbols(gray_aliases), "oder")
+                ),
+                IncorrectWordLength { input, len } => format!(
+                    "Das Wort muss {} Zeichen lang sein, aber '{}' hat {}",
+                    len,
+                    input,
+                    input.chars().count()
+                ),
+                IncorrectColorCodeLength { input, len } => format!(
+                    "Der Farbcode muss {} Zeichen lang sein, aber '{}' hat {}",
+                    len,
+                    input,
+                    input.chars().count()
+                ),
+                NotACandidate { input, suggestions } => format!(
+                    "'{}' passt nicht zu den bisherigen Hinweisen (schwerer Modus){}",
+                    input,
+                    meintest_du(suggestions)
+                ),
+                InvalidSymbol(c) => format!("Ungültiges Zeichen '{}'", c),
+                InvalidGuess(w) => format!("'{}' ist kein gültiger Rateversuch", w),
+                InvalidNotation(s) => format!(
+                    "'{}' hat nicht die Form WORT=CODE oder WORT/ZIFFERN (z.B. crate=Y_G_G oder crate/10202)",
+                    s
+                ),
+                InvalidDigitCode(c) => format!(
+                    "Ungültige Ziffer '{}' im Farbcode (erlaubt: 2 = Grün, 1 = Gelb, 0 = Grau)",
+                    c
+                ),
+                InvalidConstraint(s) => format!(
+                    "'{}' ist keine gültige Bedingung für ihre Position (z.B. c@0, r~1 oder a-)",
+                    s
+                ),
+                Contradiction(s) => {
+                    format!("Der Hinweis '{}' widerspricht den bisherigen Hinweisen", s)
+                }
+                UnreadableWordlist { path, reason } => {
+                    format!("Die Wortliste '{}' kann nicht gelesen werden: {}", path, reason)
+                }
+                InvalidWordlistEntries(entries) => format!(
+                    "Die Wortliste enthält ungültige Wörter für dieses Spiel: {}",
+                    entries
+                        .iter()
+                        .map(|(line, word)| format!("'{}' in Zeile {}", word, line))
+                        .join(", ")
+                ),
+                EmptyWordlist => "Die Wortliste enthält keine Wörter für dieses Spiel".to_string(),
+                NoWordsOfLength { len, available } => format!(
+                    "Die Wortliste enthält keine Wörter mit {} Zeichen (vorhanden: {})",
+                    len,
+                    available.iter().join(", ")
+                ),
+                UnreadableFrequencies { path, reason } => format!(
+                    "Die Häufigkeitsdatei '{}' kann nicht gelesen werden: {}",
+                    path, reason
+                ),
+                InvalidFrequencyEntry { line, entry } => format!(
+                    "'{}' in Zeile {} der Häufigkeitsdatei hat nicht die Form WORT GEWICHT",
+                    entry, line
+                ),
+                InvalidMetadataEntry { line, entry } => format!(
+                    "'{}' in Zeile {} der Wortliste hat nicht die Form WORT<TAB>HÄUFIGKEIT<TAB>TAGS",
+                    entry, line
+                ),
+                UnsupportedWordLength(len) => {
+                    format!("Wörter mit {} Zeichen werden nicht unterstützt", len)
+                }
+                ImpossibleCode(positions) => format!(
+                    "Wordle zeigt diese Farben für das Wort nie an, prüfe die Farben an den Positionen {}",
+                    positions.iter().join(", ")
+                ),
+                IncorrectBoardCount(n) => format!(
+                    "Es werden {} Farbcodes benötigt, einer für jedes noch nicht gelöste Brett",
+                    n
+                ),
+                UnknownWord { input, suggestions } => format!(
+                    "'{}' steht nicht in der Wortliste{}",
+                    input,
+                    meintest_du(suggestions)
+                ),
+            },
+        }
+    }
+}
+
+/// Returns the end of a German error message that suggests the words the input was most
+/// likely meant to be, or nothing if there are none.
+fn meintest_du(suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        return String::new();
+    }
+
+    let quoted: Vec<_> = suggestions.iter().map(|w| format!("'{}'", w)).collect();
+
+    format!(", meintest du {}?", join_alternatives(&quoted, "oder"))
+}
+
+impl FromStr for Lang {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Lang::ALL
+            .into_iter()
+            .find(|lang| lang.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let codes: Vec<_> = Lang::ALL.iter().map(|lang| lang.code()).collect();
+                format!(
+                    "unsupported language '{}' (supported: {})",
+                    s,
+                    codes.join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for Lang {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[derive(Debug)]
+/// A message that is shown to the user
+pub enum Message<'a> {
+    /// Greeting at the start of a game.
+    Welcome,
+    /// Title of the given round.
+    Round(usize),
+    /// Number of candidate words left.
+    CandidatesLeft(usize),
+    /// Heading of the list of top candidates, given the number of candidates left.
+    TopCandidates(usize),
+    /// The best-ranked candidate word.
+    TopCandidate(&'a str),
+    /// Request to enter the word guessed in the given round.
+    EnterWord(usize),
+    /// Request to enter Wordle's color code.
+    EnterCode,
+    /// The user has won after the given number of rounds.
+    Won(usize),
+    /// The solver has won after the given number of rounds.
+    SolverWon(usize),
+    /// The game was lost.
+    GameOver,
+    /// The constraints entered do not match any word.
+    NoWordsLeft,
+    /// Changing the color at `position` in `round` to `code` would leave `candidates` words.
+    LikelyTypo {
+        round: usize,
+        position: usize,
+        code: &'a str,
+        candidates: usize,
+    },
+    /// No single color could be changed so that words are left.
+    NoTypoFound,
+    /// The ways to continue when no words are left after `word`, with or without a fix.
+    RecoveryOptions { word: &'a str, fix: bool },
+    /// The colors of the given round have been corrected.
+    Fixed(usize),
+    /// There is no previous round that could be undone.
+    NothingToUndo,
+    /// The given round has been undone.
+    Undone(usize),
+    /// Prompt for the file to save the game to.
+    EnterSaveFile,
+    /// The game has been saved to the file.
+    GameSaved(&'a str),
+    /// A game with words from files cannot be saved or resumed.
+    CustomWordsNotSaved,
+    /// The number of suggestions has been changed with `:top N`.
+    TopChanged(usize),
+    /// `:top` was entered without a valid number of suggestions.
+    InvalidTop,
+    /// A saved game has been resumed after the given number of rounds.
+    GameResumed(usize),
+    /// Header of the replay of a recorded game.
+    ReplayStart {
+        preset: &'a str,
+        rounds: usize,
+        hard: bool,
+    },
+    /// The word guessed in a recorded round.
+    Guessed(&'a str),
+    /// Prompt to continue with the next round of a replay.
+    PressEnter,
+    /// A recorded game was not finished.
+    GameUnfinished,
+    /// The seed chosen for a run without `--seed`.
+    RandomSeed(u64),
+    /// A generic error.
+    Error(&'a str),
+    /// An error in the user's input.
+    InvalidInput(&'a Error),
+    /// A problem with the user's input that does not stop it from being used.
+    Warning(&'a Error),
+    /// The commands and color codes accepted at the prompts.
+    Help { gray_aliases: &'a str },
+    /// Goodbye at the end of the input.
+    Bye,
+    /// Wordle's color code for a guess.
+    Hint(&'a str),
+    /// A simulated game from a start to a target word that was won after the given number of rounds.
+    GameWon(&'a str, &'a str, usize),
+    /// A simulated game from a start to a target word that was lost.
+    GameLost(&'a str, &'a str),
+    /// Summary of all simulated games for a start word.
+    Results {
+        start: &'a str,
+        won: usize,
+        games: usize,
+        percentage: f32,
+        avg_rounds: f32,
+    },
+    /// Title of the speed-run summary.
+    SpeedRun,
+    /// Time needed for the given round.
+    RoundTime(usize, &'a str),
+    /// The best time ever achieved.
+    BestTime(&'a str),
+    /// Time needed for the whole game.
+    TotalTime(&'a str),
+    /// A new personal best, given the previous one.
+    NewPersonalBest(Option<&'a str>),
+    /// The current personal best.
+    PersonalBest(&'a str),
+    /// Average time of the given number of won games.
+    AverageTime(usize, &'a str),
+    /// The stats file cannot be located.
+    StatsUnavailable,
+    /// Title of the statistics of all games.
+    Statistics,
+    /// Number of games played, percentage of games won and streaks of days with a win.
+    StatsSummary {
+        played: usize,
+        win_percentage: usize,
+        current_streak: usize,
+        max_streak: usize,
+    },
+    /// Heading of the number of games won in each number of rounds.
+    GuessDistribution,
+    /// No games have been recorded in the stats file.
+    NoGamesPlayed,
+    /// The number of today's puzzle of the given game.
+    DailyPuzzle(&'a str, u64),
+    /// A game of the given puzzle has already been recorded.
+    PuzzleAlreadyPlayed(&'a str, u64),
+    /// The daily puzzles of the given game are not known.
+    NoDailyPuzzle(&'a str),
+    /// Number of tables removed from the given cache directory.
+    CacheCleared(usize, &'a str),
+    /// Number of damaged lines in the stats file that were skipped.
+    DamagedRecords(usize),
+    /// Number of games imported into the stats file and of those that were already in it.
+    GamesImported { imported: usize, skipped: usize },
+    /// Number of buckets of a word.
+    Buckets(&'a str, usize),
+    /// A bucket and the number of words in it.
+    Bucket(&'a str, usize),
+    /// Heading of the probabilities of the candidates being the mystery word.
+    Probabilities,
+    /// A bucket with its number of words, probability and contribution to the entropy.
+    BucketEntropy {
+        code: &'a str,
+        words: usize,
+        probability: f64,
+        bits: f64,
+    },
+    /// The entropy of a guess, the entropy remaining after it and the entropy needed to
+    /// find the word among all candidates, in bits.
+    Entropy {
+        entropy: f64,
+        remaining: f64,
+        total: f64,
+    },
+    /// Title of the given board and the number of candidate words left on it.
+    Board(usize, usize),
+    /// Title of the given board that was solved in the given round.
+    BoardSolved(usize, usize),
+    /// Request to enter Wordle's color code for the given board.
+    EnterBoardCode(usize),
+    /// The user has solved all boards after the given number of rounds.
+    AllBoardsSolved(usize),
+    /// Number of solved boards out of all boards.
+    BoardsSolved(usize, usize),
+    /// Number of rounds left.
+    RoundsLeft(usize),
+    /// The user has guessed the word after surviving the given number of rounds.
+    Survived(usize),
+    /// The solver has guessed the word after surviving the given number of rounds.
+    SolverSurvived(usize),
+    /// A simulated game from a start to a target word that was survived for the given number of rounds.
+    GameSurvived(&'a str, &'a str, usize),
+    /// The round that lied in Fibble (if any) and the number of candidate words in this case.
+    Hypothesis(Option<usize>, usize),
+    /// Number of candidate pairs of words left in Xordle.
+    PairsLeft(usize),
+    /// One of the two words in Xordle has been found.
+    WordFound(&'a str),
+    /// The user has found both words in Xordle after the given number of rounds.
+    BothWordsFound(usize),
+    /// Summary of all simulated survival games for a start word.
+    SurvivalResults {
+        start: &'a str,
+        games: usize,
+        avg_rounds: f32,
+    },
+    /// The results of a simulation have been saved as a baseline to the given file.
+    BaselineSaved(&'a str),
+    /// More games were lost with a start word than in the baseline.
+    MoreLosses {
+        start: &'a str,
+        before: usize,
+        after: usize,
+    },
+    /// The games won with a start word needed more rounds on average than in the baseline.
+    WorseAverage {
+        start: &'a str,
+        before: f32,
+        after: f32,
+    },
+    /// A different number of games was played with a start word than in the baseline.
+    GamesChanged {
+        start: &'a str,
+        before: usize,
+        after: usize,
+    },
+    /// Summary of the comparison with a baseline.
+    BaselineResults { compared: usize, regressions: usize },
+    /// Heading of the list of the best sets of the given number of opening words.
+    BestOpenings(usize),
+    /// Heading of the letter frequencies in the given number of candidate words.
+    Frequencies(usize),
+    /// Column heading for the frequencies over all positions.
+    AllPositions,
+    /// The expected number of rounds needed to find the word if a suggestion is played.
+    ExpectedTurns(f64),
+    /// Marks an obscure suggestion.
+    Obscure,
+    /// The word is in the wordlist and matches all hints.
+    StillCandidate(&'a str),
+    /// The word is not in the wordlist, but matches all hints.
+    MatchesAllHints(&'a str),
+    /// The word is not in the wordlist.
+    NotInWordlist(&'a str),
+    /// The word does not match the hints of a turn for the given reason.
+    Eliminated {
+        word: &'a str,
+        turn: &'a str,
+        mismatch: &'a Mismatch,
+    },
+    /// A target word that two strategies found after a different number of rounds.
+    DuelGame {
+        target: &'a str,
+        names: [&'a str; 2],
+        rounds: [usize; 2],
+    },
+    /// The skill and luck of a guess in a graded game.
+    GuessGrade {
+        round: usize,
+        guess: &'a str,
+        skill: f64,
+        best: &'a str,
+        luck: f64,
+        expected_left: f64,
+        left: usize,
+    },
+    /// The average skill and the total luck of a graded game.
+    GameGrade { skill: f64, luck: f64 },
+    /// Summary of a duel between two strategies with their start words.
+    DuelResults {
+        names: [&'a str; 2],
+        starts: [&'a str; 2],
+        wins: [usize; 2],
+        ties: usize,
+        avg_rounds: [f32; 2],
+    },
+    /// A target word that the strategy did not find within the round limit, with the
+    /// guesses it made.
+    VerifyFailure { target: &'a str, guesses: &'a str },
+    /// Summary of the check that the strategy finds all words within the round limit.
+    VerifyResults {
+        start: &'a str,
+        words: usize,
+        failures: usize,
+        rounds: usize,
+    },
+    /// A wordlist file and what was cleaned up when loading it.
+    WordlistCleaned { path: &'a str, cleanup: Cleanup },
+    /// An embedded wordlist with its word length and number of words.
+    WordlistInfo {
+        name: &'a str,
+        word_len: usize,
+        words: usize,
+    },
+}
+
+/// Explains a [`Mismatch`] in English.
+fn mismatch_en(mismatch: &Mismatch) -> String {
+    match *mismatch {
+        Mismatch::Position(i, Constraint::AtPos(_, c)) => {
+            format!("'{}' must be at position {}", c, i + 1)
+        }
+        Mismatch::Position(i, Constraint::NotAtPos(_, c) | Constraint::Absent(c)) => {
+            format!("'{}' must not be at position {}", c, i + 1)
+        }
+        Mismatch::Count {
+            c, max: Some(0), ..
+        } => format!("'{}' is not in the word", c),
+        Mismatch::Count { c, count, min, .. } if count < min => match min {
+            1 => format!("'{}' must be in the word", c),
+            _ => format!("'{}' must occur at least {} times", c, min),
+        },
+        Mismatch::Count { c, max, .. } => format!(
+            "'{}' must occur at most {} time{}",
+            c,
+            max.unwrap_or_default(),
+            plural_en(max.unwrap_or_default())
+        ),
+    }
+}
+
+/// Explains a [`Mismatch`] in German.
+fn mismatch_de(mismatch: &Mismatch) -> String {
+    match *mismatch {
+        Mismatch::Position(i, Constraint::AtPos(_, c)) => {
+            format!("'{}' muss an Position {} stehen", c, i + 1)
+        }
+        Mismatch::Position(i, Constraint::NotAtPos(_, c) | Constraint::Absent(c)) => {
+            format!("'{}' darf nicht an Position {} stehen", c, i + 1)
+        }
+        Mismatch::Count {
+            c, max: Some(0), ..
+        } => format!("'{}' kommt im Wort nicht vor", c),
+        Mismatch::Count { c, count, min, .. } if count < min => match min {
+            1 => format!("'{}' muss im Wort vorkommen", c),
+            _ => format!("'{}' muss mindestens {}-mal vorkommen", c, min),
+        },
+        Mismatch::Count { c, max, .. } => format!(
+            "'{}' darf höchstens {}-mal vorkommen",
+            c,
+            max.unwrap_or_default()
+        ),
+    }
+}
+
+/// Returns the note on the characters accepted for gray besides `_`, e.g. ` (also B or X)`,
+/// or nothing if there are none.
+fn gray_aliases_note(gray_aliases: &str, also: &str, or: &str) -> String {
+    match &color_symbols(gray_aliases)[3..] {
+        [] => String::new(),
+        aliases => format!(" ({} {})", also, join_alternatives(aliases, or)),
+    }
+}
+
+/// Returns the English plural suffix for `number`.
+fn plural_en(number: usize) -> &'static str {
+    if number == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lang_from_str() {
+        assert_eq!("de".parse::<Lang>(), Ok(Lang::De));
+        assert_eq!("EN".parse::<Lang>(), Ok(Lang::En));
+        assert!("xx".parse::<Lang>().is_err());
+    }
+
+    #[test]
+    fn test_tr() {
+        assert_eq!(
+            Lang::En.tr(Message::Won(1)),
+            "Congratulations! You won after 1 round."
+        );
+        assert_eq!(
+            Lang::En.tr(Message::CandidatesLeft(2)),
+            "2 candidate words left."
+        );
+        assert_eq!(
+            Lang::De.tr(Message::CandidatesLeft(1)),
+            "1 mögliches Wort übrig."
+        );
+        assert_eq!(
+            Lang::De.tr(Message::InvalidInput(&Error::InvalidColorCode {
+                input: 'Q',
+                gray_aliases: "X".to_string()
+            })),
+            "Fehler: Ungültiges Zeichen 'Q' im Farbcode (erlaubt: G, Y, _ oder X)"
+        );
+        assert_eq!(
+            Lang::En.tr(Message::InvalidInput(&Error::NotACandidate {
+                input: "crtae".to_string(),
+                suggestions: vec!["crate".to_string(), "trace".to_string()]
+            })),
+            "Error: 'crtae' does not match the previous hints (hard mode), did you mean 'crate' or 'trace'?"
+        );
+        assert_eq!(
+            Lang::De.tr(Message::InvalidInput(&Error::UnknownWord {
+                input: "zzzzz".to_string(),
+                suggestions: vec![]
+            })),
+            "Fehler: 'zzzzz' steht nicht in der Wortliste"
+        );
+        assert_eq!(
+            Lang::En.tr(Message::Warning(&Error::UnknownWord {
+                input: "crtae".to_string(),
+                suggestions: vec!["crate".to_string()]
+            })),
+            "Warning: 'crtae' is not in the wordlist, did you mean 'crate'?"
+        );
+        assert_eq!(
+            Lang::En.tr(Message::Eliminated {
+                word: "spell",
+                turn: "poles=YYYY_",
+                mismatch: &Mismatch::Count {
+                    c: 'l',
+                    count: 2,
+                    min: 1,
+                    max: Some(1)
+                }
+            }),
+            "\"spell\" is ruled out by poles=YYYY_: 'l' must occur at most 1 time."
+        );
+        assert!(Lang::En
+            .tr(Message::Help { gray_aliases: "bx" })
+            .ends_with("_ = Gray (also B or X), e.g. _Y__G."));
+        assert!(Lang::De
+            .tr(Message::Help { gray_aliases: "" })
+            .ends_with("_ = Grau, z. B. _Y__G."));
+    }
+}
@@ -2,41 +2,145 @@
 //! of [Wordle](https://www.nytimes.com/games/wordle/index.html) - just like a promper
 //! in a theater tells the actors what to say next in case they forget.
 use std::{
-    collections::HashSet,
-    error::Error,
+    collections::{BTreeMap, HashMap, HashSet},
     fmt,
-    fs::File,
-    io::{BufRead, BufReader},
+    io::{self, BufRead},
     iter::FromIterator,
-    path::Path,
+    str::FromStr,
+    sync::{Arc, OnceLock},
 };
+#[cfg(feature = "std-fs")]
+use std::{fs::File, io::BufReader, path::Path};
 
 use itertools::Itertools;
 
-#[derive(Debug)]
-/// Error type to handle errors in the user's input
-pub enum InputError {
-    InvalidColorCode(char),
-    IncorrectWordLength(usize),
-    IncorrectColorCodeLength(usize),
-}
-
-impl Error for InputError {}
-
-impl fmt::Display for InputError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use InputError::*;
+use crate::{
+    alphabet::Alphabet,
+    feedback::{Feedback, FeedbackRule, Pattern, WordleRule, MAX_PATTERN_LEN},
+    rng::Rng,
+    scratch::Scratch,
+};
 
-        let s = match self {
-            InvalidColorCode(c) => format!("Invalid color code character '{}'", c),
-            IncorrectWordLength(len) => format!("Word must be {} characters long", len),
-            IncorrectColorCodeLength(len) => format!("Color code must be {} characters long", len),
-        };
+pub mod alphabet;
+pub mod cache;
+pub mod coverage;
+pub mod daily;
+pub mod entropy;
+pub mod feedback;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fibble;
+pub mod freq;
+pub mod grade;
+pub mod i18n;
+pub mod lookahead;
+pub mod matrix;
+pub mod merge;
+pub mod metadata;
+pub mod morphology;
+pub mod multi;
+pub mod nerdle;
+pub mod notation;
+pub mod offensive;
+pub mod packed;
+pub mod preset;
+pub mod primel;
+pub mod prior;
+pub mod recovery;
+pub mod render;
+pub mod rng;
+pub mod scratch;
+pub mod simulation;
+pub mod solver;
+pub mod spelling;
+pub mod stats;
+pub mod strategy;
+pub mod transcript;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod xordle;
 
-        write!(f, "{}", s)
-    }
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+/// Error type for everything that can go wrong in the library, mostly because of invalid
+/// input
+pub enum Error {
+    #[error(
+        "Invalid color code character '{input}' (use {})",
+        join_alternatives(&color_symbols(.gray_aliases), "or")
+    )]
+    InvalidColorCode { input: char, gray_aliases: String },
+    #[error(
+        "Word must be {len} characters long, but '{input}' has {}",
+        .input.chars().count()
+    )]
+    IncorrectWordLength { input: String, len: usize },
+    #[error(
+        "Color code must be {len} characters long, but '{input}' has {}",
+        .input.chars().count()
+    )]
+    IncorrectColorCodeLength { input: String, len: usize },
+    #[error(
+        "'{input}' does not match the previous hints (hard mode){}",
+        did_you_mean(.suggestions)
+    )]
+    NotACandidate {
+        input: String,
+        suggestions: Vec<String>,
+    },
+    #[error("Invalid character '{0}'")]
+    InvalidSymbol(char),
+    #[error("'{0}' is not a valid guess")]
+    InvalidGuess(String),
+    #[error("'{0}' is not of the form WORD=CODE or WORD/DIGITS (e.g. crate=Y_G_G or crate/10202)")]
+    InvalidNotation(String),
+    #[error("Invalid digit '{0}' in color code (use 2 = Green, 1 = Yellow, 0 = Gray)")]
+    InvalidDigitCode(char),
+    #[error("'{0}' is not a valid constraint for its position (e.g. c@0, r~1 or a-)")]
+    InvalidConstraint(String),
+    #[error("The hint '{0}' contradicts the previous hints")]
+    Contradiction(String),
+    #[error("Could not read the wordlist '{path}': {reason}")]
+    UnreadableWordlist { path: String, reason: String },
+    #[error(
+        "The wordlist contains invalid words for this game: {}",
+        .0.iter().map(|(line, word)| format!("'{}' in line {}", word, line)).join(", ")
+    )]
+    InvalidWordlistEntries(Vec<(usize, String)>),
+    #[error("The wordlist does not contain any words for this game")]
+    EmptyWordlist,
+    #[error(
+        "The wordlist does not contain any words with {len} characters (available: {})",
+        .available.iter().join(", ")
+    )]
+    NoWordsOfLength { len: usize, available: Vec<usize> },
+    #[error("Could not read the frequency file '{path}': {reason}")]
+    UnreadableFrequencies { path: String, reason: String },
+    #[error("'{entry}' in line {line} of the frequency file is not of the form WORD WEIGHT")]
+    InvalidFrequencyEntry { line: usize, entry: String },
+    #[error(
+        "'{entry}' in line {line} of the wordlist is not of the form WORD<TAB>FREQUENCY<TAB>TAGS"
+    )]
+    InvalidMetadataEntry { line: usize, entry: String },
+    #[error("Words with {0} characters are not supported")]
+    UnsupportedWordLength(usize),
+    #[error(
+        "Wordle never shows these colors for this guess, check the colors at positions {}",
+        .0.iter().join(", ")
+    )]
+    ImpossibleCode(Vec<usize>),
+    #[error("{0} color codes are required, one for each board that is not solved yet")]
+    IncorrectBoardCount(usize),
+    #[error("'{input}' is not in the wordlist{}", did_you_mean(.suggestions))]
+    UnknownWord {
+        input: String,
+        suggestions: Vec<String>,
+    },
 }
 
+/// The previous name of [`Error`], which only covered errors in the user's input.
+pub type InputError = Error;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 /// A constraint that encodes information about a given character and a given position in a [`Word`]
 pub enum Constraint {
@@ -48,36 +152,74 @@ pub enum Constraint {
     Absent(char),
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+impl Constraint {
+    /// Returns the color that the constraint is derived from.
+    pub fn feedback(&self) -> Feedback {
+        match self {
+            Constraint::AtPos(_, _) => Feedback::Green,
+            Constraint::NotAtPos(_, _) => Feedback::Yellow,
+            Constraint::Absent(_) => Feedback::Gray,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The reason why a word does not match a [`ConstraintSet`]
+pub enum Mismatch {
+    /// The word violates the constraint for a position: it does not have the character of
+    /// an [`AtPos`](Constraint::AtPos) constraint there or has the character of a
+    /// [`NotAtPos`](Constraint::NotAtPos) or [`Absent`](Constraint::Absent) constraint
+    /// there.
+    Position(usize, Constraint),
+    /// The word contains the character `count` times, which is fewer than `min` or more
+    /// than `max`.
+    Count {
+        c: char,
+        count: usize,
+        min: usize,
+        max: Option<usize>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A guess and a target word that violate the invariant of [`ConstraintSet::from_guess`]:
+/// the constraints derived from the feedback for `guess` reject the `target` itself
+pub struct Inconsistency {
+    /// The guessed word.
+    pub guess: Word,
+    /// The target word that is rejected.
+    pub target: Word,
+    /// The reason why the target is rejected.
+    pub mismatch: Mismatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 /// A set of [`Constraint`]s that can be used to filter the [`Word`]s in a [`Wordlist`]
 pub struct ConstraintSet {
     /// Set of constraints. Each index in the `Vec` corresponds to a position in the word.
     constraints: Vec<Constraint>,
-    /// List of characters that have been found to be present in the word.
-    present_chars: Vec<char>,
+    /// Lower and upper bound for the number of occurrences of each character in the guess.
+    /// The lower bound is the number of green and yellow copies. If a copy is gray, the
+    /// word contains exactly that many, otherwise there is no upper bound.
+    counts: BTreeMap<char, (usize, Option<usize>)>,
 }
 
 impl ConstraintSet {
     /// Returns an iterator over the constraints in the set.
-    pub fn iter(&self) -> ::std::slice::Iter<Constraint> {
+    pub fn iter(&self) -> ::std::slice::Iter<'_, Constraint> {
         self.constraints.iter()
     }
 
-    #[allow(clippy::needless_collect)]
     /// Returns true if the given `word` complies to all the constraints in the set.
     pub fn is_match(&self, word: &Word) -> bool {
         use Constraint::*;
 
-        let chars: Vec<_> = word
-            .chars()
-            .filter(|c| !self.present_chars.contains(c))
-            .collect();
-
-        for constraint in self {
+        for (i, constraint) in self.iter().enumerate() {
             let is_match = match constraint {
-                AtPos(i, c) => word.char(*i) == *c,
-                NotAtPos(i, c) => word.char(*i) != *c && word.contains(*c),
-                Absent(c) => !chars.contains(c),
+                AtPos(i, c) => word.char(*i) == Some(*c),
+                NotAtPos(i, c) => word.char(*i) != Some(*c),
+                // A gray character that is not absent would have been green here.
+                Absent(c) => word.char(i) != Some(*c),
             };
 
             if !is_match {
@@ -85,7 +227,139 @@ impl ConstraintSet {
             }
         }
 
-        true
+        self.counts.iter().all(|(&c, &(min, max))| {
+            let count = word.chars().filter(|&d| d == c).count();
+            count >= min && max.map_or(true, |max| count <= max)
+        })
+    }
+
+    /// Returns the first reason why `word` does not comply to the constraints, or `None` if
+    /// it does (see [`is_match`](ConstraintSet::is_match)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{Constraint, ConstraintSet, Mismatch, Word};
+    /// let constraints = ConstraintSet::try_from(("crate", "_Y__G")).unwrap();
+    ///
+    /// assert_eq!(constraints.mismatch(&Word::from("horse")), None);
+    /// assert_eq!(
+    ///     constraints.mismatch(&Word::from("route")),
+    ///     Some(Mismatch::Position(3, Constraint::Absent('t')))
+    /// );
+    /// assert_eq!(
+    ///     constraints.mismatch(&Word::from("score")),
+    ///     Some(Mismatch::Count { c: 'c', count: 1, min: 0, max: Some(0) })
+    /// );
+    /// ```
+    pub fn mismatch(&self, word: &Word) -> Option<Mismatch> {
+        use Constraint::*;
+
+        let position = self
+            .iter()
+            .enumerate()
+            .find(|(i, constraint)| match constraint {
+                AtPos(i, c) => word.char(*i) != Some(*c),
+                NotAtPos(i, c) => word.char(*i) == Some(*c),
+                Absent(c) => word.char(*i) == Some(*c),
+            });
+
+        if let Some((i, constraint)) = position {
+            return Some(Mismatch::Position(i, *constraint));
+        }
+
+        self.counts.iter().find_map(|(&c, &(min, max))| {
+            let count = word.chars().filter(|&d| d == c).count();
+
+            if count < min || max.is_some_and(|max| count > max) {
+                Some(Mismatch::Count { c, count, min, max })
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Checks that Wordle could show the colors for the guessed word, which catches typos
+    /// in color codes. Returns [`Error::ImpossibleCode`] with the positions (counting from
+    /// 1) whose colors contradict each other otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{ConstraintSet, Error};
+    /// let constraints = ConstraintSet::try_from(("spell", "___YG")).unwrap();
+    /// assert!(constraints.validate().is_ok());
+    ///
+    /// // The first `l` would be yellow before the second one.
+    /// let constraints = ConstraintSet::try_from(("spell", "____Y")).unwrap();
+    /// assert_eq!(constraints.validate(), Err(Error::ImpossibleCode(vec![4, 5])));
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        use Constraint::*;
+
+        // Yellow is assigned from left to right, so a character that is gray cannot be
+        // yellow further right.
+        for (i, constraint) in self.iter().enumerate() {
+            if let Absent(c) = constraint {
+                let later_yellow = self.iter().skip(i + 1).find_map(|other| match other {
+                    NotAtPos(j, d) if d == c => Some(*j),
+                    _ => None,
+                });
+
+                if let Some(j) = later_yellow {
+                    return Err(Error::ImpossibleCode(vec![i + 1, j + 1]));
+                }
+            }
+        }
+
+        // Each yellow character must be somewhere in the mystery word where it is not
+        // green, i.e. at a position that is not green and where the guess has another
+        // character.
+        let yellow = self.iter().filter_map(|c| match c {
+            NotAtPos(_, c) => Some(*c),
+            _ => None,
+        });
+
+        for (c, count) in yellow.counts().into_iter().sorted() {
+            let available = self
+                .iter()
+                .filter(|other| match other {
+                    AtPos(_, _) => false,
+                    NotAtPos(_, d) | Absent(d) => *d != c,
+                })
+                .count();
+
+            if count > available {
+                let positions = self
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, other)| matches!(other, NotAtPos(_, d) if *d == c))
+                    .map(|(i, _)| i + 1);
+
+                return Err(Error::ImpossibleCode(positions.collect()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the guessed word that the constraints are derived from.
+    pub fn word(&self) -> String {
+        self.iter()
+            .map(|c| match *c {
+                Constraint::AtPos(_, c) | Constraint::NotAtPos(_, c) | Constraint::Absent(c) => c,
+            })
+            .collect()
+    }
+
+    /// Returns the feedback that the constraints are derived from.
+    pub fn feedback(&self) -> Vec<Feedback> {
+        self.iter().map(Constraint::feedback).collect()
+    }
+
+    /// Returns the color code with `G`, `Y` and `_` that the constraints are derived from.
+    pub fn code(&self) -> String {
+        feedback::to_code(&self.feedback())
     }
 
     /// Returns `true` if the `ConstraintSet` encodes a correct guess, i.e. all the characters
@@ -95,42 +369,356 @@ impl ConstraintSet {
     }
 }
 
-impl TryFrom<(&str, &str)> for ConstraintSet {
-    type Error = InputError;
+impl ConstraintSet {
+    /// Creates a `ConstraintSet` from an input word and a color code in which each
+    /// character in `gray_aliases` is accepted for gray besides `_`. Returns an error if the
+    /// code contains any other character or does not have one color for each character of
+    /// the word in lower case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{ConstraintSet, Error};
+    /// let constraints = ConstraintSet::with_gray_aliases("crate", "-y-#g", "-#").unwrap();
+    ///
+    /// assert_eq!(constraints, ConstraintSet::try_from(("crate", "_Y__G")).unwrap());
+    /// assert_eq!(
+    ///     ConstraintSet::with_gray_aliases("crate", "-y-#", "-#"),
+    ///     Err(Error::IncorrectColorCodeLength { input: "-y-#".to_string(), len: 5 })
+    /// );
+    /// ```
+    pub fn with_gray_aliases(word: &str, colors: &str, gray_aliases: &str) -> Result<Self, Error> {
+        let feedback = feedback::from_code(&normalize_code(colors, gray_aliases)?)?;
 
-    /// Try to create a `ConstraintSet` from an input word and string representing a color code.
-    fn try_from(input: (&str, &str)) -> Result<Self, Self::Error> {
-        let (word, colors) = input;
+        // Some characters have more than one in lower case, e.g. `İ`.
+        let word = alphabet::lowercase_word(word);
+        let len = word.chars().count();
+
+        if feedback.len() != len {
+            return Err(Error::IncorrectColorCodeLength {
+                input: colors.to_string(),
+                len,
+            });
+        }
+
+        Ok(Self::from_feedback(&word, feedback))
+    }
+
+    /// Creates the `ConstraintSet` for `guess` when the mystery word is `target`, i.e. with
+    /// the feedback computed by [`Word::feedback`].
+    ///
+    /// The target always matches the constraints, i.e.
+    /// `ConstraintSet::from_guess(g, t).is_match(t)` holds for all words `g` and `t` of the
+    /// same length, so that filtering with the real feedback never eliminates the mystery
+    /// word. [`find_inconsistency`] checks this for the words of a wordlist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{ConstraintSet, Word};
+    /// let constraints = ConstraintSet::from_guess(&Word::from("crate"), &Word::from("horse"));
+    ///
+    /// assert_eq!(constraints, ConstraintSet::try_from(("crate", "_Y__G")).unwrap());
+    /// ```
+    pub fn from_guess(guess: &Word, target: &Word) -> Self {
+        Self::from_feedback(&guess.0, guess.feedback(target))
+    }
 
+    /// Creates the constraints for `word` from the feedback for each character.
+    pub fn from_feedback(word: &str, feedback: impl IntoIterator<Item = Feedback>) -> Self {
         let mut constraints = vec![];
-        let mut present_chars = vec![];
+        let mut counts = BTreeMap::new();
 
-        let word = word.to_lowercase();
-        let colors = colors.to_uppercase();
+        let char_iter = word.chars().zip(feedback).enumerate();
 
-        let char_iter = word.chars().zip(colors.chars()).enumerate();
+        for (i, (c, feedback)) in char_iter {
+            let (min, max) = counts.entry(c).or_insert((0, None));
 
-        for (i, (c, color)) in char_iter {
-            let constraint = match color {
-                'G' => {
-                    present_chars.push(c);
+            let constraint = match feedback {
+                Feedback::Green => {
+                    *min += 1;
                     Constraint::AtPos(i, c)
                 }
-                'Y' => {
-                    present_chars.push(c);
+                Feedback::Yellow => {
+                    *min += 1;
                     Constraint::NotAtPos(i, c)
                 }
-                '_' => Constraint::Absent(c),
-                c => return Err(InputError::InvalidColorCode(c)),
+                Feedback::Gray => {
+                    *max = Some(0);
+                    Constraint::Absent(c)
+                }
             };
 
             constraints.push(constraint);
         }
 
-        Ok(Self {
+        // A gray copy caps the count at the number of green and yellow copies.
+        for (min, max) in counts.values_mut() {
+            if max.is_some() {
+                *max = Some(*min);
+            }
+        }
+
+        Self {
             constraints,
-            present_chars,
+            counts,
+        }
+    }
+}
+
+impl TryFrom<(&str, &str)> for ConstraintSet {
+    type Error = Error;
+
+    /// Try to create a `ConstraintSet` from an input word and string representing a color
+    /// code. Besides `_`, the characters in [`GRAY_ALIASES`] are accepted for gray.
+    fn try_from(input: (&str, &str)) -> Result<Self, Self::Error> {
+        let (word, colors) = input;
+
+        Self::with_gray_aliases(word, colors, GRAY_ALIASES)
+    }
+}
+
+/// Characters that are accepted for gray in color codes besides `_`.
+pub const GRAY_ALIASES: &str = "BX-.0";
+
+/// Returns the color code in upper case with each character in `gray_aliases` replaced by
+/// `_`. Returns an error for the first character that is neither `G`, `Y`, `_` nor an
+/// alias for gray.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{normalize_code, Error, GRAY_ALIASES};
+/// assert_eq!(normalize_code("by-xg", GRAY_ALIASES), Ok("_Y__G".to_string()));
+/// assert_eq!(
+///     normalize_code("BYQXG", GRAY_ALIASES).unwrap_err().to_string(),
+///     "Invalid color code character 'Q' (use G, Y, _, B, X, -, . or 0)"
+/// );
+/// ```
+pub fn normalize_code(colors: &str, gray_aliases: &str) -> Result<String, Error> {
+    let gray_aliases: String = gray_aliases.chars().map(alphabet::uppercase).collect();
+
+    colors
+        .chars()
+        .map(alphabet::uppercase)
+        .map(|color| match color {
+            'G' | 'Y' | '_' => Ok(color),
+            c if gray_aliases.contains(c) => Ok('_'),
+            c => Err(Error::InvalidColorCode {
+                input: c,
+                gray_aliases: gray_aliases.clone(),
+            }),
         })
+        .collect()
+}
+
+/// Returns the characters that are accepted in color codes with `gray_aliases`.
+pub(crate) fn color_symbols(gray_aliases: &str) -> Vec<String> {
+    ["G", "Y", "_"]
+        .into_iter()
+        .map(str::to_string)
+        .chain(
+            gray_aliases
+                .chars()
+                .map(|c| alphabet::uppercase(c).to_string()),
+        )
+        .collect()
+}
+
+/// Joins `items` with commas and `or` before the last one, e.g. `G, Y or _`.
+pub(crate) fn join_alternatives(items: &[String], or: &str) -> String {
+    match items.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} {} {}", rest.join(", "), or, last),
+        None => String::new(),
+    }
+}
+
+/// Returns the end of an error message that suggests the words the input was most likely
+/// meant to be, or nothing if there are none.
+fn did_you_mean(suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        return String::new();
+    }
+
+    let quoted: Vec<_> = suggestions.iter().map(|w| format!("'{}'", w)).collect();
+
+    format!(", did you mean {}?", join_alternatives(&quoted, "or"))
+}
+
+/// Returns the number of edits needed to turn `a` into `b`, where an edit inserts,
+/// removes or replaces a character or swaps two adjacent characters.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::edit_distance;
+/// assert_eq!(edit_distance("crate", "crate"), 0);
+/// assert_eq!(edit_distance("crtae", "crate"), 1);
+/// assert_eq!(edit_distance("crat", "crate"), 1);
+/// assert_eq!(edit_distance("spoon", "crate"), 5);
+/// ```
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+
+    // d[i][j] is the distance between the first i characters of a and the first j of b.
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let replaced = d[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = replaced.min(d[i - 1][j] + 1).min(d[i][j - 1] + 1);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[a.len()][b.len()]
+}
+
+/// Prepares user input for validation: removes all whitespace (e.g. in `c r a t e`),
+/// converts it to lower case (see [`alphabet::lowercase`]) and drops ASCII punctuation that
+/// is not in `keep`, such as a trailing period. Other characters are kept, so that
+/// validation can report them.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::normalize_input;
+/// assert_eq!(normalize_input(" C r a t e! ", ""), "crate");
+/// assert_eq!(normalize_input("Y-G.G_,", "_-."), "y-g.g_");
+/// assert_eq!(normalize_input("cr4te", ""), "cr4te");
+/// ```
+pub fn normalize_input(input: &str, keep: &str) -> String {
+    input
+        .chars()
+        .filter(|c| !c.is_whitespace() && (!c.is_ascii_punctuation() || keep.contains(*c)))
+        .map(alphabet::lowercase)
+        .collect()
+}
+
+/// Prepares a word entered by the user like [`normalize_input`], but keeps the symbols of
+/// `alphabet` and converts each character to the symbol it stands for (see
+/// [`Alphabet::fold`]), so that it can be compared with the words of the wordlist.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{alphabet::Alphabet, normalize_word};
+/// assert_eq!(normalize_word(" C r a t e! ", &Alphabet::LATIN), "crate");
+///
+/// let turkish = Alphabet::new("abcçdefgğhıijklmnoöprsştuüvyz", |_| true);
+/// assert_eq!(normalize_word("KIRIK", &turkish), "kırık");
+/// assert_eq!(normalize_word("İLKER", &turkish), "ilker");
+/// ```
+pub fn normalize_word(input: &str, alphabet: &Alphabet) -> String {
+    input
+        .chars()
+        .filter(|&c| !c.is_whitespace() && (!c.is_ascii_punctuation() || alphabet.contains(c)))
+        .map(|c| alphabet.fold(c))
+        .collect()
+}
+
+/// Checks the invariant of [`ConstraintSet::from_guess`] for each word in `guesses` and
+/// each word of the same length in `targets`. Returns the first pair for which the target
+/// does not match the constraints derived from its own feedback, or `None` if there is
+/// none.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{find_inconsistency, Word, Wordlist};
+/// let words: Wordlist = ["crate", "haste", "spell", "lelps"].iter().map(Word::from).collect();
+///
+/// assert_eq!(find_inconsistency(&words, &words), None);
+/// ```
+pub fn find_inconsistency(guesses: &Wordlist, targets: &Wordlist) -> Option<Inconsistency> {
+    guesses.iter().find_map(|guess| {
+        let len = guess.chars().count();
+
+        targets
+            .iter()
+            .filter(|target| target.chars().count() == len)
+            .find_map(|target| {
+                ConstraintSet::from_guess(guess, target)
+                    .mismatch(target)
+                    .map(|mismatch| Inconsistency {
+                        guess: guess.clone(),
+                        target: target.clone(),
+                        mismatch,
+                    })
+            })
+    })
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Constraint::AtPos(i, c) => write!(f, "{}@{}", c, i),
+            Constraint::NotAtPos(i, c) => write!(f, "{}~{}", c, i),
+            Constraint::Absent(c) => write!(f, "{}-", c),
+        }
+    }
+}
+
+impl fmt::Display for ConstraintSet {
+    /// Writes the constraints in their canonical textual form, in the order of the
+    /// positions they belong to: `c@0` if the character is at position 0 (green), `r~1` if
+    /// it is in the word but not at position 1 (yellow) and `a-` if it is absent (gray).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.iter().join(", "))
+    }
+}
+
+impl FromStr for ConstraintSet {
+    type Err = Error;
+
+    /// Parses the textual form written by `Display`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::ConstraintSet;
+    /// let constraints = ConstraintSet::try_from(("crate", "G_Y_G")).unwrap();
+    ///
+    /// assert_eq!(constraints.to_string(), "c@0, r-, a~2, t-, e@4");
+    /// assert_eq!("c@0, r-, a~2, t-, e@4".parse(), Ok(constraints));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut word = String::new();
+        let mut colors = String::new();
+
+        for (i, item) in s.split(',').map(str::trim).enumerate() {
+            let invalid = || Error::InvalidConstraint(item.to_string());
+
+            let mut chars = item.chars();
+            let c = chars.next().ok_or_else(invalid)?;
+            let rest = chars.as_str();
+
+            let at = |prefix| rest.strip_prefix(prefix).and_then(|p| p.parse().ok()) == Some(i);
+
+            let color = match rest {
+                "-" => '_',
+                _ if at('@') => 'G',
+                _ if at('~') => 'Y',
+                _ => return Err(invalid()),
+            };
+
+            word.push(c);
+            colors.push(color);
+        }
+
+        Self::try_from((word.as_str(), colors.as_str()))
     }
 }
 
@@ -154,73 +742,129 @@ impl<'a> IntoIterator for &'a ConstraintSet {
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// A candidate or mystery word in a Wordle game
-pub struct Word(String);
+///
+/// The characters are shared between all clones of a word, so that copying words (e.g.
+/// when the candidates are filtered in each round of a simulation) only copies pointers.
+pub struct Word(
+    /// The characters as a string.
+    Arc<str>,
+    /// The characters one by one, so that the character at a position is found in
+    /// constant time.
+    Arc<[char]>,
+);
 
 impl Word {
     /// Returns `true` if the word contains the given character.
     pub fn contains(&self, c: char) -> bool {
-        self.0.contains(c)
+        self.1.contains(&c)
     }
 
-    /// Returns the the character at the given `index` in the word.
-    pub fn char(&self, index: usize) -> char {
-        self.0.chars().nth(index).unwrap()
+    /// Returns the character at the given `index` in the word or `None` if the word is
+    /// shorter.
+    pub fn char(&self, index: usize) -> Option<char> {
+        self.1.get(index).copied()
     }
 
     /// Returns an iterator over the characters in the word.
     pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
-        self.0.chars()
+        self.1.iter().copied()
     }
 
-    /// Returns a string representing the color code that Wordle would present
-    /// for a target word `w`.
+    /// Returns the feedback that Wordle would present for a target word `w`. Green is
+    /// given first, then yellow from left to right for as many copies of a character as
+    /// the target word has left.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use prompter::Word;
+    /// # use prompter::{feedback::Feedback::*, Word};
     /// let w1 = Word::from("crate");
     /// let w2 = Word::from("space");
     ///
-    /// assert_eq!(w1.match_code(&w2), "Y_G_G");
-    /// assert_eq!(w2.match_code(&w1), "__GYG");
+    /// assert_eq!(w1.feedback(&w2), [Yellow, Gray, Green, Gray, Green]);
     /// ```
-    pub fn match_code(&self, w: &Word) -> String {
-        let mut target_chars: Vec<_> = self
-            .chars()
-            .zip(w.chars())
-            .filter(|(c1, c2)| c1 != c2)
-            .map(|(_c1, c2)| c2)
-            .collect();
+    pub fn feedback(&self, w: &Word) -> Vec<Feedback> {
+        self.pattern(w).iter().collect()
+    }
 
-        self.chars()
-            .zip(w.chars())
+    /// Returns the feedback that Wordle would present for a target word `w` as a
+    /// [`Pattern`], without allocating (see [`feedback`](Word::feedback)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::Word;
+    /// let pattern = Word::from("crate").pattern(&Word::from("space"));
+    ///
+    /// assert_eq!(pattern.to_string(), "Y_G_G");
+    /// ```
+    pub fn pattern(&self, w: &Word) -> Pattern {
+        let pairs = || self.chars().zip(w.chars()).take(MAX_PATTERN_LEN);
+
+        // Positions of the target word whose character is green or already taken by a
+        // yellow one.
+        let mut taken = pairs()
+            .enumerate()
+            .filter(|(_, (c1, c2))| c1 == c2)
+            .fold(0u64, |taken, (i, _)| taken | 1 << i);
+
+        pairs()
             .map(|(c1, c2)| {
                 if c1 == c2 {
-                    'G'
-                } else if let Some(index) = target_chars.iter().position(|c| c == &c1) {
-                    target_chars.remove(index);
-                    'Y'
-                } else {
-                    '_'
+                    return Feedback::Green;
+                }
+
+                let free = pairs()
+                    .enumerate()
+                    .position(|(j, (_, c))| c == c1 && taken & 1 << j == 0);
+
+                match free {
+                    Some(j) => {
+                        taken |= 1 << j;
+                        Feedback::Yellow
+                    }
+                    None => Feedback::Gray,
                 }
             })
             .collect()
     }
 
+    /// Returns a string representing the color code that Wordle would present
+    /// for a target word `w` (see [`feedback`](Word::feedback)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::Word;
+    /// let w1 = Word::from("crate");
+    /// let w2 = Word::from("space");
+    ///
+    /// assert_eq!(w1.match_code(&w2), "Y_G_G");
+    /// assert_eq!(w2.match_code(&w1), "__GYG");
+    /// ```
+    pub fn match_code(&self, w: &Word) -> String {
+        self.pattern(w).to_string()
+    }
+
     /// Computes the number of different color codes that are assigned to the `Word`
     /// when matched against every other word in the wordlist.
     pub fn filter_potential(&self, wordlist: &Wordlist) -> usize {
-        let constraints: HashSet<_> = wordlist.iter().map(|w| self.match_code(w)).collect();
+        self.filter_potential_with(wordlist, &mut Scratch::default())
+    }
 
-        constraints.len()
+    /// Computes the [`filter_potential`](Word::filter_potential) with the buffers in
+    /// `scratch`, so that scoring many words allocates them only once.
+    pub fn filter_potential_with(&self, wordlist: &Wordlist, scratch: &mut Scratch) -> usize {
+        WordleRule.filter_potential_with(self, wordlist, scratch)
     }
 }
 
 impl<S: AsRef<str>> From<S> for Word {
     /// Creates a `Word` from a type that can automatically be dereferenced into a `str`.
     fn from(s: S) -> Self {
-        Self(s.as_ref().to_string())
+        let s = s.as_ref();
+
+        Self(s.into(), s.chars().collect())
     }
 }
 
@@ -230,29 +874,60 @@ impl fmt::Display for Word {
     }
 }
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 /// A list of [`Word`]s
-pub struct Wordlist(Vec<Word>);
+///
+/// Looking up a word with [`Wordlist::contains`] or [`Wordlist::position`] takes constant
+/// time: the position of each word is stored in an index that is built the first time it
+/// is needed, so that lists that are never searched (e.g. the buckets of a guess) do not
+/// pay for it.
+pub struct Wordlist {
+    /// The words in their order.
+    words: Vec<Word>,
+    /// Position of the first occurrence of each word, sharing the characters of the words.
+    index: OnceLock<HashMap<Arc<str>, usize>>,
+}
 
 impl Wordlist {
+    /// Returns the list of the given `words`.
+    fn from_vec(words: Vec<Word>) -> Self {
+        Self {
+            words,
+            index: OnceLock::new(),
+        }
+    }
+
+    /// Returns the position of the first occurrence of each word, building it if needed.
+    fn index(&self) -> &HashMap<Arc<str>, usize> {
+        self.index.get_or_init(|| {
+            let mut index = HashMap::with_capacity(self.words.len());
+
+            for (i, w) in self.words.iter().enumerate() {
+                index.entry(w.0.clone()).or_insert(i);
+            }
+
+            index
+        })
+    }
+
     /// Loads the default wordlist from a file.
     pub fn load() -> Self {
-        include_str!("words.txt").lines().map(Word::from).collect()
+        preset::Preset::default().wordlist()
     }
 
     /// Returns the number of words in the list.
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.words.len()
     }
 
     /// Returns `true` if no words are in the list.
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.words.is_empty()
     }
 
     /// Returns an iterator over references to the words in the list.
-    pub fn iter(&self) -> ::std::slice::Iter<Word> {
-        self.0.iter()
+    pub fn iter(&self) -> ::std::slice::Iter<'_, Word> {
+        self.words.iter()
     }
 
     /// Returns an iterator over the words in the list that comply to the given `constraints`.
@@ -264,40 +939,368 @@ impl Wordlist {
     /// over pairs of word references and scores. The return values are sorted by the score
     /// in descending order. Two words with the same score will be sorted lexicographically.
     ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{Word, Wordlist};
+    /// let wordlist: Wordlist = ["latch", "hatch", "crate", "batch"].iter().map(Word::from).collect();
+    /// let ranked: Vec<_> = wordlist.rank_words().map(|(w, score)| (w.to_string(), score)).collect();
+    ///
+    /// assert_eq!(ranked, [("batch".into(), 3), ("hatch".into(), 3), ("latch".into(), 3), ("crate".into(), 2)]);
+    /// ```
+    ///
     /// [`filter_potential`]: Word::filter_potential
     pub fn rank_words(&self) -> impl Iterator<Item = (&Word, usize)> {
+        let mut scratch = Scratch::default();
+
         self.iter()
-            .map(|w| (w, w.filter_potential(self)))
-            .sorted_by(|a, b| (b.1).cmp(&a.1))
+            .map(move |w| (w, w.filter_potential_with(self, &mut scratch)))
+            .sorted_by(|(w1, s1), (w2, s2)| s2.cmp(s1).then(w1.cmp(w2)))
+    }
+
+    /// Returns an iterator over the words in the list that start with `prefix`.
+    pub fn starting_with<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a Word> {
+        self.iter().filter(move |w| w.0.starts_with(prefix))
+    }
+
+    /// Returns the longest common prefix of all the words in the list that start with
+    /// `prefix`. If there is no such word, `prefix` itself is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{Word, Wordlist};
+    /// let wordlist: Wordlist = ["crane", "crate", "cramp", "trace"].iter().map(Word::from).collect();
+    ///
+    /// assert_eq!(wordlist.common_prefix("c"), "cra");
+    /// assert_eq!(wordlist.common_prefix("crat"), "crate");
+    /// assert_eq!(wordlist.common_prefix("x"), "x");
+    /// ```
+    pub fn common_prefix(&self, prefix: &str) -> String {
+        let mut words = self.starting_with(prefix);
+
+        let Some(first) = words.next() else {
+            return prefix.to_string();
+        };
+
+        let mut common = &*first.0;
+
+        for w in words {
+            let len = common
+                .char_indices()
+                .zip(w.chars())
+                .find(|((_, c1), c2)| c1 != c2)
+                .map_or(common.len().min(w.0.len()), |((i, _), _)| i);
+
+            common = &common[..len];
+        }
+
+        common.to_string()
+    }
+
+    /// Returns `true` if `word` is in the list.
+    pub fn contains(&self, word: &str) -> bool {
+        self.index().contains_key(word)
+    }
+
+    /// Returns the words of the list that are at most `max_edits` edits away from `word`
+    /// (see [`edit_distance`]), the closest first and equally close ones in alphabetical
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{Word, Wordlist};
+    /// let wordlist: Wordlist = ["trace", "crate", "spoon", "grate"].iter().map(Word::from).collect();
+    ///
+    /// let closest: Vec<_> = wordlist.closest("crtae", 2).iter().map(|w| w.to_string()).collect();
+    ///
+    /// assert_eq!(closest, ["crate", "grate"]);
+    /// assert!(wordlist.closest("zzzzz", 2).is_empty());
+    /// ```
+    pub fn closest(&self, word: &str, max_edits: usize) -> Vec<&Word> {
+        let mut closest: Vec<_> = self
+            .iter()
+            .map(|w| (edit_distance(word, &w.0), w))
+            .filter(|&(distance, _)| distance <= max_edits)
+            .collect();
+
+        closest.sort_unstable();
+        closest.into_iter().map(|(_, w)| w).collect()
     }
 
-    /// Removes the given `word` from the list if it exists.
+    /// Returns the position of the first occurrence of `word` in the list, counting from 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{Word, Wordlist};
+    /// let wordlist: Wordlist = ["crate", "trace", "react"].iter().map(Word::from).collect();
+    ///
+    /// assert_eq!(wordlist.position("trace"), Some(1));
+    /// assert_eq!(wordlist.position("spoon"), None);
+    /// ```
+    pub fn position(&self, word: &str) -> Option<usize> {
+        self.index().get(word).copied()
+    }
+
+    /// Removes the given `word` from the list if it exists. The words after it are moved
+    /// up by one, so the index is only used (and kept up to date) if it has already been
+    /// built: for a single lookup, building it would take longer than searching the list.
     pub fn remove(&mut self, word: &str) {
-        if let Some(index) = self.iter().position(|w| w.0 == word) {
-            self.0.remove(index);
+        let position = match self.index.get() {
+            Some(index) => index.get(word).copied(),
+            None => self.iter().position(|w| &*w.0 == word),
+        };
+
+        let Some(i) = position else {
+            return;
+        };
+
+        self.words.remove(i);
+
+        let Some(index) = self.index.get_mut() else {
+            return;
+        };
+
+        index.remove(word);
+
+        // Only the words whose first occurrence came after the removed word move up, and a
+        // later occurrence of the removed word becomes the first one.
+        for (j, w) in self.words.iter().enumerate().skip(i) {
+            let first = index.entry(w.0.clone()).or_insert(j);
+
+            if *first == j + 1 {
+                *first = j;
+            }
+        }
+    }
+
+    /// Shuffles the words in a random order that only depends on `seed`.
+    pub fn shuffle(&mut self, seed: u64) {
+        let mut rng = Rng::new(seed);
+
+        // Fisher-Yates: each word is swapped with one of the words before it.
+        for i in (1..self.words.len()).rev() {
+            self.words.swap(i, rng.below(i + 1));
+        }
+
+        self.index.take();
+    }
+
+    /// Returns `n` different words chosen at random (or all words if the list is shorter),
+    /// in a random order. The choice only depends on `seed`, so it can be repeated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{Word, Wordlist};
+    /// let wordlist: Wordlist = ["crate", "trace", "react", "caret"].iter().map(Word::from).collect();
+    /// let sample = wordlist.sample(2, 42);
+    ///
+    /// assert_eq!(sample.len(), 2);
+    /// assert!(sample.iter().all(|w| wordlist.contains(&w.to_string())));
+    /// assert_eq!(wordlist.sample(2, 42).iter().collect::<Vec<_>>(), sample.iter().collect::<Vec<_>>());
+    /// assert_eq!(wordlist.sample(10, 42).len(), 4);
+    /// ```
+    pub fn sample(&self, n: usize, seed: u64) -> Wordlist {
+        let mut rng = Rng::new(seed);
+        let mut words = self.words.clone();
+        let n = n.min(words.len());
+
+        // The first `n` steps of a Fisher-Yates shuffle from the front.
+        for i in 0..n {
+            let j = i + rng.below(words.len() - i);
+            words.swap(i, j);
+        }
+
+        words.truncate(n);
+
+        Wordlist::from_vec(words)
+    }
+
+    /// Reads a wordlist with one word per line, e.g. from a string or a network stream.
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<Self> {
+        reader.lines().map(|line| line.map(Word::from)).collect()
+    }
+
+    /// Checks that the list is not empty and that all words are valid guesses according
+    /// to `alphabet`. Returns an error with all invalid words and their line numbers,
+    /// counting from 1. The words may have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{alphabet::Alphabet, Error, Wordlist};
+    /// let wordlist = Wordlist::from_reader("crate\ntr4ce\nchoice\nw0rd\n".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     wordlist.validate(&Alphabet::default()),
+    ///     Err(Error::InvalidWordlistEntries(vec![
+    ///         (2, "tr4ce".to_string()),
+    ///         (4, "w0rd".to_string())
+    ///     ]))
+    /// );
+    /// ```
+    pub fn validate(&self, alphabet: &Alphabet) -> Result<(), Error> {
+        if self.is_empty() {
+            return Err(Error::EmptyWordlist);
+        }
+
+        let invalid: Vec<_> = self
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| alphabet.validate(&w.0).is_err())
+            .map(|(i, w)| (i + 1, w.to_string()))
+            .collect();
+
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidWordlistEntries(invalid))
+        }
+    }
+
+    /// Cleans up a list read from a file: trims and lowercases the words, checks that they
+    /// are valid guesses according to `alphabet` (see [`Wordlist::validate`]), removes
+    /// blank lines and duplicates and, if `sort` is `true`, sorts the words. Returns the
+    /// cleaned list and a summary of what was changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{alphabet::Alphabet, Cleanup, Wordlist};
+    /// let wordlist = Wordlist::from_reader("Trace\n crate\n\ntrace\n".as_bytes()).unwrap();
+    /// let (wordlist, cleanup) = wordlist.clean(&Alphabet::default(), true).unwrap();
+    ///
+    /// assert_eq!(wordlist.iter().map(|w| w.to_string()).collect::<Vec<_>>(), ["crate", "trace"]);
+    /// assert_eq!(cleanup, Cleanup { normalized: 2, blank: 1, duplicates: 1 });
+    /// ```
+    pub fn clean(self, alphabet: &Alphabet, sort: bool) -> Result<(Wordlist, Cleanup), Error> {
+        let mut cleanup = Cleanup::default();
+
+        let normalized: Wordlist = self
+            .into_iter()
+            .map(|w| {
+                let normalized = alphabet.normalize(w.0.trim());
+
+                if *normalized != *w.0 {
+                    cleanup.normalized += 1;
+                }
+
+                Word::from(normalized)
+            })
+            .collect();
+
+        // Blank lines are checked as words so that the line numbers stay the same.
+        match normalized.validate(alphabet) {
+            Err(Error::InvalidWordlistEntries(entries)) => {
+                let entries: Vec<_> = entries
+                    .into_iter()
+                    .filter(|(_, word)| !word.is_empty())
+                    .collect();
+
+                if !entries.is_empty() {
+                    return Err(Error::InvalidWordlistEntries(entries));
+                }
+            }
+            Err(error) => return Err(error),
+            Ok(()) => {}
+        }
+
+        let mut seen = HashSet::new();
+        let mut words = vec![];
+
+        for w in normalized {
+            if w.0.is_empty() {
+                cleanup.blank += 1;
+            } else if seen.insert(w.clone()) {
+                words.push(w);
+            } else {
+                cleanup.duplicates += 1;
+            }
+        }
+
+        if sort {
+            words.sort();
+        }
+
+        if words.is_empty() {
+            return Err(Error::EmptyWordlist);
+        }
+
+        Ok((Wordlist::from_vec(words), cleanup))
+    }
+
+    /// Splits the list into lists of words with the same number of characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{Word, Wordlist};
+    /// let wordlist: Wordlist = ["crate", "choice", "trace"].iter().map(Word::from).collect();
+    /// let by_length = wordlist.split_by_length();
+    ///
+    /// assert_eq!(by_length.keys().collect::<Vec<_>>(), [&5, &6]);
+    /// assert_eq!(by_length[&5].len(), 2);
+    /// ```
+    pub fn split_by_length(self) -> BTreeMap<usize, Wordlist> {
+        let mut by_length: BTreeMap<usize, Vec<Word>> = BTreeMap::new();
+
+        for w in self {
+            by_length.entry(w.chars().count()).or_default().push(w);
         }
+
+        by_length
+            .into_iter()
+            .map(|(len, words)| (len, Wordlist::from_vec(words)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// What [`Wordlist::clean`] has changed
+pub struct Cleanup {
+    /// Number of words that were trimmed or lowercased.
+    pub normalized: usize,
+    /// Number of blank lines that were removed.
+    pub blank: usize,
+    /// Number of duplicate words that were removed.
+    pub duplicates: usize,
+}
+
+impl Cleanup {
+    /// Returns `true` if nothing was changed.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
     }
 }
 
-impl<P: AsRef<Path>> From<P> for Wordlist {
-    /// Loads a wordlist from a text file.
-    fn from(path: P) -> Self {
-        let file = File::open(path).expect("file not found!");
-        let reader = BufReader::new(file);
+#[cfg(feature = "std-fs")]
+impl Wordlist {
+    /// Loads a wordlist with one word per line from the text file at `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let unreadable = |e: io::Error| Error::UnreadableWordlist {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        };
+
+        let file = File::open(path).map_err(unreadable)?;
 
-        reader.lines().map(|w| Word::from(w.unwrap())).collect()
+        Self::from_reader(BufReader::new(file)).map_err(unreadable)
     }
 }
 
 impl FromIterator<Word> for Wordlist {
     fn from_iter<I: IntoIterator<Item = Word>>(iter: I) -> Self {
-        let mut wordlist = Wordlist::default();
-
-        for w in iter {
-            wordlist.0.push(w);
-        }
+        Wordlist::from_vec(iter.into_iter().collect())
+    }
+}
 
-        wordlist
+impl fmt::Debug for Wordlist {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Wordlist").field(&self.words).finish()
     }
 }
 
@@ -306,7 +1309,7 @@ impl IntoIterator for Wordlist {
     type IntoIter = ::std::vec::IntoIter<Word>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.words.into_iter()
     }
 }
 
@@ -315,7 +1318,7 @@ impl<'a> IntoIterator for &'a Wordlist {
     type IntoIter = ::std::slice::Iter<'a, Word>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.iter()
+        self.words.iter()
     }
 }
 
@@ -324,6 +1327,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::preset::Preset;
 
     #[rstest(
         input,
@@ -348,14 +1352,23 @@ mod tests {
         case("nylon", "___YG", "thorn", true),
         case("tacit", "G____", "thorn", true),
         case("crate", "__YG_", "haste", false),
+        case("crate", "__YGG", "haste", true),
         case("abase", "Y_Y__", "cacao", true),
         case("abaka", "Y_Y__", "cacao", true),
-        case("avian", "Y__G_", "cacao", true)
+        case("avian", "Y__G_", "cacao", true),
+        case("spell", "YYYY_", "poles", true),
+        case("spell", "YYYY_", "lelps", false),
+        case("sassy", "G____", "shout", true),
+        case("sassy", "G____", "sissy", false)
     )]
     fn test_is_match(input: &str, code: &str, target: &str, is_match: bool) {
         let constraint_set = ConstraintSet::try_from((input, code)).unwrap();
 
         assert_eq!(constraint_set.is_match(&Word::from(target)), is_match);
+        assert_eq!(
+            constraint_set.mismatch(&Word::from(target)).is_none(),
+            is_match
+        );
     }
 
     #[rstest(
@@ -389,4 +1402,182 @@ mod tests {
 
         assert_eq!(word.match_code(&Word::from(target)), code);
     }
+
+    #[rstest(
+        colors,
+        aliases,
+        expected,
+        case("GY_BX", GRAY_ALIASES, Ok("GY___".to_string())),
+        case("gy-.0", GRAY_ALIASES, Ok("GY___".to_string())),
+        case("GYxxx", "", Err(Error::InvalidColorCode { input: 'X', gray_aliases: String::new() })),
+        case("GY~~~", "~", Ok("GY___".to_string())),
+        case("GYZ__", GRAY_ALIASES, Err(Error::InvalidColorCode { input: 'Z', gray_aliases: GRAY_ALIASES.to_string() })),
+        // `ß` is `SS` in upper case, but is reported as it was entered.
+        case("GYß__", GRAY_ALIASES, Err(Error::InvalidColorCode { input: 'ß', gray_aliases: GRAY_ALIASES.to_string() }))
+    )]
+    fn test_normalize_code(colors: &str, aliases: &str, expected: Result<String, Error>) {
+        assert_eq!(normalize_code(colors, aliases), expected);
+    }
+
+    #[rstest(
+        word,
+        code,
+        case("crate", "GYGYG"),
+        case("spell", "_Y_GG"),
+        case("12+46=58", "GG_Y__YG")
+    )]
+    fn test_constraint_set_roundtrip(word: &str, code: &str) {
+        let constraints = ConstraintSet::try_from((word, code)).unwrap();
+
+        assert_eq!(constraints.to_string().parse(), Ok(constraints));
+    }
+
+    #[rstest(
+        input,
+        item,
+        case("c@0, r@2", "r@2"),
+        case("c@0, r~", "r~"),
+        case("c@0, r+", "r+"),
+        case("c@0,, a-", ""),
+        case("", ""),
+        case("c@99999999999999999999", "c@99999999999999999999"),
+        case("c@-0", "c@-0")
+    )]
+    fn test_constraint_set_from_str_err(input: &str, item: &str) {
+        assert_eq!(
+            input.parse::<ConstraintSet>(),
+            Err(Error::InvalidConstraint(item.to_string()))
+        );
+    }
+
+    #[rstest(
+        word,
+        code,
+        len,
+        case("crate", "GYG", 5),
+        case("cr", "GYG", 2),
+        case("", "G", 0),
+        // `İ` has two characters in lower case, but stays a single one.
+        case("İ", "GG", 1)
+    )]
+    fn test_constraint_set_length_mismatch(word: &str, code: &str, len: usize) {
+        assert_eq!(
+            ConstraintSet::try_from((word, code)),
+            Err(Error::IncorrectColorCodeLength {
+                input: code.to_string(),
+                len
+            })
+        );
+    }
+
+    #[rstest(
+        word,
+        code,
+        expected,
+        case("spell", "___YG", Ok(())),
+        case("abbey", "_YY__", Ok(())),
+        case("sassy", "GY___", Ok(())),
+        case("spell", "____Y", Err(vec![4, 5])),
+        case("abbey", "__Y__", Err(vec![2, 3])),
+        case("aaaaa", "Y____", Err(vec![1])),
+        case("aabcd", "YYGGG", Err(vec![1, 2])),
+        case("abcde", "YYYGG", Ok(())),
+        case("abcde", "YGGGG", Err(vec![1]))
+    )]
+    fn test_validate(word: &str, code: &str, expected: Result<(), Vec<usize>>) {
+        let constraints = ConstraintSet::try_from((word, code)).unwrap();
+
+        assert_eq!(
+            constraints.validate(),
+            expected.map_err(Error::ImpossibleCode)
+        );
+    }
+
+    #[test]
+    fn test_from_guess() {
+        let words: Vec<_> = Wordlist::load().iter().step_by(37).cloned().collect();
+
+        for guess in &words {
+            for target in &words {
+                let constraints = ConstraintSet::from_guess(guess, target);
+
+                assert_eq!(constraints.validate(), Ok(()), "{}", constraints);
+                assert!(constraints.is_match(target), "{} {}", constraints, target);
+            }
+        }
+    }
+
+    #[rstest(
+        preset,
+        case(Preset::Wordle),
+        case(Preset::Wordle6),
+        case(Preset::Wordle7)
+    )]
+    #[ignore = "checks all pairs of words, run with `cargo test --release -- --ignored`"]
+    fn test_find_inconsistency(preset: Preset) {
+        let wordlist = preset.wordlist();
+
+        assert_eq!(find_inconsistency(&wordlist, &wordlist), None);
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let wordlist = Wordlist::from_reader("crate\ntrace\n".as_bytes()).unwrap();
+
+        assert_eq!(wordlist.len(), 2);
+        assert!(wordlist.contains("trace"));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut wordlist: Wordlist = ["crate", "trace", "react", "trace", "caret"]
+            .iter()
+            .map(Word::from)
+            .collect();
+
+        assert_eq!(wordlist.position("react"), Some(2));
+
+        wordlist.remove("trace");
+        assert_eq!(wordlist.position("crate"), Some(0));
+        assert_eq!(wordlist.position("react"), Some(1));
+        assert_eq!(wordlist.position("trace"), Some(2));
+        assert_eq!(wordlist.position("caret"), Some(3));
+
+        wordlist.remove("trace");
+        wordlist.remove("spoon");
+        assert!(!wordlist.contains("trace"));
+        assert_eq!(wordlist.position("caret"), Some(2));
+        assert_eq!(wordlist.len(), 3);
+
+        // Without an index, the word is searched in the list.
+        wordlist.shuffle(1);
+        wordlist.remove("crate");
+        assert_eq!(wordlist.len(), 2);
+
+        for (i, w) in wordlist.iter().enumerate() {
+            assert_eq!(wordlist.position(&w.to_string()), Some(i));
+        }
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let wordlist = Wordlist::load();
+        let words = |wordlist: &Wordlist| wordlist.iter().map(|w| w.to_string()).collect_vec();
+
+        let mut shuffled = wordlist.clone();
+        shuffled.shuffle(7);
+        assert_ne!(words(&shuffled), words(&wordlist));
+        assert_eq!(
+            words(&shuffled).into_iter().sorted().collect_vec(),
+            words(&wordlist)
+        );
+
+        let mut again = wordlist.clone();
+        again.shuffle(7);
+        assert_eq!(words(&again), words(&shuffled));
+
+        let sample = wordlist.sample(100, 7);
+        assert_eq!(sample.iter().unique().count(), 100);
+        assert_ne!(words(&sample), words(&wordlist.sample(100, 8)));
+    }
 }
@@ -0,0 +1,142 @@
+//! Estimates of the number of rounds needed to find the mystery word, computed by looking
+//! a few rounds ahead.
+//!
+//! The score of [`Wordlist::rank_words`] only looks at the next guess. A word that splits
+//! the candidates into many buckets is usually good, but how many rounds are still needed
+//! depends on the sizes of the buckets and on how well they can be split up in turn.
+use crate::{
+    feedback::{FeedbackRule, WordleRule},
+    Word, Wordlist,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A bounded search over the buckets of the candidates
+pub struct Lookahead {
+    /// Number of rounds to look ahead before the rest is estimated.
+    depth: usize,
+    /// Number of best-ranked candidates that are tried as the next guess in each bucket.
+    width: usize,
+}
+
+impl Default for Lookahead {
+    fn default() -> Self {
+        Self::new(2, 5)
+    }
+}
+
+impl Lookahead {
+    /// Creates a search that looks `depth` rounds ahead and tries the `width` best-ranked
+    /// candidates as the next guess in each bucket.
+    pub fn new(depth: usize, width: usize) -> Self {
+        Self {
+            depth,
+            width: width.max(1),
+        }
+    }
+
+    /// Returns the expected number of rounds needed to find the mystery word, including
+    /// this one, if `guess` is played now and each word in `candidates` is equally likely
+    /// to be the mystery word.
+    ///
+    /// Only candidates are played in later rounds (as in hard mode). Beyond the search
+    /// depth, the next guess is assumed to tell all remaining candidates apart, so the
+    /// estimate is rather optimistic for large buckets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{lookahead::Lookahead, Word, Wordlist};
+    /// let candidates: Wordlist = ["crate", "trace", "react"].iter().map(Word::from).collect();
+    /// let lookahead = Lookahead::default();
+    ///
+    /// // "crate" is right in one out of three cases and tells the other two words apart.
+    /// let turns = lookahead.expected_turns(&Word::from("crate"), &candidates);
+    /// assert!((turns - 5.0 / 3.0).abs() < 1e-9);
+    /// ```
+    pub fn expected_turns(&self, guess: &Word, candidates: &Wordlist) -> f64 {
+        self.expected_turns_by(&WordleRule, guess, candidates)
+    }
+
+    /// Returns the expected number of rounds like [`Lookahead::expected_turns`], but with
+    /// the buckets of the feedback of `rule` instead of Wordle's.
+    pub fn expected_turns_by<R: FeedbackRule>(
+        &self,
+        rule: &R,
+        guess: &Word,
+        candidates: &Wordlist,
+    ) -> f64 {
+        self.evaluate(rule, guess, candidates, self.depth)
+    }
+
+    fn evaluate<R: FeedbackRule>(
+        &self,
+        rule: &R,
+        guess: &Word,
+        candidates: &Wordlist,
+        depth: usize,
+    ) -> f64 {
+        if candidates.is_empty() {
+            return 1.0;
+        }
+
+        let total = candidates.len() as f64;
+
+        let rest: f64 = rule
+            .buckets(guess, candidates)
+            .into_values()
+            .filter(|words| words.as_slice() != [guess])
+            .map(|words| {
+                let bucket: Wordlist = words.into_iter().cloned().collect();
+                bucket.len() as f64 * self.value(rule, &bucket, depth.saturating_sub(1))
+            })
+            .sum();
+
+        1.0 + rest / total
+    }
+
+    /// Returns the expected number of rounds needed to find the mystery word among
+    /// `candidates` with the best of the tried guesses.
+    fn value<R: FeedbackRule>(&self, rule: &R, candidates: &Wordlist, depth: usize) -> f64 {
+        let n = candidates.len() as f64;
+
+        match candidates.len() {
+            0 => 0.0,
+            1 => 1.0,
+            // One guess is right, all others are found in the next round.
+            _ if depth == 0 => (1.0 + 2.0 * (n - 1.0)) / n,
+            _ => rule
+                .rank_words(candidates)
+                .into_iter()
+                .take(self.width)
+                .map(|(w, _)| self.evaluate(rule, w, candidates, depth))
+                .fold(f64::INFINITY, f64::min),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wordlist(words: &[&str]) -> Wordlist {
+        words.iter().map(Word::from).collect()
+    }
+
+    #[test]
+    fn test_expected_turns() {
+        let lookahead = Lookahead::default();
+        let words = wordlist(&["crate"]);
+
+        assert_eq!(lookahead.expected_turns(&Word::from("crate"), &words), 1.0);
+        assert_eq!(lookahead.expected_turns(&Word::from("trace"), &words), 2.0);
+
+        let words = wordlist(&["batch", "catch", "hatch", "latch", "match", "patch"]);
+
+        // Each word only rules out itself, so the words have to be tried one by one.
+        let turns = lookahead.expected_turns(&Word::from("batch"), &words);
+        assert!(turns > 2.0);
+
+        let deep = Lookahead::new(6, 6).expected_turns(&Word::from("batch"), &words);
+        assert!((deep - 3.5).abs() < 1e-9);
+    }
+}
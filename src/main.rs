@@ -1,19 +1,129 @@
+mod baseline;
+mod config;
+mod engine;
+mod savegame;
+#[cfg(feature = "serve")]
+mod serve;
+mod term;
+
 use std::{
-    collections::HashMap,
-    io::{self, Write},
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    ffi::OsString,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
-use clap::{Parser, Subcommand};
+use baseline::{Baseline, Regression, Summary};
+use clap::{
+    builder::TypedValueParser,
+    error::{ContextKind, ContextValue, ErrorKind},
+    CommandFactory, Parser, Subcommand, ValueEnum,
+};
+use config::Config;
 use env_logger::{Builder, Target};
 use itertools::Itertools;
 use log::{debug, info, LevelFilter};
-use prompter::*;
-
-/// Length of the word to be guessed.
-const WORD_LEN: usize = 5;
+use prompter::{
+    cache::{Cache, Key},
+    coverage::{self, Weighting},
+    daily, entropy,
+    feedback::{self, FeedbackRule, WordleRule},
+    fibble::Fibble,
+    freq::Frequencies,
+    grade,
+    i18n::{Lang, Message},
+    lookahead::Lookahead,
+    matrix,
+    merge::MergedConstraints,
+    metadata::Metadata,
+    morphology,
+    multi::{default_round_limit, MultiGame},
+    notation::Turn,
+    offensive,
+    packed::Loader,
+    preset::{AnswerList, Preset},
+    prior::Prior,
+    recovery,
+    render::{self, render_board, Style},
+    rng, simulation,
+    solver::Solver,
+    spelling::Spelling,
+    stats::{self, GameRecord, Stats},
+    strategy::{self, Pool, Strategy, TieBreak},
+    transcript::{self, Outcome, Transcript},
+    xordle::Xordle,
+    *,
+};
+use savegame::SavedGame;
+use serde::Deserialize;
+
+/// Number of boards in a game of Quordle.
+const QUORDLE_BOARDS: usize = 4;
+
+/// Maximum number of words suggested for a word that is not accepted.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Maximum number of edits between a word that is not accepted and a suggestion for it.
+const MAX_SUGGESTION_EDITS: usize = 2;
+
+/// Number of rounds in a game of Xordle.
+const XORDLE_ROUNDS: usize = 9;
+
+/// Number of rounds in a game of Fibble.
+const FIBBLE_ROUNDS: usize = 8;
+
+/// Maximum number of boards whose whole history is shown in each round.
+const FULL_BOARD_NUM: usize = 4;
+
+/// Maximum number of candidates for which the probability of each one is shown.
+const MAX_PROBABILITIES: usize = 10;
+
+/// Factor by which the weight of the answers of previous games is multiplied with
+/// `--repeat-policy downweight`.
+const USED_ANSWER_FACTOR: f64 = 0.1;
+
+/// Command that saves the game in `play`, followed by the name of the file.
+const SAVE_COMMAND: &str = ":save";
+
+/// Command that changes the number of suggestions in `play`, followed by the number.
+const TOP_COMMAND: &str = ":top";
+
+/// Command that lists the commands and color codes at any prompt.
+const HELP_COMMAND: &str = ":help";
+
+/// Command that ends the game at any prompt.
+const QUIT_COMMAND: &str = ":quit";
+
+/// Exit codes, so that scripts can tell the outcomes of a command apart. A command that
+/// returns normally exits with 0, i.e. the word was found or the command succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exit {
+    /// A file could not be read or written, or the results are worse than the baseline.
+    Failure = 1,
+    /// The word was not found within the round limit, or `verify` found words that are not.
+    NotSolved = 2,
+    /// No word matches the feedback, i.e. the hints contradict each other.
+    NoCandidates = 3,
+    /// The game was ended with `:quit`, or the input ended before the game was over.
+    Aborted = 4,
+    /// The command line is invalid (`EX_USAGE`).
+    Usage = 64,
+    /// The input is invalid, e.g. an unknown word or a color code of the wrong length
+    /// (`EX_DATAERR`).
+    InvalidInput = 65,
+}
 
-/// Number of rounds to play.
-const ROUND_NUM: usize = 6;
+impl Exit {
+    /// Ends the program with this exit code.
+    fn exit(self) -> ! {
+        process::exit(self as i32)
+    }
+}
 
 #[derive(Parser)]
 #[clap(name = "prompter")]
@@ -21,282 +131,3404 @@ const ROUND_NUM: usize = 6;
 struct Cli {
     #[clap(subcommand)]
     command: Commands,
+
+    /// Mark Wordle's feedback with symbols instead of colors: [A] = Green, (a) = Yellow, .a. = Gray
+    #[clap(long, global = true, visible_alias = "no-color")]
+    ascii: bool,
+
+    /// Language of the messages (en, de) [default: taken from the locale]
+    #[clap(long, global = true, value_name = "LANG")]
+    lang: Option<Lang>,
+
+    /// Variant of Wordle that sets the word length, number of rounds and wordlist (wordle, wordle6, wordle7, nerdle, primel) [default: wordle]
+    #[clap(long, global = true, value_name = "NAME")]
+    preset: Option<Preset>,
+
+    /// Revision of Wordle's answers: the original list or the one curated by the NYT (classic, nyt) [default: classic]
+    #[clap(long, global = true, value_name = "LIST")]
+    list: Option<AnswerList>,
+
+    /// Only play words with British or American spelling, e.g. for clones of Wordle with a different dictionary (british, american, both) [default: both]
+    #[clap(long, global = true, value_name = "SPELLING")]
+    spelling: Option<Spelling>,
+
+    /// Load the words from FILE (one word per line) instead of the preset's wordlist
+    #[clap(long, global = true, value_name = "FILE")]
+    wordlist: Option<PathBuf>,
+
+    /// Strategy that ranks the suggestions, optionally with parameters (solve, survive, entropy, minimax, positional, lookahead[:k=N,depth=N], random[:seed=N]) [default: solve, or entropy with --frequencies]
+    #[clap(long, global = true, value_name = "NAME[:KEY=VALUE,...]")]
+    strategy: Option<Strategy>,
+
+    /// Words that may be suggested: only candidates, also probes that have been ruled out, or both with candidates first among equally good words (candidates, probes, mixed) [default: candidates]
+    #[clap(long, global = true, value_name = "POOL")]
+    suggest: Option<Pool>,
+
+    /// Order of equally good suggestions before they are sorted alphabetically: no other order, or the words most likely to be the mystery word first (alphabetical, likely) [default: alphabetical]
+    #[clap(long, global = true, value_name = "ORDER")]
+    tiebreak: Option<TieBreak>,
+
+    /// Among equally good suggestions, put regular plurals (ending in s) and past tenses (ending in ed) last because they are rarely the answer
+    #[clap(long, global = true)]
+    demote_inflections: bool,
+
+    /// Never suggest vulgar or offensive words, e.g. in a classroom or on a stream (they may still be the mystery word)
+    #[clap(long, global = true)]
+    family_friendly: bool,
+
+    /// Leave out the words with TAG in an extended wordlist file (word<TAB>frequency<TAB>tags), e.g. archaic
+    #[clap(long, global = true, value_name = "TAG")]
+    exclude_tag: Vec<String>,
+
+    /// Prefer common words: load the weight of each word from FILE (one word and its weight per line)
+    #[clap(long, global = true, value_name = "FILE")]
+    frequencies: Option<PathBuf>,
+
+    /// Multiply the weight of the words in the preset's wordlist of likely answers by W when --wordlist loads a dictionary [default: 1]
+    #[clap(long, global = true, value_name = "W", value_parser = parse_weight)]
+    answer_weight: Option<f64>,
+
+    /// Load the answers of previous games from FILE (one word per line)
+    #[clap(long, global = true, value_name = "FILE")]
+    used_answers: Option<PathBuf>,
+
+    /// How the answers of previous games are treated [default: exclude]
+    #[clap(long, global = true, value_name = "POLICY")]
+    repeat_policy: Option<RepeatPolicy>,
+
+    /// Sort the words loaded with --wordlist alphabetically, e.g. for `wordlist show`
+    #[clap(long, global = true)]
+    sort_wordlist: bool,
+
+    /// Play words with N characters: choose the preset of the same game or the words with N characters from the file loaded with --wordlist [default: the preset's word length]
+    #[clap(long, global = true, value_name = "N", value_parser = positive_parser())]
+    len: Option<usize>,
+
+    /// Number of words suggested in each round [default: 10]
+    #[clap(long, global = true, value_name = "N", value_parser = positive_parser())]
+    suggestions: Option<usize>,
+
+    /// Number of rounds allowed [default: the preset's, number of boards + 5 with several boards, 9 in Xordle, 8 in Fibble]
+    #[clap(long, global = true, value_name = "N", value_parser = positive_parser())]
+    rounds: Option<usize>,
+
+    /// Record a transcript of each game played with `play` in DIR (guesses, feedback, suggestions and outcome)
+    #[clap(long, global = true, value_name = "DIR")]
+    record: Option<PathBuf>,
+
+    /// Seed of all random choices, e.g. of `simulate --sample` and the random strategy, so that a run can be repeated [default: a new seed for each run, which is printed]
+    #[clap(long, global = true, value_name = "N")]
+    seed: Option<u64>,
+
+    /// Read the defaults for these options from FILE [default: ~/.config/prompter/config.toml]
+    #[clap(long, global = true, value_name = "FILE")]
+    config: Option<PathBuf>,
+
+    /// Use the config file ~/.config/prompter/profiles/NAME.toml and keep separate stats, e.g. for each player or language
+    #[clap(long, global = true, value_name = "NAME", value_parser = parse_profile)]
+    profile: Option<String>,
+
+    /// Neither load precomputed rankings from the cache directory (~/.cache/prompter) nor store them there
+    #[clap(long, global = true)]
+    no_cache: bool,
+
+    /// Show more details, e.g. each round of simulated games, timings and cache hits (-vv for even more)
+    #[clap(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Print how long each phase took (loading, ranking, filtering, ...) on stderr, one `time phase=NAME ms=N` line per phase
+    #[clap(long, global = true)]
+    time: bool,
+
+    /// Number of threads for the commands that can use several, e.g. `buckets` [default: the number of CPUs]
+    #[clap(short, long, global = true, value_name = "N", value_parser = positive_parser())]
+    jobs: Option<usize>,
+
+    /// Show fewer details, e.g. only the summary of `simulate --start WORD`
+    #[clap(short, long, global = true, action = clap::ArgAction::Count, conflicts_with = "verbose")]
+    quiet: u8,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Get help while playing Wordle
-    Play {},
+    Play {
+        /// Time each round and the whole game and compare the result to your personal best
+        #[clap(long)]
+        speedrun: bool,
+
+        /// Only accept guesses that match all previous hints
+        #[clap(long)]
+        hard: bool,
+
+        /// Print the spoiler-free emoji grid of the game for sharing when the game is over
+        #[clap(long)]
+        share: bool,
+
+        /// Read the input from FILE instead of the keyboard: guesses, color codes and the commands `list` and `undo`, separated by whitespace, and the commands `:save FILE` and `:top N`, each on a line of its own
+        #[clap(long, value_name = "FILE")]
+        script: Option<PathBuf>,
+
+        /// Survive as many rounds as possible without guessing the word, playing only words that match all hints (Survivle)
+        #[clap(long, conflicts_with_all = ["speedrun", "hard", "share"])]
+        survive: bool,
+
+        /// Number of boards that share the same guesses (2 = Dordle, 4 = Quordle, 8 = Octordle, ...)
+        #[clap(long, default_value_t = 1, value_parser = boards_parser(), conflicts_with_all = ["speedrun", "hard", "share", "survive"])]
+        boards: usize,
+
+        /// Play without a round limit, e.g. to practice; the game is not added to the stats
+        #[clap(long, conflicts_with_all = ["speedrun", "share", "survive", "boards", "rounds"])]
+        practice: bool,
+
+        /// Open with WORD and enter its color code right away, without ranking the words for the first round
+        #[clap(long, short, value_name = "WORD", conflicts_with_all = ["survive", "boards", "resume"])]
+        start: Option<String>,
+
+        /// Continue the game saved with `:save FILE`, with the same settings and candidates
+        #[clap(long, value_name = "FILE", conflicts_with_all = ["speedrun", "survive", "boards"])]
+        resume: Option<PathBuf>,
+
+        /// Number of words suggested in each round, like --suggestions (`:top N` changes it during the game)
+        #[clap(long, value_name = "N", value_parser = positive_parser())]
+        top: Option<usize>,
+    },
+    /// Get help while playing Quordle (four boards that share the same guesses)
+    Quordle,
+    /// Get help while playing Xordle (two words without common letters on one board)
+    Xordle,
+    /// Get help while playing Fibble (one row of feedback is a lie)
+    Fibble,
     /// Simulate a Wordle game
     Simulate {
         /// Start word
         #[clap(long, short, value_name = "WORD")]
         start: Option<String>,
 
-        /// Target word
+        /// Target word (one per board)
         #[clap(long, short, requires = "start", value_name = "WORD")]
-        target: Option<String>,
+        target: Vec<String>,
+
+        /// Survive as many rounds as possible without guessing the target word (Survivle)
+        #[clap(long)]
+        survive: bool,
+
+        /// Number of boards that share the same guesses (2 = Dordle, 4 = Quordle, 8 = Octordle, ...)
+        #[clap(long, default_value_t = 1, value_parser = boards_parser(), conflicts_with = "survive")]
+        boards: usize,
+
+        /// Play against N target words chosen at random (see --seed) instead of all words
+        #[clap(long, value_name = "N", conflicts_with = "target", value_parser = positive_parser())]
+        sample: Option<usize>,
+
+        /// Compare the results with the baseline saved in FILE and exit with an error code if they are worse
+        #[clap(long, value_name = "FILE", conflicts_with_all = ["target", "survive"])]
+        baseline: Option<PathBuf>,
+
+        /// Save the results to FILE as the baseline for later runs
+        #[clap(long, value_name = "FILE", conflicts_with_all = ["target", "survive"])]
+        save_baseline: Option<PathBuf>,
+    },
+    /// Let two strategies solve the same target words and compare them word by word
+    Duel {
+        /// The first strategy (solve, survive, entropy, minimax, positional, lookahead, random)
+        #[clap(value_name = "STRATEGY")]
+        first: Strategy,
+
+        /// The second strategy (solve, survive, entropy, minimax, positional, lookahead, random)
+        #[clap(value_name = "STRATEGY")]
+        second: Strategy,
+
+        /// Start word of the first strategy, given a second time for the second strategy [default: the word ranked best by each strategy]
+        #[clap(long, short, value_name = "WORD", num_args = 1, action = clap::ArgAction::Append)]
+        start: Vec<String>,
+
+        /// Target word [default: every word in the wordlist]
+        #[clap(long, short, value_name = "WORD")]
+        target: Vec<String>,
+    },
+    /// Check that the strategy finds every word in the wordlist within the round limit
+    Verify {
+        /// Start word [default: the word ranked best by the strategy]
+        #[clap(long, short, value_name = "WORD")]
+        start: Option<String>,
+    },
+    /// Suggest the next words after the given turns without an interactive game
+    Suggest {
+        /// A guess and Wordle's feedback, written as WORD=CODE (e.g. crate=Y_G_G) or WORD/DIGITS with 2 = Green, 1 = Yellow, 0 = Gray (e.g. crate/10202)
+        #[clap(value_name = "TURN")]
+        turns: Vec<Turn>,
+
+        /// Number of words suggested, like --suggestions
+        #[clap(long, value_name = "N", value_parser = positive_parser())]
+        top: Option<usize>,
     },
     /// Show the different "buckets" in which the words in the wordlist are sorted for WORD
     Buckets {
         #[clap(value_name = "WORD")]
         word: String,
+
+        /// Order of the buckets: by color code or by the number of words, largest first
+        #[clap(long, value_enum, default_value_t = BucketOrder::Code)]
+        sort: BucketOrder,
+
+        /// Only show the first N buckets
+        #[clap(long, value_name = "N")]
+        top: Option<usize>,
+
+        /// Only show the number of words in each bucket, not the words themselves
+        #[clap(long)]
+        counts_only: bool,
+
+        /// Only show buckets with at least K words
+        #[clap(long, default_value_t = 1, value_name = "K")]
+        min_size: usize,
+
+        /// Show the probability of each bucket and its contribution to the entropy of WORD
+        #[clap(long)]
+        entropy: bool,
+
+        /// Sort only the words that are left after a guess and Wordle's feedback, written as for `suggest` (can be repeated)
+        #[clap(long, value_name = "TURN")]
+        after: Vec<Turn>,
+    },
+    /// Explain whether WORD is still a candidate after the given guesses and, if not, which hint rules it out
+    Explain {
+        #[clap(value_name = "WORD")]
+        word: String,
+
+        /// A guess and Wordle's feedback, written as for `suggest` (can be repeated)
+        #[clap(long, value_name = "TURN")]
+        guess: Vec<Turn>,
+    },
+    /// Grade each guess of a game by skill (compared to the best word) and luck (compared to the expected number of words left)
+    Grade {
+        /// A guess and Wordle's feedback, written as for `suggest`
+        #[clap(value_name = "TURN", required = true)]
+        turns: Vec<Turn>,
+    },
+    /// Show a game recorded with --record round by round: the candidates left, the suggestions and the guess
+    Replay {
+        /// The transcript of the game
+        #[clap(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Wait for Enter before each round
+        #[clap(long)]
+        step: bool,
+    },
+    /// Show the number of today's puzzle and whether a game of it has been recorded
+    Daily,
+    /// Show the number of games played with `play`, the percentage won, the streaks of days with a win and the guess distribution
+    Stats {
+        /// Print a compact, spoiler-free summary for sharing instead
+        #[clap(long)]
+        share: bool,
+
+        #[clap(subcommand)]
+        action: Option<StatsAction>,
+    },
+    /// Manage the precomputed rankings in the cache directory (~/.cache/prompter)
+    Cache {
+        #[clap(subcommand)]
+        action: CacheAction,
+    },
+    /// Write the feedback for every pair of guess and mystery word to a file (see the documentation of `prompter::matrix` for the format)
+    Matrix {
+        /// The output file, written as CSV if it ends with .csv and in the binary format if it ends with .bin
+        #[clap(long, short, value_name = "FILE")]
+        out: PathBuf,
+    },
+    /// Find sets of opening words that together cover as many different letters as possible
+    Coverage {
+        /// Number of words in each set
+        #[clap(long, short, default_value_t = 2, value_name = "K", value_parser = positive_parser())]
+        words: usize,
+
+        /// Weight each letter by the number of words in the wordlist that contain it
+        #[clap(long)]
+        weighted: bool,
+    },
+    /// Show how many of the candidate words contain each letter
+    Freq {
+        /// Also show the frequencies at each position
+        #[clap(long)]
+        positional: bool,
+
+        /// Print the frequencies as JSON
+        #[clap(long)]
+        json: bool,
+
+        /// Count only the words that are left after a guess and Wordle's feedback, written as for `suggest` (can be repeated)
+        #[clap(long, value_name = "TURN")]
+        after: Vec<Turn>,
+    },
+    /// Inspect the wordlists and derive custom ones with the normalization that the solver uses
+    Wordlist {
+        #[clap(subcommand)]
+        action: WordlistAction,
+    },
+    /// Run an engine that is driven by line-delimited JSON requests on stdin
+    Engine,
+    /// Run an HTTP server that provides suggestions and buckets as JSON
+    #[cfg(feature = "serve")]
+    Serve {
+        /// Address to listen on
+        #[clap(long, default_value = "127.0.0.1:3000", value_name = "ADDR")]
+        addr: std::net::SocketAddr,
     },
 }
 
-fn main() {
-    let args = Cli::parse();
-
-    match &args.command {
-        Commands::Play {} => {
-            play();
-        }
-        Commands::Simulate { start, target } => {
-            let mut builder = Builder::new();
+/// What `wordlist` does
+#[derive(Subcommand)]
+enum WordlistAction {
+    /// List the embedded wordlists with their word length and number of words
+    List,
+    /// Print the words of the game (the preset's wordlist or the one loaded with --wordlist), one per line
+    Show,
+    /// Write the words of the game to FILE, one per line
+    Export {
+        /// The output file
+        #[clap(long, short, value_name = "FILE")]
+        out: PathBuf,
+    },
+    /// Print the words in FILE (or read from stdin) that have N characters
+    FilterLength {
+        #[clap(value_name = "N", value_parser = positive_parser())]
+        len: usize,
 
-            builder
-                .format(|buf, record| writeln!(buf, "{}", record.args()))
-                .target(Target::Stdout);
+        #[clap(value_name = "FILE")]
+        file: Option<PathBuf>,
+    },
+    /// Print the words in FILE (or read from stdin) in lowercase, without whitespace and punctuation that the preset does not use
+    ToLower {
+        #[clap(value_name = "FILE")]
+        file: Option<PathBuf>,
+    },
+}
 
-            let level = match (start, target) {
-                (Some(_), Some(_)) => LevelFilter::Debug,
-                (None, None) => LevelFilter::Warn,
-                (_, _) => LevelFilter::Info,
-            };
+/// What `cache` does
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Remove all cached rankings, so that they are computed again when they are needed
+    Clear,
+}
 
-            builder.filter_level(level);
-            builder.init();
-            simulate_all(start.as_ref(), target.as_ref());
-        }
-        Commands::Buckets { word } => {
-            let word = Word::from(word);
+/// What `stats` does besides showing the stats
+#[derive(Subcommand)]
+enum StatsAction {
+    /// Add the games in FILE with texts from Wordle's share button (e.g. copied from a chat) to the stats, skipping puzzles that have already been recorded
+    Import {
+        #[clap(value_name = "FILE")]
+        file: PathBuf,
+    },
+}
 
-            let wordlist = Wordlist::load();
+/// The order in which `buckets` lists the buckets
+#[derive(Clone, Copy, ValueEnum)]
+enum BucketOrder {
+    /// Alphabetically by the color code.
+    Code,
+    /// By the number of words, largest first.
+    Size,
+}
 
-            let mut map = HashMap::new();
+/// How the answers of previous games are treated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum RepeatPolicy {
+    /// They are no candidates, but may still be played.
+    #[default]
+    Exclude,
+    /// They are candidates, but less likely to be the mystery word than the other words.
+    Downweight,
+    /// They are treated like all other words.
+    Ignore,
+}
 
-            for w in &wordlist {
-                let code = word.match_code(w);
+/// How `buckets` shows the buckets
+struct BucketOptions {
+    sort: BucketOrder,
+    /// Maximum number of buckets shown.
+    top: Option<usize>,
+    /// Whether only the number of words in each bucket is shown.
+    counts_only: bool,
+    /// Minimum number of words in a bucket that is shown.
+    min_size: usize,
+    /// Whether the probability and entropy of each bucket are shown.
+    entropy: bool,
+}
 
-                let vec = map.entry(code).or_insert_with(Vec::new);
-                vec.push(w);
-            }
+/// Returns a parser for the supported numbers of boards.
+fn boards_parser() -> impl TypedValueParser<Value = usize> {
+    clap::value_parser!(u32).range(1..=64).map(|n| n as usize)
+}
 
-            println!("\"{}\" has {} Wordle buckets.", word, map.len());
+/// Returns a parser for counts that must be at least 1, e.g. of rounds or threads.
+fn positive_parser() -> impl TypedValueParser<Value = usize> {
+    clap::value_parser!(u32).range(1..).map(|n| n as usize)
+}
 
-            for (code, words) in map.iter().sorted() {
-                println!("\n{} ({} word{})", code, words.len(), plural(words.len()));
+/// Parses a weight, which has to be a positive number.
+fn parse_weight(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(weight) if weight.is_finite() && weight > 0.0 => Ok(weight),
+        _ => Err(format!("'{}' is not a positive number", s)),
+    }
+}
 
-                for w in words {
-                    println!("{}", w);
-                }
-            }
-        }
+/// Parses the name of a profile, which is used in file names.
+fn parse_profile(s: &str) -> Result<String, String> {
+    if !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    {
+        Ok(s.to_string())
+    } else {
+        Err(format!(
+            "'{}' is not a valid profile name (use letters, digits, '-' and '_')",
+            s
+        ))
     }
 }
 
-fn plural(number: usize) -> String {
-    let s = if number == 1 { "" } else { "s" };
+/// Minimum number of candidates for which the ranking is cached.
+const MIN_CACHED_CANDIDATES: usize = 500;
+
+/// Parses a ranking stored in the cache with one word and its score per line, or returns
+/// `None` if it contains words that are neither in `candidates` nor in `dictionary`.
+fn parse_ranking<'a>(
+    table: &str,
+    candidates: &'a Wordlist,
+    dictionary: &'a Wordlist,
+) -> Option<Vec<(&'a Word, usize)>> {
+    let words: HashMap<String, &Word> = dictionary
+        .iter()
+        .chain(candidates.iter())
+        .map(|w| (w.to_string(), w))
+        .collect();
+
+    table
+        .lines()
+        .map(|line| {
+            let (word, score) = line.split_once(' ')?;
+            Some((*words.get(word)?, score.parse().ok()?))
+        })
+        .collect()
+}
 
-    s.to_string()
+/// Returns the key of the pattern matrix of `wordlist` in the CSV format in the cache.
+fn matrix_key(wordlist: &Wordlist) -> Key {
+    Key::new("matrix").with_words(wordlist)
 }
 
-fn play() {
-    println!("Welcome! Let's play Wordle.");
+/// Number of words suggested in each round if not configured otherwise.
+const DEFAULT_SUGGESTIONS: usize = 10;
 
-    let mut wordlist = Wordlist::load();
+/// Seeds chosen for runs without `--seed` are smaller than this, so they are easy to type.
+const MAX_NEW_SEED: u64 = 1_000_000;
 
-    for i in 1..=ROUND_NUM {
-        println!(
-            "\n---[ Round #{} ]------------------------------------------------",
-            i
-        );
+/// Returns a seed for a run in which none was given, which differs from run to run.
+fn new_seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
 
-        let w_count = wordlist.len();
-        println!("\n{} candidate word{} left.", w_count, plural(w_count));
+    rng::splitmix64(nanos ^ u64::from(process::id())) % MAX_NEW_SEED
+}
 
-        let start = std::time::Instant::now();
-        let candidates = wordlist.rank_words();
-        let duration = start.elapsed();
+/// Settings that control how `prompter` talks to the user
+#[derive(Clone)]
+struct Settings {
+    style: Style,
+    lang: Lang,
+    preset: Preset,
+    /// Number of characters of the words.
+    word_len: usize,
+    /// Revision of the preset's wordlist.
+    list: AnswerList,
+    /// Spelling of the words.
+    spelling: Spelling,
+    /// Words loaded from a file that replace the preset's wordlist.
+    wordlist: Option<Wordlist>,
+    /// Number of words suggested in each round.
+    suggestions: usize,
+    /// Number of rounds allowed instead of the default of the game, if given.
+    rounds: Option<usize>,
+    /// Characters accepted for gray in color codes besides `_`.
+    gray_aliases: String,
+    /// The probability of each word being the mystery word.
+    prior: Prior,
+    /// Strategy that ranks the suggestions, if chosen.
+    strategy: Option<Strategy>,
+    /// Words that may be suggested.
+    pool: Pool,
+    /// Order of equally good suggestions.
+    tiebreak: TieBreak,
+    /// Whether inflected forms come last among equally good suggestions.
+    demote_inflections: bool,
+    /// Whether offensive words are left out of the suggestions.
+    family_friendly: bool,
+    /// Frequencies and tags of the words in the wordlist file.
+    metadata: Metadata,
+    /// Answers of previous games that are no candidates.
+    excluded: Option<Wordlist>,
+    /// Seed of the random choices.
+    seed: u64,
+    /// Directory in which the transcripts of the games are recorded.
+    record: Option<PathBuf>,
+    /// Profile whose stats are used.
+    profile: Option<String>,
+    /// Cache of precomputed rankings.
+    cache: Option<Cache>,
+    /// Whether the time of each phase is printed.
+    time: bool,
+    /// Number of threads for the commands that can use several.
+    jobs: usize,
+}
 
-        println!("\nTop candidate word{}:", plural(w_count));
+impl Settings {
+    /// Returns the text of `msg` in the selected language.
+    fn tr(&self, msg: Message) -> String {
+        self.lang.tr(msg)
+    }
 
-        for (w, score) in candidates.take(10) {
-            println!("{} ({})", w, score);
+    /// Runs `f` and prints how long it took as `phase` if `--time` is given.
+    fn timed<T>(&self, phase: &str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        self.print_time(phase, start.elapsed());
+
+        result
+    }
+
+    /// Prints how long `phase` took if `--time` is given, on stderr so that it does not mix
+    /// with output that is piped on.
+    fn print_time(&self, phase: &str, duration: Duration) {
+        if self.time {
+            let ms = duration.as_secs_f64() * 1000.0;
+            eprintln!("time phase={} ms={:.3}", phase, ms);
         }
-        debug!("\nTime elapsed for word ranking: {:?}", duration);
+    }
 
-        if wordlist.len() == 1 {
-            println!("\nCongratulations! You won after {} round{}.", i, plural(i));
-            break;
+    /// Returns the number of rounds to play.
+    fn rounds(&self) -> usize {
+        self.round_limit(self.preset.rounds_for(self.word_len))
+    }
+
+    /// Returns the number of rounds to play in a game in which `default` rounds are allowed
+    /// unless `--rounds` is given.
+    fn round_limit(&self, default: usize) -> usize {
+        self.rounds.unwrap_or(default)
+    }
+
+    /// Returns the words of the game.
+    fn wordlist(&self) -> Wordlist {
+        let wordlist = match &self.wordlist {
+            Some(wordlist) => wordlist.clone(),
+            None => self.preset.answers(self.list),
+        };
+
+        self.spelling.filter(wordlist)
+    }
+
+    /// Returns `true` if the words of the game come from files (`--wordlist` or
+    /// `--used-answers`), which saved games do not record.
+    fn has_custom_words(&self) -> bool {
+        self.wordlist.is_some() || self.excluded.is_some()
+    }
+
+    /// Returns the words that may be the mystery word, i.e. the words of the game without
+    /// the excluded answers of previous games.
+    fn answers(&self) -> Wordlist {
+        match &self.excluded {
+            Some(excluded) => self
+                .wordlist()
+                .into_iter()
+                .filter(|w| !excluded.contains(&w.to_string()))
+                .collect(),
+            None => self.wordlist(),
         }
+    }
 
-        let mut word = get_user_word(i);
+    /// Returns the chosen strategy. By default, this is `Solve` or, if a frequency file has
+    /// been loaded, `Entropy`.
+    fn strategy(&self) -> Strategy {
+        match self.strategy {
+            Some(strategy) => strategy,
+            None if self.prior.is_uniform() => Strategy::Solve,
+            None => Strategy::Entropy,
+        }
+    }
 
-        while let Err(error) = word {
-            println!("\nError: {}", error);
-            word = get_user_word(i);
+    /// Ranks the words of the chosen pool with the chosen strategy by how well they sort
+    /// the candidates into buckets. `dictionary` contains all words that may be played.
+    fn rank<'a>(
+        &self,
+        candidates: &'a Wordlist,
+        dictionary: &'a Wordlist,
+    ) -> Vec<(&'a Word, usize)> {
+        let ranked = self.timed("ranking", || self.rank_cached(candidates, dictionary));
+        let ranked = self.suggestable(ranked);
+        let ranked = self.tiebreak.apply(ranked, candidates, &self.prior);
+
+        if !self.demote_inflections {
+            return ranked;
         }
 
-        let mut constraints = get_contraints(word.as_ref().unwrap());
+        // With `--suggest mixed`, the candidates stay in front of the probes.
+        let is_candidate: HashSet<&Word> = candidates.iter().collect();
 
-        while let Err(error) = constraints {
-            println!("\nError: {}", error);
-            constraints = get_contraints(word.as_ref().unwrap());
+        strategy::break_ties(ranked, |w| {
+            (
+                self.pool == Pool::Mixed && !is_candidate.contains(w),
+                morphology::is_inflected(w),
+            )
+        })
+    }
+
+    /// Ranks the words of the chosen pool with the chosen strategy. The rankings of many
+    /// candidates, e.g. for the first guess, take long and are therefore cached.
+    fn rank_cached<'a>(
+        &self,
+        candidates: &'a Wordlist,
+        dictionary: &'a Wordlist,
+    ) -> Vec<(&'a Word, usize)> {
+        let rank = || {
+            self.pool
+                .rank(self.strategy(), dictionary, candidates, &self.prior)
+        };
+
+        let cache = match &self.cache {
+            Some(cache) if self.is_cacheable(candidates) => cache,
+            _ => return rank(),
+        };
+
+        let key = Key::new("ranking")
+            .with(&self.strategy().to_string())
+            .with(&self.pool.to_string())
+            .with_words(dictionary)
+            .with_words(candidates);
+
+        if let Some(ranked) = cache
+            .load(&key)
+            .and_then(|table| parse_ranking(&table, candidates, dictionary))
+        {
+            info!("Loaded the ranking from the cache: {}", key.file_name());
+            return ranked;
         }
 
-        if constraints.as_ref().unwrap().correct_word() {
-            println!("\nCongratulations! You won after {} round{}.", i, plural(i));
-            break;
+        let ranked = rank();
+        let table: String = ranked
+            .iter()
+            .map(|(w, score)| format!("{} {}\n", w, score))
+            .collect();
+
+        // The cache only saves time, so the ranking is used even if it cannot be stored.
+        if let Err(error) = cache.store(&key, &table) {
+            debug!("Could not cache the ranking: {}", error);
         }
 
-        wordlist = Wordlist::from_iter(wordlist.filter(&constraints.unwrap()));
-        wordlist.remove(word.as_ref().unwrap());
+        ranked
+    }
 
-        if wordlist.len() > 1 && i == ROUND_NUM {
-            println!("\n{} candidate words left.", wordlist.len());
-            println!("\nGame over.");
-            break;
+    /// Returns `true` if the ranking of `candidates` is cached.
+    fn is_cacheable(&self, candidates: &Wordlist) -> bool {
+        // Other priors are not part of the key, and random rankings are quick, but would
+        // be cached once per seed.
+        self.cache.is_some()
+            && candidates.len() >= MIN_CACHED_CANDIDATES
+            && self.prior.is_uniform()
+            && !matches!(self.strategy(), Strategy::Random { .. })
+    }
+
+    /// Returns the word that [`Settings::rank`] ranks first and its score. If the ranking is
+    /// not cached and only the score decides the order, the other words are not ranked
+    /// completely (see [`Strategy::best_guess`]), which is much faster.
+    fn best<'a>(
+        &self,
+        candidates: &'a Wordlist,
+        dictionary: &'a Wordlist,
+    ) -> Option<(&'a Word, usize)> {
+        if self.is_cacheable(candidates)
+            || self.family_friendly
+            || self.demote_inflections
+            || self.tiebreak != TieBreak::Alphabetical
+        {
+            return self.rank(candidates, dictionary).first().copied();
         }
 
-        if wordlist.is_empty() {
-            println!("\nSomething went wrong. There are no matching words left.");
-            break;
+        self.timed("ranking", || {
+            self.pool
+                .best(self.strategy(), dictionary, candidates, &self.prior)
+        })
+    }
+
+    /// Removes the words that must not be suggested from `ranked`, i.e. the offensive words
+    /// in family-friendly mode.
+    fn suggestable<'a>(&self, ranked: Vec<(&'a Word, usize)>) -> Vec<(&'a Word, usize)> {
+        if !self.family_friendly {
+            return ranked;
         }
+
+        ranked
+            .into_iter()
+            .filter(|(w, _)| !offensive::is_offensive(w))
+            .collect()
+    }
+
+    /// Normalizes a word entered by the user for the preset's alphabet (see
+    /// [`normalize_word`]).
+    fn normalize(&self, input: &str) -> String {
+        normalize_word(input, &self.preset.alphabet())
     }
 }
 
-fn simulate(start: &Word, target: &Word) -> Option<usize> {
-    let mut wordlist = Wordlist::load();
+/// Loads the words with `word_len` characters from the file at `path`, which may contain
+/// words of different lengths, after cleaning them up (see [`Wordlist::clean`]).
+fn load_wordlist(
+    path: &Path,
+    preset: Preset,
+    word_len: usize,
+    sort: bool,
+    lang: Lang,
+) -> Result<(Wordlist, Metadata), Error> {
+    let unreadable = |e: io::Error| Error::UnreadableWordlist {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    };
 
-    debug!("{} -> {}", start, target);
+    // The words are checked and packed one line at a time, so that only the words with
+    // the right length are kept in memory, even for very large dictionaries.
+    let alphabet = preset.alphabet();
+    let mut loader = Loader::new(&alphabet, word_len)?;
+    let mut metadata = Metadata::default();
+
+    for (i, line) in BufReader::new(File::open(path).map_err(unreadable)?)
+        .lines()
+        .enumerate()
+    {
+        let line = line.map_err(unreadable)?;
+        loader.push(metadata.push_line(i + 1, &line)?);
+    }
 
-    for i in 1..=ROUND_NUM {
-        debug!(
-            "\n---[ Round #{} ]------------------------------------------------",
-            i
+    let (mut words, cleanup) = loader.finish()?;
+
+    if sort {
+        words.sort();
+    }
+
+    // The summary goes to stderr so that it does not mix with output that is piped on.
+    if !cleanup.is_empty() {
+        eprintln!(
+            "{}",
+            lang.tr(Message::WordlistCleaned {
+                path: &path.display().to_string(),
+                cleanup,
+            })
         );
+    }
 
-        let w_count = wordlist.len();
-        debug!("\n{} candidate word{} left.", w_count, plural(w_count));
+    Ok((Wordlist::from(words), metadata))
+}
 
-        let w = match i {
-            1 => start,
-            _ => wordlist.rank_words().next().unwrap().0,
-        };
+/// Log levels from the least to the most detailed.
+const LOG_LEVELS: [LevelFilter; 6] = [
+    LevelFilter::Off,
+    LevelFilter::Error,
+    LevelFilter::Warn,
+    LevelFilter::Info,
+    LevelFilter::Debug,
+    LevelFilter::Trace,
+];
+
+/// Sets up the details that are shown besides the regular output: one level more for each
+/// `-v` and one less for each `-q`.
+fn init_logger(args: &Cli) {
+    // `simulate` shows each game if the start word is fixed, and each round if the target
+    // word is fixed as well.
+    let default = match &args.command {
+        Commands::Simulate { start, target, .. } => match (start, target.is_empty()) {
+            (Some(_), false) => LevelFilter::Debug,
+            (None, true) => LevelFilter::Warn,
+            (_, _) => LevelFilter::Info,
+        },
+        _ => LevelFilter::Warn,
+    };
 
-        debug!("Top candidate word: {}", w);
+    let default = LOG_LEVELS.iter().position(|&level| level == default);
+    let level = (default.unwrap_or_default() + usize::from(args.verbose))
+        .saturating_sub(usize::from(args.quiet))
+        .min(LOG_LEVELS.len() - 1);
 
-        if wordlist.len() == 1 {
-            debug!("\nI won after {} round{}.", i, plural(i));
-            return Some(i);
-        }
+    Builder::new()
+        .format(|buf, record| writeln!(buf, "{}", record.args()))
+        .target(Target::Stdout)
+        .filter_level(LOG_LEVELS[level])
+        .init();
+}
 
-        let w_string = w.to_string();
-        let color_code = w.match_code(target);
-        debug!("Wordle hint: {}", color_code);
+/// Loads the config file at `path`, or the default config if there is none.
+fn load_config(path: Option<PathBuf>) -> Config {
+    match path {
+        Some(path) => Config::load(path).unwrap_or_else(|error| {
+            println!("{}", Lang::from_env().tr(Message::Error(&error)));
+            Exit::Failure.exit();
+        }),
+        None => Config::default(),
+    }
+}
 
-        let constraints = ConstraintSet::try_from((w_string.as_ref(), color_code.as_ref()));
+/// Returns the value of the option `name` (e.g. `--config`) in the command line `args`,
+/// given as `NAME VALUE` or `NAME=VALUE`.
+fn option_value(args: &[OsString], name: &str) -> Option<OsString> {
+    args.iter().enumerate().find_map(|(i, arg)| {
+        let arg = arg.to_str()?;
 
-        if constraints.as_ref().unwrap().correct_word() {
-            debug!("\nI won after {} round{}.", i, plural(i));
-            return Some(i);
+        match arg.strip_prefix(name)? {
+            "" => args.get(i + 1).cloned(),
+            value => value.strip_prefix('=').map(OsString::from),
         }
+    })
+}
 
-        wordlist = Wordlist::from_iter(wordlist.filter(&constraints.unwrap()));
-        wordlist.remove(&w_string);
+/// Parses the command line. An unknown subcommand may be an alias from the config file,
+/// which is replaced by the arguments it stands for.
+fn parse_args() -> Cli {
+    let args: Vec<OsString> = std::env::args_os().collect();
 
-        if wordlist.len() > 1 && i == ROUND_NUM {
-            debug!("\n{} candidate words left.", wordlist.len());
-            debug!("\nGame over.");
-            break;
-        }
-    }
+    let error = match Cli::try_parse_from(&args) {
+        Ok(cli) => return cli,
+        Err(error) => error,
+    };
 
-    None
+    let name = match error.get(ContextKind::InvalidSubcommand) {
+        Some(ContextValue::String(name)) => name.clone(),
+        _ => exit_with_usage(error),
+    };
+
+    // The options that choose the config file cannot be parsed before the alias is known.
+    let profile = option_value(&args, "--profile").and_then(|p| p.into_string().ok());
+    let path = option_value(&args, "--config")
+        .map(PathBuf::from)
+        .or_else(|| Config::default_path(profile.as_deref()));
+
+    match load_config(path).expand_alias(&args, &name) {
+        Some(args) => Cli::try_parse_from(args).unwrap_or_else(|error| exit_with_usage(error)),
+        None => exit_with_usage(error),
+    }
 }
 
-fn word_iter<'a>(
-    word_opt: Option<&'a Word>,
-    wordlist: &'a Wordlist,
-) -> impl Iterator<Item = &'a Word> {
-    let iter = if word_opt.is_none() {
-        Some(wordlist.iter())
+fn main() {
+    let args = parse_args();
+    init_logger(&args);
+
+    let load_start = Instant::now();
+
+    let profile = args.profile.as_deref();
+    let config = load_config(
+        args.config
+            .clone()
+            .or_else(|| Config::default_path(profile)),
+    );
+
+    let style = if args.ascii || config.ascii || std::env::var_os("NO_COLOR").is_some() {
+        Style::Ascii
     } else {
-        None
+        Style::Color
     };
 
-    iter.into_iter().flatten().chain(word_opt)
-}
+    let lang = args.lang.or(config.lang).unwrap_or_else(Lang::from_env);
+    let mut preset = args.preset.or(config.preset).unwrap_or_default();
+    let wordlist_path = args.wordlist.as_ref().or(config.wordlist.as_ref());
+    let word_len = args.len.unwrap_or(preset.word_len());
+
+    // Without a wordlist file, the length chooses the preset of the same game.
+    if wordlist_path.is_none() && word_len != preset.word_len() {
+        preset = preset.with_word_len(word_len).unwrap_or_else(|| {
+            println!(
+                "{}",
+                lang.tr(Message::InvalidInput(&Error::UnsupportedWordLength(
+                    word_len
+                )))
+            );
+            Exit::InvalidInput.exit();
+        });
+    }
 
-fn simulate_all(start: Option<&String>, target: Option<&String>) {
-    let wordlist = Wordlist::load();
+    let list = args.list.or(config.list).unwrap_or_default();
+    let sort = args.sort_wordlist || config.sort_wordlist;
+    let excluded_tags = if args.exclude_tag.is_empty() {
+        &config.exclude_tags
+    } else {
+        &args.exclude_tag
+    };
+    let (wordlist, metadata) = match wordlist_path {
+        Some(path) => {
+            let (wordlist, metadata) = load_wordlist(path, preset, word_len, sort, lang)
+                .and_then(|(wordlist, metadata)| {
+                    let wordlist = metadata.exclude_tags(wordlist, excluded_tags);
+
+                    if wordlist.is_empty() {
+                        Err(Error::EmptyWordlist)
+                    } else {
+                        Ok((wordlist, metadata))
+                    }
+                })
+                .unwrap_or_else(|error| {
+                    println!("{}", lang.tr(Message::InvalidInput(&error)));
+                    Exit::InvalidInput.exit();
+                });
+
+            (Some(wordlist), metadata)
+        }
+        None => (None, Metadata::default()),
+    };
 
-    let start_word = start.map(Word::from);
-    let start_words = word_iter(start_word.as_ref(), &wordlist);
+    // The frequencies in the wordlist file are used unless a frequency file is given.
+    let mut prior = args
+        .frequencies
+        .as_ref()
+        .or(config.frequencies.as_ref())
+        .map_or_else(
+            || metadata.prior().unwrap_or_else(Prior::uniform),
+            |path| {
+                Prior::from_file(path).unwrap_or_else(|error| {
+                    println!("{}", lang.tr(Message::InvalidInput(&error)));
+                    Exit::InvalidInput.exit();
+                })
+            },
+        );
 
-    for s in start_words {
-        let mut scores = Vec::with_capacity(wordlist.len());
+    let used_answers = args
+        .used_answers
+        .as_ref()
+        .or(config.used_answers.as_ref())
+        .map(|path| {
+            load_wordlist(path, preset, word_len, sort, lang)
+                .unwrap_or_else(|error| {
+                    println!("{}", lang.tr(Message::InvalidInput(&error)));
+                    Exit::InvalidInput.exit();
+                })
+                .0
+        });
+    let repeat_policy = args
+        .repeat_policy
+        .or(config.repeat_policy)
+        .unwrap_or_default();
+
+    // The NYT has not repeated any answer so far.
+    let excluded = match used_answers {
+        Some(used) if repeat_policy == RepeatPolicy::Exclude => Some(used),
+        Some(used) if repeat_policy == RepeatPolicy::Downweight => {
+            prior = prior.with_factor(&used, USED_ANSWER_FACTOR);
+            None
+        }
+        _ => None,
+    };
 
-        let target_word = target.map(Word::from);
-        let target_words = word_iter(target_word.as_ref(), &wordlist);
+    // The preset's wordlist only contains likely answers, so its words are preferred among
+    // those of a dictionary loaded instead.
+    if wordlist.is_some() {
+        if let Some(factor) = args.answer_weight.or(config.answer_weight) {
+            prior = prior.with_factor(&preset.answers(list), factor);
+        }
+    }
 
-        for t in target_words {
-            if let Some(score) = simulate(s, t) {
-                scores.push(score);
-                info!("{} -> {}: Won after {} round{}", s, t, score, plural(score));
+    let seed = args.seed.unwrap_or_else(new_seed);
+
+    let settings = Settings {
+        style,
+        lang,
+        preset,
+        word_len,
+        list,
+        spelling: args.spelling.or(config.spelling).unwrap_or_default(),
+        wordlist,
+        suggestions: args
+            .suggestions
+            .or(config.suggestions)
+            .unwrap_or(DEFAULT_SUGGESTIONS),
+        rounds: args.rounds,
+        gray_aliases: config.gray.unwrap_or_else(|| GRAY_ALIASES.to_string()),
+        prior,
+        strategy: args
+            .strategy
+            .or(config.strategy)
+            .map(|strategy| strategy.with_seed(seed)),
+        pool: args.suggest.or(config.suggest).unwrap_or_default(),
+        tiebreak: args.tiebreak.or(config.tiebreak).unwrap_or_default(),
+        demote_inflections: args.demote_inflections || config.demote_inflections,
+        family_friendly: args.family_friendly || config.family_friendly,
+        metadata,
+        excluded,
+        seed,
+        record: args.record.clone().or(config.record.clone()),
+        profile: args.profile.clone(),
+        cache: Cache::default_dir()
+            .filter(|_| !args.no_cache)
+            .map(Cache::new),
+        time: args.time,
+        jobs: args.jobs.unwrap_or_else(|| {
+            std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+        }),
+    };
+
+    settings.print_time("load", load_start.elapsed());
+
+    // A seed that was not given is printed if it is used, so that the run can be repeated.
+    let random = |strategy: &Strategy| matches!(strategy, Strategy::Random { .. });
+    let uses_seed = random(&settings.strategy())
+        || match &args.command {
+            Commands::Simulate { sample, .. } => sample.is_some(),
+            Commands::Duel { first, second, .. } => random(first) || random(second),
+            _ => false,
+        };
+
+    if args.seed.is_none() && uses_seed {
+        eprintln!("{}", settings.tr(Message::RandomSeed(seed)));
+    }
+
+    // The survival strategy only applies to `play` if no options were given that cannot be
+    // combined with it.
+    let strategy_survive = settings.strategy() == Strategy::Survive;
+
+    match &args.command {
+        Commands::Play {
+            speedrun,
+            hard,
+            share,
+            script,
+            survive,
+            boards,
+            practice,
+            start,
+            resume,
+            top,
+        } => {
+            let settings = Settings {
+                suggestions: top.unwrap_or(settings.suggestions),
+                ..settings
+            };
+
+            if let Some(path) = script {
+                match fs::read_to_string(path) {
+                    Ok(script) => term::set_script(&script),
+                    Err(error) => {
+                        println!("{}", settings.tr(Message::Error(&error.to_string())));
+                        Exit::Failure.exit();
+                    }
+                }
+            }
+
+            if *boards > 1 {
+                let rounds = settings.round_limit(default_round_limit(*boards));
+                play_multi(*boards, rounds, &settings);
+            } else if let Some(path) = resume {
+                // The candidates would differ from those of the saved game.
+                if settings.has_custom_words() {
+                    println!("{}", settings.tr(Message::CustomWordsNotSaved));
+                    Exit::Usage.exit();
+                }
+
+                let saved = SavedGame::load(path).unwrap_or_else(|error| {
+                    println!("{}", settings.tr(Message::Error(&error)));
+                    Exit::Failure.exit();
+                });
+
+                // The saved settings decide which words are candidates.
+                let settings = Settings {
+                    preset: saved.preset,
+                    word_len: saved.len,
+                    list: saved.list,
+                    spelling: saved.spelling,
+                    ..settings
+                };
+
+                let hard = saved.hard || *hard || config.hard;
+                play(
+                    false,
+                    hard,
+                    *share,
+                    *practice,
+                    None,
+                    &saved.turns,
+                    &settings,
+                );
+            } else if *survive || (strategy_survive && !(*speedrun || *hard || *share)) {
+                play_survive(&settings);
             } else {
-                info!("{} -> {}: Lost", s, t);
+                play(
+                    *speedrun,
+                    *hard || config.hard,
+                    *share,
+                    *practice,
+                    start.as_deref(),
+                    &[],
+                    &settings,
+                );
             }
         }
-
-        if !(start.is_some() && target.is_some()) {
-            print_results(s, scores.iter().sum(), scores.len(), wordlist.len());
+        Commands::Quordle => {
+            let rounds = settings.round_limit(default_round_limit(QUORDLE_BOARDS));
+            play_multi(QUORDLE_BOARDS, rounds, &settings);
         }
-    }
-}
+        Commands::Xordle => {
+            play_xordle(settings.round_limit(XORDLE_ROUNDS), &settings);
+        }
+        Commands::Fibble => {
+            play_fibble(settings.round_limit(FIBBLE_ROUNDS), &settings);
+        }
+        Commands::Simulate {
+            start,
+            target,
+            survive,
+            boards,
+            sample,
+            baseline,
+            save_baseline,
+        } => {
+            if !target.is_empty() && target.len() != *boards {
+                exit_with_usage(Cli::command().error(
+                    ErrorKind::WrongNumberOfValues,
+                    format!("--target must be given once per board ({} times)", boards),
+                ));
+            }
 
-fn print_results(start_word: &Word, total_score: usize, won_count: usize, game_count: usize) {
-    let won_percentage = won_count as f32 / game_count as f32 * 100.0;
-    let avg_score = total_score as f32 / won_count as f32;
+            // The baseline is loaded first, so that a missing file is reported right away.
+            let baseline = baseline.as_ref().map(|path| {
+                Baseline::load(path).unwrap_or_else(|error| {
+                    println!("{}", settings.tr(Message::Error(&error)));
+                    Exit::Failure.exit();
+                })
+            });
+
+            let openers = settings.timed("simulation", || {
+                if *boards > 1 {
+                    let rounds = settings.round_limit(default_round_limit(*boards));
+                    simulate_all_multi(start.as_ref(), target, *sample, *boards, rounds, &settings)
+                } else {
+                    let strategy = if *survive {
+                        Strategy::Survive
+                    } else {
+                        settings.strategy()
+                    };
+                    simulate_all(start.as_ref(), target.first(), *sample, strategy, &settings)
+                }
+            });
 
-    println!(
-        "With start word \"{}\", I won {} / {} games ({:.2} %) in on average {:.2} rounds.",
-        start_word, won_count, game_count, won_percentage, avg_score
-    )
-}
+            if let Some(path) = save_baseline {
+                write_baseline(path, &openers, &settings);
+            }
 
-fn user_input() -> String {
-    let mut buffer = String::new();
-    print!("> ");
-    io::stdout().flush().unwrap();
-    io::stdin().read_line(&mut buffer).unwrap();
-    buffer.trim().to_string()
-}
+            if let Some(baseline) = baseline {
+                compare_with_baseline(&baseline, &openers, &settings);
+            }
+        }
+        Commands::Duel {
+            first,
+            second,
+            start,
+            target,
+        } => {
+            if start.len() > 2 {
+                exit_with_usage(Cli::command().error(
+                    ErrorKind::TooManyValues,
+                    "--start can be given at most twice (once per strategy)",
+                ));
+            }
 
-fn get_user_word(i: usize) -> Result<String, InputError> {
-    println!(
-        "\nPlease enter your {} word.",
-        if i == 1 { "first" } else { "next" }
-    );
-    let word = user_input();
+            let strategies = [*first, *second].map(|strategy| strategy.with_seed(settings.seed));
+            duel(strategies, start, target, &settings);
+        }
+        Commands::Verify { start } => {
+            verify(start.as_ref(), &settings);
+        }
+        Commands::Suggest { turns, top } => {
+            let settings = Settings {
+                suggestions: top.unwrap_or(settings.suggestions),
+                ..settings
+            };
 
-    if word.len() != WORD_LEN {
-        return Err(InputError::IncorrectWordLength(WORD_LEN));
-    }
+            suggest(turns, &settings);
+        }
+        Commands::Buckets {
+            word,
+            sort,
+            top,
+            counts_only,
+            min_size,
+            entropy,
+            after,
+        } => {
+            let word = Word::from(settings.normalize(word));
+            let options = BucketOptions {
+                sort: *sort,
+                top: *top,
+                counts_only: *counts_only,
+                min_size: *min_size,
+                entropy: *entropy,
+            };
 
-    Ok(word)
+            show_buckets(&word, after, &options, &settings);
+        }
+        Commands::Explain { word, guess } => {
+            explain(&Word::from(settings.normalize(word)), guess, &settings);
+        }
+        Commands::Grade { turns } => {
+            grade(turns, &settings);
+        }
+        Commands::Replay { file, step } => {
+            replay(file, *step, &settings);
+        }
+        Commands::Daily => {
+            show_daily(&settings);
+        }
+        Commands::Stats { share, action } => match action {
+            Some(StatsAction::Import { file }) => import_stats(file, &settings),
+            None => show_stats(*share, &settings),
+        },
+        Commands::Cache { action } => match action {
+            CacheAction::Clear => clear_cache(&settings),
+        },
+        Commands::Matrix { out } => {
+            let format = match out.extension().and_then(|e| e.to_str()) {
+                Some("csv") => matrix::Format::Csv,
+                Some("bin") => matrix::Format::Binary,
+                _ => exit_with_usage(
+                    Cli::command()
+                        .error(ErrorKind::InvalidValue, "--out must end with .csv or .bin"),
+                ),
+            };
+
+            write_matrix(out, format, &settings);
+        }
+        Commands::Coverage { words, weighted } => {
+            let weighting = if *weighted {
+                Weighting::Frequency
+            } else {
+                Weighting::Distinct
+            };
+            coverage(*words, weighting, &settings);
+        }
+        Commands::Freq {
+            positional,
+            json,
+            after,
+        } => {
+            freq(after, *positional, *json, &settings);
+        }
+        Commands::Wordlist { action } => manage_wordlist(action, &settings),
+        Commands::Engine => {
+            if let Err(error) = engine::run(settings.preset) {
+                println!("{}", settings.tr(Message::Error(&error.to_string())));
+                Exit::Failure.exit();
+            }
+        }
+        #[cfg(feature = "serve")]
+        Commands::Serve { addr } => {
+            if let Err(error) = serve::serve(*addr, settings.preset) {
+                println!("{}", settings.tr(Message::Error(&error.to_string())));
+                Exit::Failure.exit();
+            }
+        }
+    }
+}
+
+/// Returns the title line of a round or section.
+fn title(text: &str) -> String {
+    let line = "-".repeat(56_usize.saturating_sub(text.chars().count()));
+
+    format!("---[ {} ]{}", text, line)
+}
+
+fn play(
+    speedrun: bool,
+    hard: bool,
+    share: bool,
+    practice: bool,
+    start: Option<&str>,
+    turns: &[Turn],
+    settings: &Settings,
+) {
+    println!("{}", settings.tr(Message::Welcome));
+
+    let dictionary = settings.wordlist();
+
+    // The start word is played in the first round without ranking the words, which takes
+    // longer than in any other round.
+    let mut start = start.map(|word| known_word(word, &dictionary, settings).to_string());
+    let answers = settings.answers();
+    let mut wordlist = answers.clone();
+    let mut merged = MergedConstraints::new(settings.word_len);
+
+    let game_start = Instant::now();
+    let mut round_times = Vec::with_capacity(settings.rounds());
+    let mut history = Vec::with_capacity(settings.rounds());
+    let mut won = false;
+
+    // Constraints at the start of each round that has been played, for undoing rounds.
+    let mut snapshots = Vec::with_capacity(settings.rounds());
+    let mut i = 1;
+
+    // The word whose colors are entered again after no words were left.
+    let mut retry = None;
+
+    // Number of words suggested in each round, changed with `:top N`.
+    let mut top = settings.suggestions;
+
+    // Practice games go on until the word is found.
+    let limit = (!practice).then(|| settings.rounds());
+
+    // Games of today's puzzle are tagged with its number, and playing it twice is allowed,
+    // but probably a mistake.
+    let puzzle = daily::puzzle_number(settings.preset, stats::today());
+
+    if let Some(n) = puzzle.filter(|_| turns.is_empty()) {
+        if load_stats(settings).has_puzzle(n) {
+            let title = settings.preset.title();
+            println!("\n{}", settings.tr(Message::PuzzleAlreadyPlayed(title, n)));
+        }
+    }
+
+    // Each game is recorded in its own file, which is updated after every round.
+    let mut recording = settings.record.as_ref().map(|dir| {
+        let transcript = Transcript::new(settings.preset.name(), settings.word_len, hard);
+        (transcript.new_path(dir), transcript)
+    });
+
+    // The turns of a saved game are replayed as if they had just been entered.
+    for turn in turns {
+        if turn.guess().chars().count() != settings.word_len {
+            let error = Error::IncorrectWordLength {
+                input: turn.guess().to_string(),
+                len: settings.word_len,
+            };
+            exit_with_error(&error, settings);
+        }
+
+        let constraints = turn.constraints();
+        let next = validated(constraints.clone())
+            .and_then(|c| merged.merge(&c))
+            .unwrap_or_else(|error| exit_with_error(&error, settings));
+
+        if let Some((_, transcript)) = &mut recording {
+            transcript.rounds.push(transcript::Round {
+                candidates: remaining_words(&answers, &merged, &history).len(),
+                suggestions: vec![],
+                guess: turn.guess().to_string(),
+                code: turn.code().to_string(),
+            });
+        }
+
+        history.push(constraints);
+        snapshots.push(std::mem::replace(&mut merged, next));
+        round_times.push(Duration::ZERO);
+        i += 1;
+    }
+
+    if !turns.is_empty() {
+        wordlist = remaining_words(&answers, &merged, &history);
+        println!("\n{}", settings.tr(Message::GameResumed(turns.len())));
+        println!("\n{}", render_board(&history, settings.style));
+    }
+
+    while limit.map_or(true, |limit| i <= limit) {
+        let round_start = Instant::now();
+
+        println!("\n{}", title(&settings.tr(Message::Round(i))));
+
+        let w_count = wordlist.len();
+        println!("\n{}", settings.tr(Message::CandidatesLeft(w_count)));
+
+        // In hard mode, only candidates may be played.
+        let completions = if hard { &wordlist } else { &dictionary };
+
+        let opener = if i == 1 { start.take() } else { None };
+        let mut candidates = vec![];
+        let mut suggestions = Vec::with_capacity(top);
+
+        if opener.is_none() {
+            candidates = settings.rank(&wordlist, completions);
+
+            let shown = top.min(candidates.len());
+            println!("\n{}", settings.tr(Message::TopCandidates(shown)));
+
+            for &(w, score) in candidates.iter().take(top) {
+                print_suggestion(w, score, &wordlist, settings);
+                suggestions.push(transcript::Suggestion {
+                    word: w.to_string(),
+                    score,
+                });
+            }
+
+            print_probabilities(&wordlist, settings);
+        }
+
+        if let Some(word) = &opener {
+            println!("\n{}", settings.tr(Message::Guessed(word)));
+        }
+
+        if wordlist.len() == 1 {
+            println!("\n{}", settings.tr(Message::Won(i)));
+            round_times.push(round_start.elapsed());
+            won = true;
+
+            // The last word left is the one that will be guessed.
+            let word = wordlist.iter().next().unwrap();
+            let constraints = ConstraintSet::from_guess(word, word);
+            record_round(&mut recording, w_count, suggestions, &constraints, settings);
+            history.push(constraints);
+            break;
+        }
+
+        let word = match retry.take().or(opener) {
+            Some(word) => Some(word),
+            None => loop {
+                match get_user_guess(i, completions, hard, settings) {
+                    Ok(Guess::Word(word)) => break Some(word),
+                    Ok(Guess::List) => println!("\n{}", wordlist.iter().join(" ")),
+                    Ok(Guess::Undo) if i > 1 => break None,
+                    Ok(Guess::Undo) => println!("\n{}", settings.tr(Message::NothingToUndo)),
+                    Ok(Guess::Save(path)) => save_game(&path, hard, &history, settings),
+                    Ok(Guess::Top(Some(n))) => {
+                        top = n;
+                        println!("\n{}", settings.tr(Message::TopChanged(n)));
+
+                        for &(w, score) in candidates.iter().take(top) {
+                            print_suggestion(w, score, &wordlist, settings);
+                        }
+                    }
+                    Ok(Guess::Top(None)) => println!("\n{}", settings.tr(Message::InvalidTop)),
+                    Err(error) => println!("\n{}", settings.tr(Message::InvalidInput(&error))),
+                }
+            },
+        };
+
+        let Some(word) = word else {
+            i -= 1;
+            merged = snapshots.pop().unwrap();
+            history.pop();
+
+            if let Some((_, transcript)) = &mut recording {
+                transcript.rounds.pop();
+            }
+
+            round_times.pop();
+            wordlist = remaining_words(&answers, &merged, &history);
+            println!("\n{}", settings.tr(Message::Undone(i)));
+            continue;
+        };
+
+        // Codes that contradict the previous ones are most likely typos, so they are
+        // rejected right away.
+        let (constraints, next) = loop {
+            match get_contraints(&word, settings)
+                .and_then(|c| merged.merge(&c).map(|next| (c, next)))
+            {
+                Ok(result) => break result,
+                Err(error) => println!("\n{}", settings.tr(Message::InvalidInput(&error))),
+            }
+        };
+
+        record_round(&mut recording, w_count, suggestions, &constraints, settings);
+        history.push(constraints.clone());
+
+        println!("\n{}", render_board(&history, settings.style));
+
+        round_times.push(round_start.elapsed());
+
+        if constraints.correct_word() {
+            println!("\n{}", settings.tr(Message::Won(i)));
+            won = true;
+            break;
+        }
+
+        snapshots.push(std::mem::replace(&mut merged, next));
+        wordlist = settings.timed("filtering", || remaining_words(&answers, &merged, &history));
+
+        // A color has most likely been mistyped, so the player may correct it.
+        if wordlist.is_empty() {
+            println!("\n{}", settings.tr(Message::NoWordsLeft));
+
+            let guessed: Vec<_> = history.iter().map(ConstraintSet::word).collect();
+            let unguessed: Wordlist = answers
+                .iter()
+                .filter(|w| !guessed.contains(&w.to_string()))
+                .cloned()
+                .collect();
+            let fix = recovery::fixes(&unguessed, &history).into_iter().next();
+
+            match &fix {
+                Some(fix) => println!(
+                    "\n{}",
+                    settings.tr(Message::LikelyTypo {
+                        round: fix.round + 1,
+                        position: fix.position + 1,
+                        code: &fix.constraints.code(),
+                        candidates: fix.candidates,
+                    })
+                ),
+                None => println!("\n{}", settings.tr(Message::NoTypoFound)),
+            }
+
+            match (get_recovery(&word, fix.is_some(), settings), fix) {
+                (choice @ (Recovery::Undo | Recovery::Retry), _) => {
+                    merged = snapshots.pop().unwrap();
+                    history.pop();
+
+                    if let Some((_, transcript)) = &mut recording {
+                        transcript.rounds.pop();
+                    }
+
+                    round_times.pop();
+                    wordlist = remaining_words(&answers, &merged, &history);
+
+                    match choice {
+                        Recovery::Retry => retry = Some(word),
+                        _ => println!("\n{}", settings.tr(Message::Undone(i))),
+                    }
+
+                    continue;
+                }
+                (Recovery::Fix, Some(fix)) => {
+                    let code = fix.constraints.code();
+                    history[fix.round] = fix.constraints;
+
+                    if let Some(round) = recording
+                        .as_mut()
+                        .and_then(|(_, transcript)| transcript.rounds.get_mut(fix.round))
+                    {
+                        round.code = code;
+                    }
+
+                    // The constraints of all later rounds are merged again.
+                    merged = MergedConstraints::new(settings.word_len);
+                    snapshots.clear();
+
+                    for constraints in &history {
+                        let next = merged
+                            .merge(constraints)
+                            .unwrap_or_else(|error| exit_with_error(&error, settings));
+                        snapshots.push(std::mem::replace(&mut merged, next));
+                    }
+
+                    wordlist = remaining_words(&answers, &merged, &history);
+                    println!("\n{}", settings.tr(Message::Fixed(fix.round + 1)));
+                    println!("\n{}", render_board(&history, settings.style));
+                }
+                _ => break,
+            }
+        }
+
+        // The game is lost after the last round, even if only one word is left.
+        if limit == Some(i) {
+            if wordlist.len() > 1 {
+                println!("\n{}", settings.tr(Message::CandidatesLeft(wordlist.len())));
+            }
+
+            println!("\n{}", settings.tr(Message::GameOver));
+            break;
+        }
+
+        i += 1;
+    }
+
+    let outcome = if won {
+        Outcome::Won
+    } else if wordlist.is_empty() {
+        Outcome::NoWordsLeft
+    } else {
+        Outcome::Lost
+    };
+
+    if let Some((path, transcript)) = &mut recording {
+        transcript.outcome = outcome;
+        save_transcript(path, transcript, settings);
+    }
+
+    if share && !history.is_empty() {
+        let title = settings.preset.title();
+        let grid = render::share(title, &history, settings.rounds(), hard);
+        println!("\n{}", grid);
+    }
+
+    let mut record = GameRecord::new(won, speedrun, game_start.elapsed(), round_times);
+    record.puzzle = puzzle;
+
+    if speedrun {
+        finish_speedrun(record, settings);
+    } else if !practice {
+        save_record(&record, settings);
+    }
+
+    match outcome {
+        Outcome::Lost => Exit::NotSolved.exit(),
+        Outcome::NoWordsLeft => Exit::NoCandidates.exit(),
+        _ => {}
+    }
+}
+
+/// Adds the guess and its feedback in `constraints` to the transcript of the game if it
+/// is recorded, and saves the transcript.
+fn record_round(
+    recording: &mut Option<(PathBuf, Transcript)>,
+    candidates: usize,
+    suggestions: Vec<transcript::Suggestion>,
+    constraints: &ConstraintSet,
+    settings: &Settings,
+) {
+    if let Some((path, transcript)) = recording {
+        transcript.rounds.push(transcript::Round {
+            candidates,
+            suggestions,
+            guess: constraints.word(),
+            code: constraints.code(),
+        });
+
+        save_transcript(path, transcript, settings);
+    }
+}
+
+/// Writes `transcript` to `path`. Errors are only reported, so that the game goes on.
+fn save_transcript(path: &Path, transcript: &Transcript, settings: &Settings) {
+    if let Err(error) = transcript.save(path) {
+        let error = format!("{}: {}", path.display(), error);
+        println!("\n{}", settings.tr(Message::Error(&error)));
+    }
+}
+
+/// Saves the turns played so far in `history` and the settings that decide which words
+/// are candidates to `path`, so that the game can be resumed with `play --resume`.
+fn save_game(path: &Path, hard: bool, history: &[ConstraintSet], settings: &Settings) {
+    if settings.has_custom_words() {
+        println!("\n{}", settings.tr(Message::CustomWordsNotSaved));
+        return;
+    }
+
+    let saved = SavedGame {
+        preset: settings.preset,
+        len: settings.word_len,
+        list: settings.list,
+        spelling: settings.spelling,
+        hard,
+        turns: history.iter().map(Turn::from).collect(),
+    };
+
+    match saved.save(path) {
+        Ok(()) => println!(
+            "\n{}",
+            settings.tr(Message::GameSaved(&path.display().to_string()))
+        ),
+        Err(error) => println!("\n{}", settings.tr(Message::Error(&error))),
+    }
+}
+
+/// Returns the words in `dictionary` that match the constraints and have not been guessed
+/// yet.
+fn remaining_words(
+    dictionary: &Wordlist,
+    merged: &MergedConstraints,
+    history: &[ConstraintSet],
+) -> Wordlist {
+    let guessed: Vec<_> = history.iter().map(ConstraintSet::word).collect();
+
+    merged
+        .filter(dictionary)
+        .filter(|w| !guessed.contains(&w.to_string()))
+        .cloned()
+        .collect()
+}
+
+fn play_survive(settings: &Settings) {
+    println!("{}", settings.tr(Message::Welcome));
+
+    let mut wordlist = settings.wordlist();
+    let mut history = vec![];
+
+    for i in 1.. {
+        println!("\n{}", title(&settings.tr(Message::Round(i))));
+
+        let w_count = wordlist.len();
+        println!("\n{}", settings.tr(Message::CandidatesLeft(w_count)));
+
+        let ranked = settings.suggestable(Strategy::Survive.rank(&wordlist));
+        let shown = settings.suggestions.min(ranked.len());
+        println!("\n{}", settings.tr(Message::TopCandidates(shown)));
+
+        for (w, score) in ranked.into_iter().take(shown) {
+            println!("{} ({})", w, score);
+        }
+
+        let mut word = get_user_word(i, &wordlist, true, settings);
+
+        while let Err(error) = word {
+            println!("\n{}", settings.tr(Message::InvalidInput(&error)));
+            word = get_user_word(i, &wordlist, true, settings);
+        }
+
+        let word = word.unwrap();
+        let mut constraints = get_contraints(&word, settings);
+
+        while let Err(error) = constraints {
+            println!("\n{}", settings.tr(Message::InvalidInput(&error)));
+            constraints = get_contraints(&word, settings);
+        }
+
+        let constraints = constraints.unwrap();
+        history.push(constraints.clone());
+
+        println!("\n{}", render_board(&history, settings.style));
+
+        if constraints.correct_word() {
+            println!("\n{}", settings.tr(Message::Survived(i - 1)));
+            break;
+        }
+
+        wordlist = Wordlist::from_iter(wordlist.filter(&constraints));
+        wordlist.remove(&word);
+
+        if wordlist.is_empty() {
+            println!("\n{}", settings.tr(Message::NoWordsLeft));
+            break;
+        }
+    }
+}
+
+fn play_multi(boards: usize, rounds: usize, settings: &Settings) {
+    println!("{}", settings.tr(Message::Welcome));
+
+    let dictionary = settings.wordlist();
+    let mut game = MultiGame::new(boards, &dictionary).with_round_limit(rounds);
+
+    for i in 1..=rounds {
+        println!("\n{}", title(&settings.tr(Message::Round(i))));
+
+        println!("\n{}", boards_status(&game, settings));
+
+        let ranked = game.rank_words(&dictionary);
+        let shown = settings.suggestions.min(ranked.len());
+        println!("\n{}", settings.tr(Message::TopCandidates(shown)));
+
+        for (w, score) in ranked.into_iter().take(shown) {
+            println!("{} ({})", w, score);
+        }
+
+        let mut word = get_user_word(i, &dictionary, false, settings);
+
+        while let Err(error) = word {
+            println!("\n{}", settings.tr(Message::InvalidInput(&error)));
+            word = get_user_word(i, &dictionary, false, settings);
+        }
+
+        let word = word.unwrap();
+        let board_indices: Vec<_> = game.unsolved().map(|(k, _)| k).collect();
+        let mut constraints = Vec::with_capacity(board_indices.len());
+
+        for k in board_indices {
+            let mut c = get_board_contraints(&word, k + 1, settings);
+
+            while let Err(error) = c {
+                println!("\n{}", settings.tr(Message::InvalidInput(&error)));
+                c = get_board_contraints(&word, k + 1, settings);
+            }
+
+            constraints.push(c.unwrap());
+        }
+
+        game.apply(&word, constraints)
+            .unwrap_or_else(|error| exit_with_error(&error, settings));
+
+        if game.is_won() {
+            println!("\n{}", boards_status(&game, settings));
+            println!("\n{}", settings.tr(Message::AllBoardsSolved(i)));
+            return;
+        }
+
+        if game.unsolved().any(|(_, b)| b.candidates().is_empty()) {
+            println!("\n{}", settings.tr(Message::NoWordsLeft));
+            Exit::NoCandidates.exit();
+        }
+    }
+
+    println!("\n{}", boards_status(&game, settings));
+    println!("\n{}", settings.tr(Message::GameOver));
+    Exit::NotSolved.exit();
+}
+
+fn play_xordle(rounds: usize, settings: &Settings) {
+    println!("{}", settings.tr(Message::Welcome));
+
+    let dictionary = settings.wordlist();
+    let mut game =
+        Xordle::new(&dictionary).unwrap_or_else(|error| exit_with_error(&error, settings));
+    let mut history = Vec::with_capacity(rounds);
+
+    for i in 1..=rounds {
+        println!("\n{}", title(&settings.tr(Message::Round(i))));
+
+        println!("\n{}", settings.tr(Message::PairsLeft(game.pair_count())));
+
+        if game.pair_count() <= 10 {
+            for (w1, w2) in game.pairs() {
+                println!("{} + {}", w1, w2);
+            }
+        }
+
+        let ranked = game.rank_words(&dictionary);
+        let shown = settings.suggestions.min(ranked.len());
+        println!("\n{}", settings.tr(Message::TopCandidates(shown)));
+
+        for (w, score) in ranked.into_iter().take(shown) {
+            println!("{} ({})", w, score);
+        }
+
+        let mut word = get_user_word(i, &dictionary, false, settings);
+
+        while let Err(error) = word {
+            println!("\n{}", settings.tr(Message::InvalidInput(&error)));
+            word = get_user_word(i, &dictionary, false, settings);
+        }
+
+        let word = word.unwrap();
+        let mut constraints = get_unchecked_contraints(&word, settings);
+
+        while let Err(error) = constraints {
+            println!("\n{}", settings.tr(Message::InvalidInput(&error)));
+            constraints = get_unchecked_contraints(&word, settings);
+        }
+
+        let constraints = constraints.unwrap();
+        let found = game.found().len();
+        game.apply(&word, &constraints);
+        history.push(constraints);
+
+        println!("\n{}", render_board(&history, settings.style));
+
+        if game.is_won() {
+            println!("\n{}", settings.tr(Message::BothWordsFound(i)));
+            return;
+        }
+
+        if game.found().len() > found {
+            println!("\n{}", settings.tr(Message::WordFound(&word)));
+        }
+
+        if game.pair_count() == 0 {
+            println!("\n{}", settings.tr(Message::NoWordsLeft));
+            Exit::NoCandidates.exit();
+        }
+    }
+
+    println!("\n{}", settings.tr(Message::GameOver));
+    Exit::NotSolved.exit();
+}
+
+fn play_fibble(rounds: usize, settings: &Settings) {
+    println!("{}", settings.tr(Message::Welcome));
+
+    let dictionary = settings.wordlist();
+    let mut game = Fibble::new(&dictionary);
+
+    for i in 1..=rounds {
+        println!("\n{}", title(&settings.tr(Message::Round(i))));
+
+        let hypotheses = game.hypotheses();
+
+        if hypotheses.is_empty() {
+            println!("\n{}", settings.tr(Message::NoWordsLeft));
+            Exit::NoCandidates.exit();
+        }
+
+        println!();
+
+        for (lie, candidates) in &hypotheses {
+            let lie = lie.map(|k| k + 1);
+            println!(
+                "{}",
+                settings.tr(Message::Hypothesis(lie, candidates.len()))
+            );
+        }
+
+        let ranked = game.rank_words(&dictionary);
+        let shown = settings.suggestions.min(ranked.len());
+        println!("\n{}", settings.tr(Message::TopCandidates(shown)));
+
+        for (w, score) in ranked.into_iter().take(shown) {
+            println!("{} ({})", w, score);
+        }
+
+        let mut word = get_user_word(i, &dictionary, false, settings);
+
+        while let Err(error) = word {
+            println!("\n{}", settings.tr(Message::InvalidInput(&error)));
+            word = get_user_word(i, &dictionary, false, settings);
+        }
+
+        let word = word.unwrap();
+        let mut constraints = get_unchecked_contraints(&word, settings);
+
+        while let Err(error) = constraints {
+            println!("\n{}", settings.tr(Message::InvalidInput(&error)));
+            constraints = get_unchecked_contraints(&word, settings);
+        }
+
+        let constraints = constraints.unwrap();
+        let correct = constraints.correct_word();
+        game.apply(&word, constraints);
+
+        let history: Vec<_> = game.history().cloned().collect();
+        println!("\n{}", render_board(&history, settings.style));
+
+        if correct {
+            println!("\n{}", settings.tr(Message::Won(i)));
+            return;
+        }
+    }
+
+    println!("\n{}", settings.tr(Message::GameOver));
+    Exit::NotSolved.exit();
+}
+
+/// Returns the status of each board of `game`. For games with only a few boards, the
+/// whole board history is shown, otherwise only the latest guess.
+fn boards_status(game: &MultiGame, settings: &Settings) -> String {
+    let solved = game.boards().iter().filter(|b| b.is_solved()).count();
+    let full = game.boards().len() <= FULL_BOARD_NUM;
+
+    let mut lines = vec![format!(
+        "{} {}",
+        settings.tr(Message::BoardsSolved(solved, game.boards().len())),
+        settings.tr(Message::RoundsLeft(game.rounds_left()))
+    )];
+
+    for (k, board) in game.boards().iter().enumerate() {
+        let heading = match board.solved_in() {
+            Some(round) => settings.tr(Message::BoardSolved(k + 1, round)),
+            None => settings.tr(Message::Board(k + 1, board.candidates().len())),
+        };
+
+        let history = match board.history() {
+            [] => None,
+            history if full => Some(render_board(history, settings.style)),
+            [.., last] => Some(render_board(std::slice::from_ref(last), settings.style)),
+        };
+
+        match history {
+            Some(history) if full => lines.push(format!("\n{}\n{}", heading, history)),
+            Some(history) => lines.push(format!("{}  {}", history, heading)),
+            None if full => lines.push(format!("\n{}", heading)),
+            None => lines.push(heading),
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+
+    format!(
+        "{}:{:02}.{:03}",
+        millis / 60_000,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
+fn finish_speedrun(record: GameRecord, settings: &Settings) {
+    println!("\n{}\n", title(&settings.tr(Message::SpeedRun)));
+
+    let stats = load_stats(settings);
+
+    for (i, time) in record.rounds.iter().enumerate() {
+        let mut line = settings.tr(Message::RoundTime(i + 1, &format_duration(*time)));
+
+        if let Some(best) = stats.best_round(i + 1) {
+            line.push(' ');
+            line.push_str(&settings.tr(Message::BestTime(&format_duration(best))));
+        }
+
+        println!("{}", line);
+    }
+
+    println!(
+        "\n{}",
+        settings.tr(Message::TotalTime(&format_duration(record.total)))
+    );
+
+    match stats.personal_best() {
+        Some(best) if record.won && record.total < best.total => println!(
+            "{}",
+            settings.tr(Message::NewPersonalBest(Some(&format_duration(best.total))))
+        ),
+        Some(best) => println!(
+            "{}",
+            settings.tr(Message::PersonalBest(&format_duration(best.total)))
+        ),
+        None if record.won => println!("{}", settings.tr(Message::NewPersonalBest(None))),
+        None => {}
+    }
+
+    if let Some(avg) = stats.average_time() {
+        let won_count = stats.speedruns().filter(|r| r.won).count();
+
+        println!(
+            "{}",
+            settings.tr(Message::AverageTime(won_count, &format_duration(avg)))
+        );
+    }
+
+    save_record(&record, settings);
+}
+
+/// Loads the records from the stats file. Errors are reported and result in empty stats.
+fn load_stats(settings: &Settings) -> Stats {
+    match Stats::default_path(settings.profile.as_deref()).map(Stats::load) {
+        Some(Ok(stats)) => {
+            if stats.skipped() > 0 {
+                println!("{}", settings.tr(Message::DamagedRecords(stats.skipped())));
+            }
+
+            stats
+        }
+        Some(Err(error)) => {
+            println!("{}", settings.tr(Message::Error(&error.to_string())));
+            Stats::default()
+        }
+        None => Stats::default(),
+    }
+}
+
+/// Appends `record` to the stats file.
+fn save_record(record: &GameRecord, settings: &Settings) {
+    match Stats::default_path(settings.profile.as_deref()) {
+        Some(path) => {
+            if let Err(error) = Stats::append(&path, record) {
+                println!("\n{}", settings.tr(Message::Error(&error.to_string())));
+            }
+        }
+        None => println!(
+            "\n{}",
+            settings.tr(Message::Error(&settings.tr(Message::StatsUnavailable)))
+        ),
+    }
+}
+
+/// Adds the games in the share texts in the file at `path` to the stats file, except for
+/// the puzzles that have already been recorded.
+fn import_stats(path: &Path, settings: &Settings) {
+    let fail = |error: String| -> ! {
+        println!("{}", settings.tr(Message::Error(&error)));
+        Exit::Failure.exit();
+    };
+
+    let records = fs::read_to_string(path)
+        .and_then(|text| stats::parse_shares(&text))
+        .unwrap_or_else(|e| fail(format!("{}: {}", path.display(), e)));
+    let stats_path = Stats::default_path(settings.profile.as_deref())
+        .unwrap_or_else(|| fail(settings.tr(Message::StatsUnavailable)));
+    let mut puzzles: HashSet<_> = load_stats(settings)
+        .iter()
+        .filter_map(|r| r.puzzle)
+        .collect();
+
+    let mut imported = 0;
+
+    for record in &records {
+        if record.puzzle.is_some_and(|n| !puzzles.insert(n)) {
+            continue;
+        }
+
+        if let Err(error) = Stats::append(&stats_path, record) {
+            fail(error.to_string());
+        }
+
+        imported += 1;
+    }
+
+    println!(
+        "{}",
+        settings.tr(Message::GamesImported {
+            imported,
+            skipped: records.len() - imported,
+        })
+    );
+}
+
+/// Shows the number of today's puzzle and warns if a game of it has already been recorded.
+fn show_daily(settings: &Settings) {
+    let title = settings.preset.title();
+
+    match daily::puzzle_number(settings.preset, stats::today()) {
+        Some(n) => {
+            println!("{}", settings.tr(Message::DailyPuzzle(title, n)));
+
+            if load_stats(settings).has_puzzle(n) {
+                println!("{}", settings.tr(Message::PuzzleAlreadyPlayed(title, n)));
+            }
+        }
+        None => println!("{}", settings.tr(Message::NoDailyPuzzle(title))),
+    }
+}
+
+/// Shows the statistics of all games recorded in the stats file like Wordle does, with
+/// the number of rounds of the last game highlighted if it was won. With `share`, only a
+/// compact summary for sharing is printed.
+fn show_stats(share: bool, settings: &Settings) {
+    let stats = load_stats(settings);
+
+    if stats.is_empty() {
+        println!("{}", settings.tr(Message::NoGamesPlayed));
+        return;
+    }
+
+    if share {
+        let title = settings.preset.title();
+        println!("{}", stats.share(title, settings.rounds(), stats::today()));
+        return;
+    }
+
+    let streaks = stats.streaks(stats::today());
+
+    println!("{}\n", title(&settings.tr(Message::Statistics)));
+    println!(
+        "{}",
+        settings.tr(Message::StatsSummary {
+            played: stats.len(),
+            win_percentage: stats.win_percentage(),
+            current_streak: streaks.current,
+            max_streak: streaks.max,
+        })
+    );
+
+    // Imported games may have been appended after more recent ones.
+    let last = stats
+        .iter()
+        .max_by_key(|r| r.timestamp)
+        .filter(|r| r.won)
+        .map(|r| r.rounds.len());
+    let distribution = stats.distribution(settings.rounds());
+
+    println!("\n{}\n", title(&settings.tr(Message::GuessDistribution)));
+    println!(
+        "{}",
+        render::render_distribution(&distribution, last, settings.style)
+    );
+}
+
+fn simulate(start: &Word, target: &Word, strategy: Strategy, settings: &Settings) -> Option<usize> {
+    let wordlist = settings.wordlist();
+    let rounds = settings.rounds();
+    let game = simulation::solve_with(&wordlist, start, target, rounds, strategy, &settings.prior)
+        .unwrap_or_else(|error| exit_with_error(&error, settings));
+
+    debug!("{} -> {}", start, target);
+    log_rounds(&game, settings);
+
+    match game.score {
+        Some(score) => debug!("\n{}", settings.tr(Message::SolverWon(score))),
+        None => {
+            debug!(
+                "\n{}",
+                settings.tr(Message::CandidatesLeft(game.candidates_left))
+            );
+            debug!("\n{}", settings.tr(Message::GameOver));
+        }
+    }
+
+    game.score
+}
+
+/// Lets both `strategies` solve the `targets` (or all words) and prints the targets for
+/// which they need a different number of rounds and a summary.
+fn duel(strategies: [Strategy; 2], starts: &[String], targets: &[String], settings: &Settings) {
+    let wordlist = settings.wordlist();
+
+    let starts: Vec<Word> = strategies
+        .iter()
+        .enumerate()
+        .map(|(k, strategy)| match starts.get(k) {
+            Some(start) => known_word(start, &wordlist, settings),
+            None => strategy
+                .best_with(&wordlist, &settings.prior)
+                .cloned()
+                .unwrap_or_else(|| {
+                    exit_with_error(&Error::EmptyWordlist, settings);
+                }),
+        })
+        .collect();
+
+    let targets: Vec<Word> = if targets.is_empty() {
+        wordlist.iter().cloned().collect()
+    } else {
+        targets
+            .iter()
+            .map(|t| known_word(t, &wordlist, settings))
+            .collect()
+    };
+
+    let (names, starts) = (strategies.map(|s| s.name()), [&starts[0], &starts[1]]);
+    let mut wins = [0, 0];
+    let mut totals = [0, 0];
+    let mut ties = 0;
+
+    for target in &targets {
+        // Each round rules out at least one word, so the target is always found.
+        let scores = [0, 1].map(|k| {
+            let rounds = wordlist.len();
+            simulation::solve_with(
+                &wordlist,
+                starts[k],
+                target,
+                rounds,
+                strategies[k],
+                &settings.prior,
+            )
+            .unwrap_or_else(|error| exit_with_error(&error, settings))
+            .score
+            .unwrap_or(usize::MAX)
+        });
+
+        match scores[0].cmp(&scores[1]) {
+            Ordering::Less => wins[0] += 1,
+            Ordering::Greater => wins[1] += 1,
+            Ordering::Equal => ties += 1,
+        }
+
+        if scores[0] != scores[1] {
+            println!(
+                "{}",
+                settings.tr(Message::DuelGame {
+                    target: &target.to_string(),
+                    names,
+                    rounds: scores,
+                })
+            );
+        }
+
+        for k in 0..2 {
+            totals[k] += scores[k];
+        }
+    }
+
+    let games = targets.len().max(1) as f32;
+
+    println!(
+        "\n{}",
+        settings.tr(Message::DuelResults {
+            names,
+            starts: [&starts[0].to_string(), &starts[1].to_string()],
+            wins,
+            ties,
+            avg_rounds: totals.map(|total| total as f32 / games),
+        })
+    );
+}
+
+/// Lets the strategy solve every word in the wordlist and prints the words that it does
+/// not find within the round limit. Exits with an error code if there are any.
+fn verify(start: Option<&String>, settings: &Settings) {
+    let wordlist = settings.wordlist();
+    let strategy = settings.strategy();
+    let rounds = settings.rounds();
+
+    let start = match start {
+        Some(start) => known_word(start, &wordlist, settings),
+        None => strategy
+            .best_with(&wordlist, &settings.prior)
+            .cloned()
+            .unwrap_or_else(|| exit_with_error(&Error::EmptyWordlist, settings)),
+    };
+
+    let failures = simulation::verify(&wordlist, &start, rounds, strategy, &settings.prior)
+        .unwrap_or_else(|error| exit_with_error(&error, settings));
+
+    for (target, game) in &failures {
+        let guesses = game.rounds.iter().map(|round| &round.guess).join(", ");
+
+        println!(
+            "{}",
+            settings.tr(Message::VerifyFailure {
+                target: &target.to_string(),
+                guesses: &guesses,
+            })
+        );
+    }
+
+    if !failures.is_empty() {
+        println!();
+    }
+
+    println!(
+        "{}",
+        settings.tr(Message::VerifyResults {
+            start: &start.to_string(),
+            words: wordlist.len(),
+            failures: failures.len(),
+            rounds,
+        })
+    );
+
+    if !failures.is_empty() {
+        Exit::NotSolved.exit();
+    }
+}
+
+/// Simulates a Survivle game and returns the number of rounds survived before `target`
+/// had to be guessed.
+fn survive(start: &Word, target: &Word, settings: &Settings) -> Option<usize> {
+    let wordlist = settings.wordlist();
+    let game = simulation::survive(&wordlist, start, target)
+        .unwrap_or_else(|error| exit_with_error(&error, settings));
+
+    debug!("{} -> {}", start, target);
+    log_rounds(&game, settings);
+
+    match game.score {
+        Some(score) => debug!("\n{}", settings.tr(Message::SolverSurvived(score))),
+        None => debug!("\n{}", settings.tr(Message::NoWordsLeft)),
+    }
+
+    game.score
+}
+
+/// Logs the rounds of a simulated game at debug level.
+fn log_rounds(game: &simulation::Game, settings: &Settings) {
+    for (i, round) in game.rounds.iter().enumerate() {
+        debug!("\n{}", title(&settings.tr(Message::Round(i + 1))));
+        debug!(
+            "\n{}",
+            settings.tr(Message::CandidatesLeft(round.candidates))
+        );
+        debug!(
+            "{}",
+            settings.tr(Message::TopCandidate(&round.guess.to_string()))
+        );
+        debug!(
+            "{}",
+            settings.tr(Message::Hint(&feedback::to_code(&round.feedback)))
+        );
+        debug!("\n{}", render_board(&game.history()[..=i], settings.style));
+    }
+}
+
+fn coverage(k: usize, weighting: Weighting, settings: &Settings) {
+    let wordlist = settings.wordlist();
+    let openings = coverage::best_openings(&wordlist, k, weighting, settings.suggestions);
+
+    println!("{}\n", settings.tr(Message::BestOpenings(k)));
+
+    for opening in openings {
+        println!("{} ({})", opening.words.iter().join(", "), opening.score);
+    }
+}
+
+fn freq(turns: &[Turn], positional: bool, json: bool, settings: &Settings) {
+    let solver = apply_turns(turns, settings);
+    let freq = Frequencies::new(solver.candidates());
+
+    if json {
+        let mut value = serde_json::json!({ "words": freq.words, "overall": freq.overall });
+
+        if positional {
+            value["positional"] = serde_json::json!(freq.positional);
+        }
+
+        println!("{}", value);
+        return;
+    }
+
+    println!("{}\n", settings.tr(Message::Frequencies(freq.words)));
+
+    let percent = |n: usize| 100.0 * n as f32 / freq.words.max(1) as f32;
+
+    if positional {
+        let positions = (1..=freq.positional.len()).map(|i| format!("{:>6}", i));
+        println!(
+            "  {:>6}{}",
+            settings.tr(Message::AllPositions),
+            positions.collect::<String>()
+        );
+    }
+
+    for (c, n) in freq.ranked() {
+        if positional {
+            let counts = (0..freq.positional.len()).map(|i| format!("{:>6}", freq.at(i, c)));
+            println!("{} {:>6}{}", c, n, counts.collect::<String>());
+        } else {
+            println!("{} {:>6} {:>5.1}%", c, n, percent(n));
+        }
+    }
+}
+
+fn explain(word: &Word, turns: &[Turn], settings: &Settings) {
+    let len = settings.word_len;
+
+    if word.chars().count() != len {
+        let error = Error::IncorrectWordLength {
+            input: word.to_string(),
+            len,
+        };
+        exit_with_error(&error, settings);
+    }
+
+    let w_string = word.to_string();
+    let in_wordlist = settings.wordlist().contains(&w_string);
+
+    if !in_wordlist {
+        println!("{}", settings.tr(Message::NotInWordlist(&w_string)));
+    }
+
+    for turn in turns {
+        let constraints = turn.constraints();
+
+        if let Err(error) = constraints.validate() {
+            exit_with_error(&error, settings);
+        }
+
+        if let Some(mismatch) = constraints.mismatch(word) {
+            println!(
+                "{}",
+                settings.tr(Message::Eliminated {
+                    word: &w_string,
+                    turn: &turn.to_string(),
+                    mismatch: &mismatch,
+                })
+            );
+            return;
+        }
+    }
+
+    if in_wordlist {
+        println!("{}", settings.tr(Message::StillCandidate(&w_string)));
+    } else {
+        println!("{}", settings.tr(Message::MatchesAllHints(&w_string)));
+    }
+}
+
+/// Prints the skill and luck of each guess in `turns` and their totals.
+fn grade(turns: &[Turn], settings: &Settings) {
+    let constraints: Vec<_> = turns.iter().map(|turn| turn.constraints()).collect();
+
+    for c in &constraints {
+        if let Err(error) = c.validate() {
+            exit_with_error(&error, settings);
+        }
+    }
+
+    let grades = grade::grade(&settings.wordlist(), &constraints);
+
+    for (i, g) in grades.iter().enumerate() {
+        println!(
+            "{}",
+            settings.tr(Message::GuessGrade {
+                round: i + 1,
+                guess: &g.guess.to_string(),
+                skill: g.skill(),
+                best: &g.best.to_string(),
+                luck: g.luck(),
+                expected_left: g.expected_left,
+                left: g.left,
+            })
+        );
+    }
+
+    if grades.last().is_some_and(|g| g.left == 0) {
+        println!("{}", settings.tr(Message::CandidatesLeft(0)));
+    }
+
+    let skill = grades.iter().map(|g| g.skill()).sum::<f64>() / grades.len().max(1) as f64;
+    let luck = grades.iter().map(|g| g.luck()).sum();
+
+    println!("\n{}", settings.tr(Message::GameGrade { skill, luck }));
+}
+
+/// Shows the game recorded in the transcript at `path` round by round. With `step`, the
+/// next round is only shown after Enter has been pressed.
+fn replay(path: &Path, step: bool, settings: &Settings) {
+    let transcript = Transcript::load(path).unwrap_or_else(|e| {
+        let error = format!("{}: {}", path.display(), e);
+        println!("{}", settings.tr(Message::Error(&error)));
+        Exit::Failure.exit();
+    });
+
+    println!(
+        "{}",
+        settings.tr(Message::ReplayStart {
+            preset: &transcript.preset,
+            rounds: transcript.rounds.len(),
+            hard: transcript.hard,
+        })
+    );
+
+    let mut history = Vec::with_capacity(transcript.rounds.len());
+
+    for (i, round) in transcript.rounds.iter().enumerate() {
+        if step && i > 0 {
+            println!("\n{}", settings.tr(Message::PressEnter));
+            user_input(None, settings);
+        }
+
+        println!("\n{}", title(&settings.tr(Message::Round(i + 1))));
+        println!(
+            "\n{}",
+            settings.tr(Message::CandidatesLeft(round.candidates))
+        );
+
+        if !round.suggestions.is_empty() {
+            println!(
+                "\n{}",
+                settings.tr(Message::TopCandidates(round.suggestions.len()))
+            );
+
+            for suggestion in &round.suggestions {
+                println!("{} ({})", suggestion.word, suggestion.score);
+            }
+        }
+
+        let constraints = ConstraintSet::try_from((round.guess.as_str(), round.code.as_str()))
+            .unwrap_or_else(|error| exit_with_error(&error, settings));
+        history.push(constraints);
+
+        println!("\n{}", settings.tr(Message::Guessed(&round.guess)));
+        println!("\n{}", render_board(&history, settings.style));
+    }
+
+    let outcome = match transcript.outcome {
+        Outcome::Won => Message::Won(transcript.rounds.len()),
+        Outcome::Lost => Message::GameOver,
+        Outcome::NoWordsLeft => Message::NoWordsLeft,
+        Outcome::Unfinished => Message::GameUnfinished,
+    };
+
+    println!("\n{}", settings.tr(outcome));
+}
+
+/// Removes all tables from the cache directory, even with `--no-cache`.
+fn clear_cache(settings: &Settings) {
+    let Some(cache) = Cache::default_dir().map(Cache::new) else {
+        return;
+    };
+
+    match cache.clear() {
+        Ok(n) => println!(
+            "{}",
+            settings.tr(Message::CacheCleared(n, &cache.dir().display().to_string()))
+        ),
+        Err(error) => {
+            let error = format!("{}: {}", cache.dir().display(), error);
+            println!("{}", settings.tr(Message::Error(&error)));
+            Exit::Failure.exit();
+        }
+    }
+}
+
+/// Writes the pattern matrix of the wordlist to the file at `path`. The matrix in the CSV
+/// format is cached, so that it is only computed once.
+fn write_matrix(path: &Path, format: matrix::Format, settings: &Settings) {
+    let wordlist = settings.wordlist();
+    let result = settings.timed("matrix", || match (format, &settings.cache) {
+        (matrix::Format::Csv, Some(cache)) => {
+            let key = matrix_key(&wordlist);
+
+            if let Some(table) = cache.load(&key) {
+                info!("Loaded the matrix from the cache: {}", key.file_name());
+                return fs::write(path, table);
+            }
+
+            let mut table = vec![];
+            matrix::write(&wordlist, format, &mut table)?;
+            let table = String::from_utf8(table)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+            // The cache only saves time, so the matrix is written even if it cannot be stored.
+            if let Err(error) = cache.store(&key, &table) {
+                debug!("Could not cache the matrix: {}", error);
+            }
+
+            fs::write(path, table)
+        }
+        _ => fs::File::create(path)
+            .and_then(|file| matrix::write(&wordlist, format, io::BufWriter::new(file))),
+    });
+
+    if let Err(error) = result {
+        println!("{}", settings.tr(Message::Error(&error.to_string())));
+        Exit::Failure.exit();
+    }
+}
+
+/// Lists, prints, exports or derives wordlists.
+fn manage_wordlist(action: &WordlistAction, settings: &Settings) {
+    let result = match action {
+        WordlistAction::List => {
+            for preset in Preset::ALL {
+                println!(
+                    "{}",
+                    settings.tr(Message::WordlistInfo {
+                        name: preset.name(),
+                        word_len: preset.word_len(),
+                        words: preset.answers(settings.list).len(),
+                    })
+                );
+            }
+
+            Ok(())
+        }
+        WordlistAction::Show => write_words(io::stdout().lock(), settings.wordlist()),
+        WordlistAction::Export { out } => fs::File::create(out)
+            .and_then(|file| write_words(io::BufWriter::new(file), settings.wordlist())),
+        WordlistAction::FilterLength { len, file } => {
+            let words = read_words(file.as_deref(), settings)
+                .into_iter()
+                .filter(|w| w.chars().count() == *len);
+
+            write_words(io::stdout().lock(), words)
+        }
+        WordlistAction::ToLower { file } => {
+            let words = read_words(file.as_deref(), settings)
+                .into_iter()
+                .map(|w| settings.normalize(&w.to_string()))
+                .filter(|w| !w.is_empty());
+
+            write_words(io::stdout().lock(), words)
+        }
+    };
+
+    // The output may be piped into a command like `head` that stops reading early.
+    match result {
+        Err(error) if error.kind() != io::ErrorKind::BrokenPipe => {
+            println!("{}", settings.tr(Message::Error(&error.to_string())));
+            Exit::Failure.exit();
+        }
+        _ => {}
+    }
+}
+
+/// Writes `words` to `writer`, one per line.
+fn write_words<W, I>(mut writer: W, words: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: fmt::Display,
+{
+    for w in words {
+        writeln!(writer, "{}", w)?;
+    }
+
+    writer.flush()
+}
+
+/// Reads the lines of the file at `path` or, if there is none, of stdin as words, without
+/// any validation.
+fn read_words(path: Option<&Path>, settings: &Settings) -> Wordlist {
+    let result = match path {
+        Some(path) => Wordlist::from_file(path),
+        None => Wordlist::from_reader(io::stdin().lock()).map_err(|e| Error::UnreadableWordlist {
+            path: "-".to_string(),
+            reason: e.to_string(),
+        }),
+    };
+
+    result.unwrap_or_else(|error| exit_with_error(&error, settings))
+}
+
+/// Returns a solver with the words that are left after `turns`.
+fn apply_turns(turns: &[Turn], settings: &Settings) -> Solver {
+    let mut solver = Solver::new(settings.answers(), settings.word_len);
+
+    settings.timed("filtering", || {
+        for turn in turns {
+            if let Err(error) = solver.apply(turn.guess(), turn.code()) {
+                exit_with_error(&error, settings);
+            }
+        }
+    });
+
+    solver
+}
+
+fn suggest(turns: &[Turn], settings: &Settings) {
+    let solver = apply_turns(turns, settings);
+
+    let w_count = solver.candidates().len();
+    println!("{}", settings.tr(Message::CandidatesLeft(w_count)));
+
+    if w_count == 0 {
+        Exit::NoCandidates.exit();
+    }
+
+    let dictionary = settings.wordlist();
+
+    // A single suggestion is found without ranking all words.
+    let ranked = match settings.suggestions {
+        1 => Vec::from_iter(settings.best(solver.candidates(), &dictionary)),
+        n => {
+            let mut ranked = settings.rank(solver.candidates(), &dictionary);
+            ranked.truncate(n);
+            ranked
+        }
+    };
+
+    println!("\n{}", settings.tr(Message::TopCandidates(ranked.len())));
+
+    for (w, score) in ranked {
+        print_suggestion(w, score, solver.candidates(), settings);
+    }
+
+    print_probabilities(solver.candidates(), settings);
+}
+
+/// Prints the probability of each candidate being the mystery word, most likely first, if
+/// only a few candidates are left.
+fn print_probabilities(candidates: &Wordlist, settings: &Settings) {
+    if !(2..=MAX_PROBABILITIES).contains(&candidates.len()) {
+        return;
+    }
+
+    println!("\n{}", settings.tr(Message::Probabilities));
+
+    for (w, p) in settings
+        .prior
+        .probabilities(candidates)
+        .into_iter()
+        .sorted_by(|(_, p1), (_, p2)| p2.total_cmp(p1))
+    {
+        println!("{} ({:.1} %)", w, p * 100.0);
+    }
+}
+
+/// Prints a suggested word with its score and the expected number of rounds needed if it
+/// is played now.
+fn print_suggestion(w: &Word, score: usize, candidates: &Wordlist, settings: &Settings) {
+    let turns = Lookahead::default().expected_turns(w, candidates);
+    let obscure = if settings.metadata.is_obscure(w) {
+        format!(", {}", settings.tr(Message::Obscure))
+    } else {
+        String::new()
+    };
+
+    println!(
+        "{} ({}, {}{})",
+        w,
+        score,
+        settings.tr(Message::ExpectedTurns(turns)),
+        obscure
+    );
+}
+
+fn word_iter<'a>(
+    word_opt: Option<&'a Word>,
+    wordlist: &'a Wordlist,
+) -> impl Iterator<Item = &'a Word> {
+    let iter = if word_opt.is_none() {
+        Some(wordlist.iter())
+    } else {
+        None
+    };
+
+    iter.into_iter().flatten().chain(word_opt)
+}
+
+fn show_buckets(word: &Word, after: &[Turn], options: &BucketOptions, settings: &Settings) {
+    let solver = apply_turns(after, settings);
+    let total = solver.candidates().len();
+
+    if !after.is_empty() {
+        println!("{}\n", settings.tr(Message::CandidatesLeft(total)));
+    }
+
+    // Computing the patterns of one guess is quicker than reading its row from a cached
+    // matrix, so the matrix is not used here.
+    let map = settings.timed("buckets", || {
+        WordleRule.par_buckets(word, solver.candidates(), settings.jobs)
+    });
+
+    println!(
+        "{}",
+        settings.tr(Message::Buckets(&word.to_string(), map.len()))
+    );
+
+    if options.entropy {
+        let sizes: Vec<_> = map.values().map(|words| words.len()).collect();
+
+        println!(
+            "{}",
+            settings.tr(Message::Entropy {
+                entropy: entropy::entropy(&sizes),
+                remaining: entropy::remaining(&sizes),
+                total: (total.max(1) as f64).log2(),
+            })
+        );
+    }
+
+    let buckets = map
+        .iter()
+        .map(|(code, words)| (code.to_string(), words))
+        .filter(|(_, words)| words.len() >= options.min_size)
+        .sorted_by(|(c1, w1), (c2, w2)| match options.sort {
+            BucketOrder::Code => c1.cmp(c2),
+            BucketOrder::Size => w2.len().cmp(&w1.len()).then(c1.cmp(c2)),
+        })
+        .take(options.top.unwrap_or(usize::MAX));
+
+    if options.counts_only {
+        println!();
+    }
+
+    for (code, words) in buckets {
+        let n = words.len();
+        let message = if options.entropy {
+            Message::BucketEntropy {
+                code: &code,
+                words: n,
+                probability: entropy::probability(n, total),
+                bits: entropy::contribution(n, total),
+            }
+        } else {
+            Message::Bucket(&code, n)
+        };
+
+        if options.counts_only {
+            println!("{}", settings.tr(message));
+            continue;
+        }
+
+        println!("\n{}", settings.tr(message));
+
+        for w in words {
+            println!("{}", w);
+        }
+    }
+}
+
+/// Simulates the games for each start word and returns the results for each start word
+/// that was played against several targets (except in Survivle).
+fn simulate_all(
+    start: Option<&String>,
+    target: Option<&String>,
+    sample: Option<usize>,
+    strategy: Strategy,
+    settings: &Settings,
+) -> Vec<Summary> {
+    let wordlist = settings.wordlist();
+    let mut openers = vec![];
+    let targets = sample_targets(&wordlist, sample, settings);
+
+    let start_word = start.map(|w| known_word(w, &wordlist, settings));
+    let start_words = word_iter(start_word.as_ref(), &wordlist);
+    let target_word = target.map(|w| known_word(w, &wordlist, settings));
+
+    for s in start_words {
+        let mut scores = Vec::with_capacity(wordlist.len());
+        let target_words = word_iter(target_word.as_ref(), &targets);
+
+        for t in target_words {
+            let (s_string, t_string) = (s.to_string(), t.to_string());
+
+            let score = match strategy {
+                Strategy::Survive => survive(s, t, settings),
+                _ => simulate(s, t, strategy, settings),
+            };
+
+            match (score, strategy) {
+                (Some(score), Strategy::Survive) => {
+                    scores.push(score);
+                    info!(
+                        "{}",
+                        settings.tr(Message::GameSurvived(&s_string, &t_string, score))
+                    );
+                }
+                (Some(score), _) => {
+                    scores.push(score);
+                    info!(
+                        "{}",
+                        settings.tr(Message::GameWon(&s_string, &t_string, score))
+                    );
+                }
+                (None, _) => {
+                    info!("{}", settings.tr(Message::GameLost(&s_string, &t_string)));
+                }
+            }
+        }
+
+        if start.is_some() && target.is_some() {
+            continue;
+        }
+
+        if strategy == Strategy::Survive {
+            println!(
+                "{}",
+                settings.tr(Message::SurvivalResults {
+                    start: &s.to_string(),
+                    games: scores.len(),
+                    avg_rounds: scores.iter().sum::<usize>() as f32 / scores.len() as f32,
+                })
+            );
+        } else {
+            let summary = Summary {
+                start: s.to_string(),
+                games: targets.len(),
+                won: scores.len(),
+                rounds: scores.iter().sum(),
+            };
+
+            print_results(&summary, settings);
+            openers.push(summary);
+        }
+    }
+
+    openers
+}
+
+fn simulate_multi(
+    start: &Word,
+    targets: &[&Word],
+    rounds: usize,
+    settings: &Settings,
+) -> Option<usize> {
+    let wordlist = settings.wordlist();
+    let mut game = MultiGame::new(targets.len(), &wordlist).with_round_limit(rounds);
+
+    debug!("{} -> {}", start, targets.iter().join(", "));
+
+    for i in 1..=rounds {
+        debug!("\n{}", title(&settings.tr(Message::Round(i))));
+
+        let ranked;
+        let w = match i {
+            1 => start,
+            _ => {
+                ranked = game.rank_words(&wordlist);
+                ranked[0].0
+            }
+        };
+
+        debug!("{}", settings.tr(Message::TopCandidate(&w.to_string())));
+
+        let constraints: Vec<_> = game
+            .unsolved()
+            .map(|(k, _)| ConstraintSet::from_guess(w, targets[k]))
+            .collect();
+
+        game.apply(&w.to_string(), constraints)
+            .unwrap_or_else(|error| exit_with_error(&error, settings));
+
+        debug!("\n{}", boards_status(&game, settings));
+
+        if game.is_won() {
+            debug!("\n{}", settings.tr(Message::SolverWon(i)));
+            return Some(i);
+        }
+    }
+
+    debug!("\n{}", settings.tr(Message::GameOver));
+
+    None
+}
+
+/// Simulates the games with several boards for each start word and returns the results
+/// for each start word that was played against several sets of targets.
+fn simulate_all_multi(
+    start: Option<&String>,
+    targets: &[String],
+    sample: Option<usize>,
+    boards: usize,
+    rounds: usize,
+    settings: &Settings,
+) -> Vec<Summary> {
+    let wordlist = settings.wordlist();
+    let sampled = sample_targets(&wordlist, sample, settings);
+    let mut openers = vec![];
+    let words: Vec<_> = sampled.iter().collect();
+
+    let start_word = start.map(|w| known_word(w, &wordlist, settings));
+    let start_words = word_iter(start_word.as_ref(), &wordlist);
+
+    let target_words: Vec<_> = targets
+        .iter()
+        .map(|w| known_word(w, &wordlist, settings))
+        .collect();
+
+    // Without explicit targets, each word in the list is combined with the words
+    // following it to get as many games as there are words.
+    let games: Vec<Vec<&Word>> = if target_words.is_empty() {
+        (0..words.len())
+            .map(|j| (0..boards).map(|k| words[(j + k) % words.len()]).collect())
+            .collect()
+    } else {
+        vec![target_words.iter().collect()]
+    };
+
+    for s in start_words {
+        let mut scores = Vec::with_capacity(games.len());
+
+        for targets in &games {
+            let (s_string, t_string) = (s.to_string(), targets.iter().join(", "));
+
+            if let Some(score) = simulate_multi(s, targets, rounds, settings) {
+                scores.push(score);
+                info!(
+                    "{}",
+                    settings.tr(Message::GameWon(&s_string, &t_string, score))
+                );
+            } else {
+                info!("{}", settings.tr(Message::GameLost(&s_string, &t_string)));
+            }
+        }
+
+        if start.is_none() || targets.is_empty() {
+            let summary = Summary {
+                start: s.to_string(),
+                games: games.len(),
+                won: scores.len(),
+                rounds: scores.iter().sum(),
+            };
+
+            print_results(&summary, settings);
+            openers.push(summary);
+        }
+    }
+
+    openers
+}
+
+/// Returns the words of `wordlist` that are played against: `sample` words chosen at
+/// random with the seed from the settings, or all words.
+fn sample_targets(wordlist: &Wordlist, sample: Option<usize>, settings: &Settings) -> Wordlist {
+    match sample {
+        Some(n) => wordlist.sample(n, settings.seed),
+        None => wordlist.clone(),
+    }
+}
+
+fn print_results(summary: &Summary, settings: &Settings) {
+    let won_percentage = summary.won as f32 / summary.games as f32 * 100.0;
+
+    println!(
+        "{}",
+        settings.tr(Message::Results {
+            start: &summary.start,
+            won: summary.won,
+            games: summary.games,
+            percentage: won_percentage,
+            avg_rounds: summary.avg_rounds(),
+        })
+    )
+}
+
+/// Saves the results for each start word in `openers` as a baseline to `path`.
+fn write_baseline(path: &Path, openers: &[Summary], settings: &Settings) {
+    let baseline = Baseline {
+        openers: openers.to_vec(),
+    };
+
+    baseline.save(path).unwrap_or_else(|error| {
+        println!("{}", settings.tr(Message::Error(&error)));
+        Exit::Failure.exit();
+    });
+
+    println!(
+        "\n{}",
+        settings.tr(Message::BaselineSaved(&path.display().to_string()))
+    );
+}
+
+/// Prints the start words in `openers` whose results are worse than in `baseline` and
+/// exits with an error code if there are any.
+fn compare_with_baseline(baseline: &Baseline, openers: &[Summary], settings: &Settings) {
+    let regressions = baseline.compare(openers);
+    let compared = openers
+        .iter()
+        .filter(|s| baseline.openers.iter().any(|b| b.start == s.start))
+        .count();
+
+    println!();
+
+    for regression in &regressions {
+        let message = match *regression {
+            Regression::Losses { before, after } => Message::MoreLosses {
+                start: &after.start,
+                before: before.lost(),
+                after: after.lost(),
+            },
+            Regression::AvgRounds { before, after } => Message::WorseAverage {
+                start: &after.start,
+                before: before.avg_rounds(),
+                after: after.avg_rounds(),
+            },
+            Regression::Games { before, after } => Message::GamesChanged {
+                start: &after.start,
+                before: before.games,
+                after: after.games,
+            },
+        };
+
+        println!("{}", settings.tr(message));
+    }
+
+    println!(
+        "{}",
+        settings.tr(Message::BaselineResults {
+            compared,
+            regressions: regressions.len(),
+        })
+    );
+
+    if !regressions.is_empty() {
+        Exit::Failure.exit();
+    }
+}
+
+/// Prints `error` and exits with an error code.
+fn exit_with_error(error: &Error, settings: &Settings) -> ! {
+    println!("{}", settings.tr(Message::InvalidInput(error)));
+
+    match error {
+        Error::Contradiction(_) => Exit::NoCandidates.exit(),
+        _ => Exit::InvalidInput.exit(),
+    }
+}
+
+/// Prints a usage error like clap, but exits with [`Exit::Usage`]. The help and the
+/// version are printed as usual.
+fn exit_with_usage(error: clap::Error) -> ! {
+    if !error.use_stderr() {
+        error.exit();
+    }
+
+    let _ = error.print();
+    Exit::Usage.exit()
+}
+
+/// Reads a line of input. The help is shown at any prompt until something else is entered.
+fn user_input(completions: Option<&Wordlist>, settings: &Settings) -> String {
+    loop {
+        let input = term::read_line("> ", completions).unwrap_or_else(|error| {
+            println!("\n{}", settings.tr(Message::Error(&error.to_string())));
+            Exit::Failure.exit();
+        });
+
+        match input {
+            Some(input) if input.trim() == HELP_COMMAND => {
+                let gray_aliases = &settings.gray_aliases;
+                println!("\n{}\n", settings.tr(Message::Help { gray_aliases }));
+            }
+            Some(input) if input.trim() != QUIT_COMMAND => return input,
+            _ => {
+                println!("\n{}", settings.tr(Message::Bye));
+                Exit::Aborted.exit();
+            }
+        }
+    }
+}
+
+/// Input at the prompt for a guess in `play`
+enum Guess {
+    /// The word that was guessed.
+    Word(String),
+    /// Go back to the previous round.
+    Undo,
+    /// Show all candidate words.
+    List,
+    /// Save the game to a file.
+    Save(PathBuf),
+    /// Change the number of suggestions, or `None` if the number is not valid.
+    Top(Option<usize>),
+}
+
+/// Input at the prompt when no words are left in `play`
+enum Recovery {
+    /// Undo the last round.
+    Undo,
+    /// Enter the colors of the last guess again.
+    Retry,
+    /// Apply the most likely correction of a color.
+    Fix,
+    /// End the game.
+    Quit,
+}
+
+fn get_recovery(word: &str, fix: bool, settings: &Settings) -> Recovery {
+    loop {
+        println!("\n{}", settings.tr(Message::RecoveryOptions { word, fix }));
+
+        match settings.normalize(&user_input(None, settings)).as_str() {
+            "undo" => return Recovery::Undo,
+            "retry" => return Recovery::Retry,
+            "fix" if fix => return Recovery::Fix,
+            "" => return Recovery::Quit,
+            _ => {}
+        }
+    }
+}
+
+fn get_user_guess(
+    i: usize,
+    completions: &Wordlist,
+    hard: bool,
+    settings: &Settings,
+) -> Result<Guess, Error> {
+    println!("\n{}", settings.tr(Message::EnterWord(i)));
+    let input = user_input(Some(completions), settings);
+
+    // The file name is taken as it is, or from the next input if it is missing.
+    if let Some(path) = input.trim().strip_prefix(SAVE_COMMAND) {
+        if path.is_empty() || path.starts_with(char::is_whitespace) {
+            let path = match path.trim() {
+                "" => {
+                    println!("\n{}", settings.tr(Message::EnterSaveFile));
+                    user_input(None, settings)
+                }
+                path => path.to_string(),
+            };
+
+            return Ok(Guess::Save(PathBuf::from(path.trim())));
+        }
+    }
+
+    if let Some(n) = input.trim().strip_prefix(TOP_COMMAND) {
+        if n.is_empty() || n.starts_with(char::is_whitespace) {
+            return Ok(Guess::Top(n.trim().parse().ok().filter(|&n| n > 0)));
+        }
+    }
+
+    let input = settings.normalize(&input);
+
+    match input.as_str() {
+        "undo" => Ok(Guess::Undo),
+        "list" => Ok(Guess::List),
+        _ => {
+            let word = check_user_word(input, completions, hard, settings)?;
+            warn_if_unknown(&word, completions, settings);
+            Ok(Guess::Word(word))
+        }
+    }
+}
+
+fn get_user_word(
+    i: usize,
+    completions: &Wordlist,
+    hard: bool,
+    settings: &Settings,
+) -> Result<String, Error> {
+    println!("\n{}", settings.tr(Message::EnterWord(i)));
+    let word = settings.normalize(&user_input(Some(completions), settings));
+    let word = check_user_word(word, completions, hard, settings)?;
+    warn_if_unknown(&word, completions, settings);
+
+    Ok(word)
+}
+
+/// Warns with the closest words if `word` is not in `completions`. Outside of hard mode,
+/// such a guess is still played, as the dictionary may lack words that Wordle accepts.
+fn warn_if_unknown(word: &str, completions: &Wordlist, settings: &Settings) {
+    if !completions.contains(word) {
+        let warning = Error::UnknownWord {
+            suggestions: suggestions(word, completions),
+            input: word.to_string(),
+        };
+        println!("\n{}", settings.tr(Message::Warning(&warning)));
+    }
+}
+
+fn check_user_word(
+    word: String,
+    completions: &Wordlist,
+    hard: bool,
+    settings: &Settings,
+) -> Result<String, Error> {
+    let alphabet = settings.preset.alphabet();
+
+    // An invalid symbol explains a wrong length, e.g. in "übel".
+    if let Some(c) = word.chars().find(|&c| !alphabet.contains(c)) {
+        return Err(Error::InvalidSymbol(c));
+    }
+
+    if word.chars().count() != settings.word_len {
+        return Err(Error::IncorrectWordLength {
+            input: word,
+            len: settings.word_len,
+        });
+    }
+
+    alphabet.validate(&word)?;
+
+    if hard && !completions.contains(&word) {
+        return Err(Error::NotACandidate {
+            suggestions: suggestions(&word, completions),
+            input: word,
+        });
+    }
+
+    Ok(word)
+}
+
+/// Returns the word given on the command line, e.g. with `--start` or `--target`, or exits
+/// with an error if it is not in `wordlist`.
+fn known_word(input: &str, wordlist: &Wordlist, settings: &Settings) -> Word {
+    let word = check_user_word(settings.normalize(input), wordlist, false, settings)
+        .unwrap_or_else(|error| exit_with_error(&error, settings));
+
+    if !wordlist.contains(&word) {
+        let error = Error::UnknownWord {
+            suggestions: suggestions(&word, wordlist),
+            input: word,
+        };
+        exit_with_error(&error, settings);
+    }
+
+    Word::from(word)
+}
+
+/// Returns the words of `wordlist` that `input` was most likely meant to be.
+fn suggestions(input: &str, wordlist: &Wordlist) -> Vec<String> {
+    wordlist
+        .closest(input, MAX_SUGGESTION_EDITS)
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|w| w.to_string())
+        .collect()
+}
+
+fn get_contraints(word: &str, settings: &Settings) -> Result<ConstraintSet, Error> {
+    get_unchecked_contraints(word, settings).and_then(validated)
+}
+
+/// Reads a color code without checking that Wordle could show it, for variants whose
+/// feedback differs from Wordle's (Xordle, Fibble).
+fn get_unchecked_contraints(word: &str, settings: &Settings) -> Result<ConstraintSet, Error> {
+    println!("\n{}", settings.tr(Message::EnterCode));
+    read_contraints(word, settings)
+}
+
+fn get_board_contraints(
+    word: &str,
+    board: usize,
+    settings: &Settings,
+) -> Result<ConstraintSet, Error> {
+    println!("\n{}", settings.tr(Message::EnterBoardCode(board)));
+    read_contraints(word, settings).and_then(validated)
+}
+
+fn validated(constraints: ConstraintSet) -> Result<ConstraintSet, Error> {
+    constraints.validate().map(|_| constraints)
 }
 
-fn get_contraints(word: &str) -> Result<ConstraintSet, InputError> {
-    println!("\nPlease enter Wordle's answer. (G = Green, Y = Yellow, _ = Gray)");
-    let colors = user_input();
+fn read_contraints(word: &str, settings: &Settings) -> Result<ConstraintSet, Error> {
+    let keep = format!("_{}", settings.gray_aliases);
+    let colors = normalize_input(&user_input(None, settings), &keep);
 
-    if colors.len() != WORD_LEN {
-        return Err(InputError::IncorrectColorCodeLength(WORD_LEN));
+    if colors.chars().count() != settings.word_len {
+        return Err(Error::IncorrectColorCodeLength {
+            input: colors,
+            len: settings.word_len,
+        });
     }
 
-    ConstraintSet::try_from((word, colors.as_ref()))
+    ConstraintSet::with_gray_aliases(word, &colors, &settings.gray_aliases)
 }
 
 #[cfg(test)]
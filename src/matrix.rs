@@ -0,0 +1,171 @@
+//! The feedback for every pair of guess and mystery word, written to a file for use in
+//! other tools.
+//!
+//! # Patterns
+//!
+//! The feedback for a guess is stored as a pattern number: a number in base 3 with one
+//! digit per character, the first character being the most significant one. As in the
+//! compact notation, `2` is Green, `1` is Yellow and `0` is Gray. The feedback `10202` is
+//! therefore stored as 1 · 81 + 0 · 27 + 2 · 9 + 0 · 3 + 2 = 101.
+//!
+//! # CSV
+//!
+//! The first row has the column name `guess` followed by the mystery words. Each further
+//! row has a guess followed by the feedback for each mystery word, written with one digit
+//! per character (e.g. `10202`).
+//!
+//! # Binary
+//!
+//! All numbers are little-endian.
+//!
+//! | Bytes     | Content                                                          |
+//! |-----------|------------------------------------------------------------------|
+//! | 4         | The magic bytes `PRMX`                                           |
+//! | 1         | Version of the format (currently 1)                              |
+//! | 1         | Number of characters per word                                    |
+//! | 4         | Number of words `n` (u32)                                        |
+//! | variable  | The `n` words in UTF-8, each followed by a newline               |
+//! | 2 · n · n | The pattern numbers (u16), one row of `n` numbers for each guess |
+//!
+//! The same words are used as guesses and as mystery words, in the same order.
+use std::io::{self, Write};
+
+use crate::{feedback::Feedback, Word, Wordlist};
+
+/// Magic bytes at the start of the binary format.
+pub const MAGIC: &[u8; 4] = b"PRMX";
+
+/// Version of the binary format.
+pub const VERSION: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The file format of the matrix
+pub enum Format {
+    /// Comma-separated values with the feedback written as digits.
+    Csv,
+    /// Pattern numbers in a compact binary format.
+    Binary,
+}
+
+/// Returns the pattern number of `feedback` (see the [module documentation](self)).
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{feedback, matrix};
+/// let feedback = feedback::from_code("Y_G_G").unwrap();
+///
+/// assert_eq!(matrix::pattern(&feedback), 101);
+/// ```
+pub fn pattern(feedback: &[Feedback]) -> u16 {
+    feedback.iter().fold(0, |n, f| n * 3 + u16::from(digit(*f)))
+}
+
+/// Returns the digit of a color in a pattern.
+fn digit(feedback: Feedback) -> u8 {
+    match feedback {
+        Feedback::Green => 2,
+        Feedback::Yellow => 1,
+        Feedback::Gray => 0,
+    }
+}
+
+/// Writes the feedback for each pair of words in `wordlist` to `writer` in `format`.
+pub fn write<W: Write>(wordlist: &Wordlist, format: Format, mut writer: W) -> io::Result<()> {
+    let words: Vec<&Word> = wordlist.iter().collect();
+
+    match format {
+        Format::Csv => {
+            write!(writer, "guess")?;
+
+            for target in &words {
+                write!(writer, ",{}", target)?;
+            }
+
+            writeln!(writer)?;
+
+            for guess in &words {
+                write!(writer, "{}", guess)?;
+
+                for target in &words {
+                    let digits: String = guess
+                        .feedback(target)
+                        .into_iter()
+                        .map(|f| char::from(b'0' + digit(f)))
+                        .collect();
+                    write!(writer, ",{}", digits)?;
+                }
+
+                writeln!(writer)?;
+            }
+        }
+        Format::Binary => {
+            let invalid = |what: &str| io::Error::new(io::ErrorKind::InvalidInput, what);
+
+            // The patterns of words with more than 10 characters do not fit into a u16.
+            let word_len = words.first().map_or(0, |w| w.chars().count());
+            let word_len = u8::try_from(word_len)
+                .ok()
+                .filter(|&len| len <= 10)
+                .ok_or_else(|| invalid("words are too long for the binary format"))?;
+            let n = u32::try_from(words.len()).map_err(|_| invalid("too many words"))?;
+
+            writer.write_all(MAGIC)?;
+            writer.write_all(&[VERSION, word_len])?;
+            writer.write_all(&n.to_le_bytes())?;
+
+            for word in &words {
+                writeln!(writer, "{}", word)?;
+            }
+
+            for guess in &words {
+                for target in &words {
+                    writer.write_all(&pattern(&guess.feedback(target)).to_le_bytes())?;
+                }
+            }
+        }
+    }
+
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wordlist(words: &[&str]) -> Wordlist {
+        words.iter().map(Word::from).collect()
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut out = vec![];
+        write(&wordlist(&["crate", "trace"]), Format::Csv, &mut out).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "guess,crate,trace\ncrate,22222,12212\ntrace,12212,22222\n"
+        );
+    }
+
+    #[test]
+    fn test_binary() {
+        let mut out = vec![];
+        write(&wordlist(&["crate", "trace"]), Format::Binary, &mut out).unwrap();
+
+        let header = [
+            b"PRMX" as &[u8],
+            &[1, 5],
+            &2u32.to_le_bytes(),
+            b"crate\ntrace\n",
+        ]
+        .concat();
+        assert_eq!(&out[..header.len()], header);
+
+        let patterns: Vec<_> = out[header.len()..]
+            .chunks(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .collect();
+        assert_eq!(patterns, [242, 158, 158, 242]);
+    }
+}
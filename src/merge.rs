@@ -0,0 +1,324 @@
+//! Merging the constraints of several guesses into one set.
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use itertools::Itertools;
+
+use crate::{Constraint, ConstraintSet, Error, Word, Wordlist};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// The combined constraints of several guesses without redundancies
+///
+/// A word matches the merged constraints if and only if it matches each of the
+/// [`ConstraintSet`]s that were merged.
+pub struct MergedConstraints {
+    /// The character known to be at each position.
+    fixed: Vec<Option<char>>,
+    /// Characters that are known to be in the word but not at each position.
+    excluded: Vec<BTreeSet<char>>,
+    /// Lower and upper bounds for the number of occurrences of the characters about which
+    /// something is known.
+    counts: BTreeMap<char, (usize, Option<usize>)>,
+}
+
+impl MergedConstraints {
+    /// Creates an empty set of constraints for words with `word_len` characters, which
+    /// matches every word.
+    pub fn new(word_len: usize) -> Self {
+        Self {
+            fixed: vec![None; word_len],
+            excluded: vec![BTreeSet::new(); word_len],
+            counts: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the length of the words that the constraints are about.
+    pub fn word_len(&self) -> usize {
+        self.fixed.len()
+    }
+
+    /// Returns the constraints combined with `constraints` or an error if they contradict
+    /// each other, in which case no word could match both.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{merge::MergedConstraints, ConstraintSet, Error};
+    /// let crate_ = ConstraintSet::try_from(("crate", "_Y__G")).unwrap();
+    /// let horse = ConstraintSet::try_from(("horse", "_YY_G")).unwrap();
+    ///
+    /// let merged = MergedConstraints::new(5).merge(&crate_).unwrap().merge(&horse).unwrap();
+    /// assert_eq!(merged.to_string(), "o~1, r~1, r~2, e@4, a-, c-, h-, s-, t-");
+    ///
+    /// let wrong = ConstraintSet::try_from(("rogue", "_____")).unwrap();
+    /// assert!(merged.merge(&wrong).is_err());
+    /// ```
+    pub fn merge(&self, constraints: &ConstraintSet) -> Result<Self, Error> {
+        let mut merged = self.clone();
+        merged.add(constraints)?;
+        merged.simplify();
+
+        Ok(merged)
+    }
+
+    fn add(&mut self, constraints: &ConstraintSet) -> Result<(), Error> {
+        if constraints.constraints.len() != self.word_len() {
+            return Err(Error::IncorrectWordLength {
+                input: constraints.word(),
+                len: self.word_len(),
+            });
+        }
+
+        let contradiction = |c: &Constraint| Error::Contradiction(c.to_string());
+
+        for (i, constraint) in constraints.iter().enumerate() {
+            let c = match *constraint {
+                Constraint::AtPos(_, c) => {
+                    if self.fixed[i].is_some_and(|d| d != c) || self.excluded[i].contains(&c) {
+                        return Err(contradiction(constraint));
+                    }
+
+                    self.fixed[i] = Some(c);
+                    c
+                }
+                Constraint::NotAtPos(_, c) | Constraint::Absent(c) => {
+                    if self.fixed[i] == Some(c) {
+                        return Err(contradiction(constraint));
+                    }
+
+                    // A gray character that is absent needs no exclusion.
+                    if constraints.counts[&c].0 > 0 {
+                        self.excluded[i].insert(c);
+                    }
+
+                    c
+                }
+            };
+
+            self.restrict(c, constraints.counts[&c])
+                .map_err(|_| contradiction(constraint))?;
+        }
+
+        self.check()
+    }
+
+    /// Narrows the bounds for the number of occurrences of `c`. Fails if no number is left.
+    fn restrict(&mut self, c: char, (min, max): (usize, Option<usize>)) -> Result<(), ()> {
+        let bounds = self.counts.entry(c).or_insert((0, None));
+
+        bounds.0 = bounds.0.max(min);
+        bounds.1 = match (bounds.1, max) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+
+        if bounds.1.is_some_and(|max| max < bounds.0) {
+            return Err(());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that there are enough positions for all characters that must occur and that
+    /// no character is fixed at more positions than it may occur.
+    fn check(&self) -> Result<(), Error> {
+        for (&c, &(min, max)) in &self.counts {
+            let fixed = self.fixed.iter().filter(|&&f| f == Some(c)).count();
+            let possible = (0..self.word_len())
+                .filter(|&i| self.fixed[i].is_none() && !self.excluded[i].contains(&c))
+                .count();
+
+            if fixed + possible < min {
+                let constraint = match self.excluded.iter().position(|e| e.contains(&c)) {
+                    Some(i) => Constraint::NotAtPos(i, c),
+                    None => Constraint::AtPos(0, c),
+                };
+
+                return Err(Error::Contradiction(constraint.to_string()));
+            }
+
+            if max.is_some_and(|max| fixed > max) {
+                let i = self.fixed.iter().rposition(|&f| f == Some(c)).unwrap_or(0);
+
+                return Err(Error::Contradiction(Constraint::AtPos(i, c).to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drops the exclusions at positions whose character is known.
+    fn simplify(&mut self) {
+        for (fixed, excluded) in self.fixed.iter().zip(self.excluded.iter_mut()) {
+            if fixed.is_some() {
+                excluded.clear();
+            }
+        }
+    }
+
+    /// Returns `true` if `word` complies with all the constraints.
+    pub fn is_match(&self, word: &Word) -> bool {
+        let positions_match =
+            self.fixed.iter().zip(&self.excluded).zip(word.chars()).all(
+                |((fixed, excluded), c)| fixed.map_or(true, |f| f == c) && !excluded.contains(&c),
+            );
+
+        positions_match
+            && self.counts.iter().all(|(&c, &(min, max))| {
+                let count = word.chars().filter(|&d| d == c).count();
+                count >= min && max.map_or(true, |max| count <= max)
+            })
+    }
+
+    /// Returns the words in `wordlist` that comply with all the constraints.
+    pub fn filter<'a>(&'a self, wordlist: &'a Wordlist) -> impl Iterator<Item = &'a Word> {
+        wordlist.iter().filter(|w| self.is_match(w))
+    }
+}
+
+impl ConstraintSet {
+    /// Merges the set with `other` (see [`MergedConstraints::merge`]).
+    pub fn merge(&self, other: &ConstraintSet) -> Result<MergedConstraints, Error> {
+        MergedConstraints::new(self.constraints.len())
+            .merge(self)?
+            .merge(other)
+    }
+}
+
+impl fmt::Display for MergedConstraints {
+    /// Writes the constraints in the same form as a [`ConstraintSet`]: first the known
+    /// characters (`c@0`) and exclusions (`r~1`) by position, then the number of
+    /// occurrences where it is not evident from the positions (`r+` for at least once,
+    /// `l=1` for exactly once, `e>=2` for at least twice) and finally the absent characters
+    /// (`a-`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let positional =
+            self.fixed
+                .iter()
+                .zip(&self.excluded)
+                .enumerate()
+                .flat_map(|(i, (fixed, excluded))| {
+                    let fixed = fixed.map(|c| Constraint::AtPos(i, c));
+                    fixed
+                        .into_iter()
+                        .chain(excluded.iter().map(move |&c| Constraint::NotAtPos(i, c)))
+                });
+
+        let present = self.counts.iter().filter_map(|(&c, &(min, max))| {
+            let fixed = self.fixed.iter().filter(|&&f| f == Some(c)).count();
+            let known = if fixed > 0 {
+                fixed
+            } else {
+                usize::from(self.excluded.iter().any(|e| e.contains(&c)))
+            };
+
+            match max {
+                Some(0) => None,
+                Some(max) => Some(format!("{}={}", c, max)),
+                None if min <= known => None,
+                None if min == 1 => Some(format!("{}+", c)),
+                None => Some(format!("{}>={}", c, min)),
+            }
+        });
+
+        let absent = self
+            .counts
+            .iter()
+            .filter(|(_, &(_, max))| max == Some(0))
+            .map(|(&c, _)| Constraint::Absent(c).to_string());
+
+        let mut items = positional
+            .map(|c| c.to_string())
+            .chain(present)
+            .chain(absent);
+
+        write!(f, "{}", items.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        guesses,
+        target,
+        case(&["crate", "horse"], "ombre"),
+        case(&["spell", "poles"], "poles"),
+        case(&["abbey", "babes"], "bebop"),
+        case(&["stare", "pluck", "vouch"], "mound"),
+        case(&["spell", "lolly"], "poles"),
+        case(&["geese", "eerie"], "tepee")
+    )]
+    fn test_merge_matches_sequential_filter(guesses: &[&str], target: &str) {
+        let wordlist = Wordlist::load();
+        let sets: Vec<_> = guesses
+            .iter()
+            .map(|&g| ConstraintSet::from_guess(&Word::from(g), &Word::from(target)))
+            .collect();
+
+        let merged = sets
+            .iter()
+            .try_fold(MergedConstraints::new(5), |m, c| m.merge(c))
+            .unwrap();
+
+        let expected: Vec<_> = wordlist
+            .iter()
+            .filter(|w| sets.iter().all(|c| c.is_match(w)))
+            .collect();
+
+        assert_eq!(merged.filter(&wordlist).collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest(
+        first,
+        second,
+        offending,
+        case(("crate", "G____"), ("cloud", "_____"), "c-"),
+        case(("crate", "G____"), ("other", "____Y"), "r~4"),
+        case(("crate", "_G___"), ("blame", "_G___"), "l@1"),
+        case(("crate", "_Y___"), ("brown", "_G___"), "r@1"),
+        case(("crate", "Y____"), ("occcc", "_YYYY"), "c~0"),
+        case(("spell", "YYYY_"), ("lolly", "Y_Y__"), "l~0"),
+        case(("lolly", "__G__"), ("spell", "___G_"), "l@3")
+    )]
+    fn test_merge_contradiction(first: (&str, &str), second: (&str, &str), offending: &str) {
+        let first = ConstraintSet::try_from(first).unwrap();
+        let second = ConstraintSet::try_from(second).unwrap();
+
+        assert_eq!(
+            first.merge(&second),
+            Err(Error::Contradiction(offending.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_simplify() {
+        let first = ConstraintSet::try_from(("crate", "_Y___")).unwrap();
+        let second = ConstraintSet::try_from(("bloom", "_G___")).unwrap();
+
+        assert_eq!(
+            first.merge(&second).unwrap().to_string(),
+            "l@1, r+, a-, b-, c-, e-, m-, o-, t-"
+        );
+    }
+
+    #[rstest(
+        first,
+        second,
+        expected,
+        case(("spell", "YYYY_"), ("poles", "GGGGG"), "p@0, o@1, l@2, e@3, s@4, l=1"),
+        case(("spell", "YYG__"), ("sheep", "Y_GYY"), "s~0, p~1, e@2, e~3, p~4, e>=2, h-, l-"),
+        case(("geese", "_GY_G"), ("eerie", "YG__G"), "e~0, e@1, e~2, e@4, e>=3, g-, i-, r-, s-")
+    )]
+    fn test_counts(first: (&str, &str), second: (&str, &str), expected: &str) {
+        let first = ConstraintSet::try_from(first).unwrap();
+        let second = ConstraintSet::try_from(second).unwrap();
+
+        assert_eq!(first.merge(&second).unwrap().to_string(), expected);
+    }
+}
@@ -0,0 +1,268 @@
+//! Frequencies and tags of the words in an extended wordlist.
+//!
+//! Besides one word per line, a wordlist file may give the frequency and tags of each word
+//! in further columns separated by tabs: `word<TAB>freq<TAB>tags`. The frequency is a
+//! non-negative number and the tags are separated by commas, e.g. (with tabs between the
+//! columns)
+//!
+//! ```text
+//! crate   120   common
+//! craic   2     informal,obscure
+//! thine         archaic
+//! trace
+//! ```
+//!
+//! Both columns are optional and may be empty, so plain wordlists are valid as well. The
+//! frequencies can serve as the [prior](crate::prior) of the words, and the tags can be used
+//! to leave words out of the game (e.g. `archaic`) or to mark them in the suggestions (e.g.
+//! `obscure`).
+use std::{collections::HashMap, io::BufRead};
+#[cfg(feature = "std-fs")]
+use std::{fs, path::Path};
+
+use crate::{alphabet::lowercase_word, prior::Prior, Error, Word, Wordlist};
+
+/// Tag of the words that are marked as obscure in the suggestions.
+pub const OBSCURE: &str = "obscure";
+
+/// Words with less than this fraction of the highest frequency in the file are obscure.
+const OBSCURE_FRACTION: f64 = 0.001;
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// The frequency and tags of one word
+pub struct WordMetadata {
+    /// Frequency of the word, if given.
+    pub frequency: Option<f64>,
+    /// Tags of the word, e.g. `archaic` or `obscure`.
+    pub tags: Vec<String>,
+}
+
+impl WordMetadata {
+    /// Returns `true` if the word has `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// The frequencies and tags of the words in a wordlist file
+pub struct Metadata {
+    /// Metadata of the words that have a frequency or tags, by lowercase word.
+    words: HashMap<String, WordMetadata>,
+    /// Highest frequency of any word.
+    max_frequency: Option<f64>,
+}
+
+impl Metadata {
+    /// Reads a wordlist with one word per line, optionally followed by its frequency and
+    /// tags (see the [module documentation](self)). Returns the words as they are written
+    /// in the file, so that they can still be cleaned up with [`Wordlist::clean`], and their
+    /// metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{metadata::Metadata, Word};
+    /// let (wordlist, metadata) =
+    ///     Metadata::from_reader("crate\t120\ncraic\t2\tobscure\nthine\t\tarchaic\n".as_bytes())
+    ///         .unwrap();
+    ///
+    /// assert_eq!(wordlist.len(), 3);
+    /// assert!(metadata.is_obscure(&Word::from("craic")));
+    /// assert!(metadata.has_tag(&Word::from("thine"), "archaic"));
+    /// assert!(Metadata::from_reader("crate\tlots\n".as_bytes()).is_err());
+    /// ```
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<(Wordlist, Metadata), Error> {
+        let mut words = vec![];
+        let mut metadata = Metadata::default();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| Error::UnreadableWordlist {
+                path: "-".to_string(),
+                reason: e.to_string(),
+            })?;
+
+            words.push(Word::from(metadata.push_line(i + 1, &line)?));
+        }
+
+        Ok((Wordlist::from_iter(words), metadata))
+    }
+
+    /// Reads the frequency and tags from `line` (with the number `line_number`, counting
+    /// from 1) of a wordlist file and returns the word as it is written in the file. This
+    /// allows the words to be processed one at a time, e.g. by a
+    /// [`Loader`](crate::packed::Loader).
+    pub fn push_line<'l>(&mut self, line_number: usize, line: &'l str) -> Result<&'l str, Error> {
+        let mut columns = line.split('\t');
+        let word = columns.next().unwrap_or_default();
+        let frequency = columns.next().map(str::trim).unwrap_or_default();
+        let tags = columns.next().unwrap_or_default();
+
+        let invalid = || Error::InvalidMetadataEntry {
+            line: line_number,
+            entry: line.to_string(),
+        };
+
+        if columns.next().is_some() {
+            return Err(invalid());
+        }
+
+        let frequency = match frequency {
+            "" => None,
+            frequency => match frequency.parse::<f64>() {
+                Ok(f) if f.is_finite() && f >= 0.0 => Some(f),
+                _ => return Err(invalid()),
+            },
+        };
+        let tags: Vec<_> = tags
+            .split(',')
+            .map(|tag| tag.trim().to_lowercase())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+
+        if let Some(frequency) = frequency {
+            self.max_frequency = Some(
+                self.max_frequency
+                    .map_or(frequency, |max| max.max(frequency)),
+            );
+        }
+
+        if frequency.is_some() || !tags.is_empty() {
+            self.words.insert(
+                lowercase_word(word.trim()),
+                WordMetadata { frequency, tags },
+            );
+        }
+
+        Ok(word)
+    }
+
+    /// Returns `true` if no word has a frequency or tags.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the metadata of `word`, if it has any.
+    pub fn get(&self, word: &Word) -> Option<&WordMetadata> {
+        self.words.get(&*word.0)
+    }
+
+    /// Returns `true` if `word` has `tag`.
+    pub fn has_tag(&self, word: &Word, tag: &str) -> bool {
+        self.get(word).is_some_and(|m| m.has_tag(tag))
+    }
+
+    /// Returns `true` if `word` is tagged as obscure or its frequency is less than a
+    /// thousandth of the highest frequency.
+    pub fn is_obscure(&self, word: &Word) -> bool {
+        let Some(metadata) = self.get(word) else {
+            return false;
+        };
+
+        let rare = match (metadata.frequency, self.max_frequency) {
+            (Some(frequency), Some(max)) => frequency < max * OBSCURE_FRACTION,
+            _ => false,
+        };
+
+        rare || metadata.has_tag(OBSCURE)
+    }
+
+    /// Returns the words in `wordlist` that have none of the `tags`.
+    pub fn exclude_tags(&self, wordlist: Wordlist, tags: &[String]) -> Wordlist {
+        if tags.is_empty() {
+            return wordlist;
+        }
+
+        wordlist
+            .into_iter()
+            .filter(|w| !tags.iter().any(|tag| self.has_tag(w, tag)))
+            .collect()
+    }
+
+    /// Returns the prior with the frequencies of the words as weights (see
+    /// [`Prior::from_weights`]), or `None` if no word has a frequency.
+    pub fn prior(&self) -> Option<Prior> {
+        self.max_frequency?;
+
+        Some(Prior::from_weights(self.words.iter().filter_map(
+            |(word, m)| m.frequency.map(|frequency| (word.clone(), frequency)),
+        )))
+    }
+}
+
+#[cfg(feature = "std-fs")]
+impl Metadata {
+    /// Loads a wordlist with optional metadata (see [`Metadata::from_reader`]) from the text
+    /// file at `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<(Wordlist, Metadata), Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| Error::UnreadableWordlist {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+
+        Self::from_reader(content.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(wordlist: &Wordlist) -> Vec<String> {
+        wordlist.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let (wordlist, metadata) = Metadata::from_reader(
+            "Crate\t1000\tcommon\ncraic\t0.5\nthine\t\tArchaic, obscure\ntrace\n\n".as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(words(&wordlist), ["Crate", "craic", "thine", "trace", ""]);
+        assert_eq!(
+            metadata.get(&Word::from("thine")),
+            Some(&WordMetadata {
+                frequency: None,
+                tags: vec!["archaic".to_string(), "obscure".to_string()]
+            })
+        );
+        assert_eq!(metadata.get(&Word::from("trace")), None);
+
+        assert!(!metadata.is_obscure(&Word::from("crate")));
+        assert!(metadata.is_obscure(&Word::from("craic")));
+        assert!(metadata.is_obscure(&Word::from("thine")));
+        assert!(!metadata.is_obscure(&Word::from("trace")));
+
+        let wordlist = wordlist.clean(&Default::default(), false).unwrap().0;
+        let tags = ["archaic".to_string()];
+        assert_eq!(
+            words(&metadata.exclude_tags(wordlist, &tags)),
+            ["crate", "craic", "trace"]
+        );
+
+        let prior = metadata.prior().unwrap();
+        assert_eq!(prior.weight(&Word::from("crate")), 1000.0);
+        assert_eq!(prior.weight(&Word::from("trace")), 0.5);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            Metadata::from_reader("crate\ntrace\t-1\n".as_bytes()).err(),
+            Some(Error::InvalidMetadataEntry {
+                line: 2,
+                entry: "trace\t-1".to_string()
+            })
+        );
+        assert!(Metadata::from_reader("crate\t1\ttag\textra\n".as_bytes()).is_err());
+        assert_eq!(
+            Metadata::from_reader("crate\n".as_bytes())
+                .unwrap()
+                .1
+                .prior(),
+            None
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Heuristics for inflected word forms, which are rarely chosen as the mystery word.
+//!
+//! The NYT hardly ever picks regular plurals (e.g. `cards`) or past tenses (e.g. `baked`)
+//! as the answer, although they are valid guesses. The checks only look at the endings, so
+//! words like `bonus` or `bleed` are not affected, but some words like `naked` are.
+
+use crate::Word;
+
+/// Returns `true` if `word` looks like a regular plural: it ends in `s`, but not in `ss`,
+/// `us` or `is`.
+pub fn is_plural(word: &Word) -> bool {
+    word.0.ends_with('s') && !["ss", "us", "is"].iter().any(|end| word.0.ends_with(end))
+}
+
+/// Returns `true` if `word` looks like a regular past tense: it ends in `ed`, but not in
+/// `eed`.
+pub fn is_past_tense(word: &Word) -> bool {
+    word.0.ends_with("ed") && !word.0.ends_with("eed")
+}
+
+/// Returns `true` if `word` looks like a regular plural or past tense.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{morphology, Word};
+/// assert!(morphology::is_inflected(&Word::from("cards")));
+/// assert!(morphology::is_inflected(&Word::from("baked")));
+/// assert!(!morphology::is_inflected(&Word::from("crate")));
+/// ```
+pub fn is_inflected(word: &Word) -> bool {
+    is_plural(word) || is_past_tense(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        word,
+        expected,
+        case("cards", true),
+        case("plays", true),
+        case("abyss", false),
+        case("bonus", false),
+        case("basis", false),
+        case("baked", true),
+        case("bleed", false),
+        case("crate", false)
+    )]
+    fn test_is_inflected(word: &str, expected: bool) {
+        assert_eq!(is_inflected(&Word::from(word)), expected);
+    }
+}
@@ -0,0 +1,236 @@
+//! Games with several boards that share the same guesses, like Quordle.
+use crate::{ConstraintSet, Error, Word, Wordlist};
+
+/// Returns the number of rounds that are usually allowed for a game with `boards` boards,
+/// e.g. 6 for Wordle, 7 for Dordle, 9 for Quordle, 13 for Octordle and 21 for Sedecordle.
+pub fn default_round_limit(boards: usize) -> usize {
+    boards + 5
+}
+
+#[derive(Debug, Clone)]
+/// A single board of a [`MultiGame`]
+pub struct Board {
+    /// Words that are still possible targets for this board.
+    candidates: Wordlist,
+    /// Feedback for all guesses played on this board.
+    history: Vec<ConstraintSet>,
+    /// Round in which the board was solved.
+    solved_in: Option<usize>,
+}
+
+impl Board {
+    /// Returns the words that are still possible targets for this board.
+    pub fn candidates(&self) -> &Wordlist {
+        &self.candidates
+    }
+
+    /// Returns the feedback for all guesses played on this board so far.
+    pub fn history(&self) -> &[ConstraintSet] {
+        &self.history
+    }
+
+    /// Returns the round in which the board was solved.
+    pub fn solved_in(&self) -> Option<usize> {
+        self.solved_in
+    }
+
+    /// Returns `true` if the target word of the board has been found.
+    pub fn is_solved(&self) -> bool {
+        self.solved_in.is_some()
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A game with several boards that all receive the same guesses but have different
+/// target words
+pub struct MultiGame {
+    boards: Vec<Board>,
+    /// Number of guesses played so far.
+    round: usize,
+    /// Number of guesses allowed to solve all boards.
+    round_limit: usize,
+}
+
+impl MultiGame {
+    /// Creates a game with `count` boards whose candidates are the words in `wordlist`.
+    /// The number of allowed guesses is given by [`default_round_limit`].
+    pub fn new(count: usize, wordlist: &Wordlist) -> Self {
+        let board = Board {
+            candidates: wordlist.clone(),
+            history: vec![],
+            solved_in: None,
+        };
+
+        Self {
+            boards: vec![board; count],
+            round: 0,
+            round_limit: default_round_limit(count),
+        }
+    }
+
+    /// Sets the number of guesses allowed to solve all boards.
+    pub fn with_round_limit(mut self, round_limit: usize) -> Self {
+        self.round_limit = round_limit;
+        self
+    }
+
+    /// Returns the number of guesses allowed to solve all boards.
+    pub fn round_limit(&self) -> usize {
+        self.round_limit
+    }
+
+    /// Returns the number of guesses that are left.
+    pub fn rounds_left(&self) -> usize {
+        self.round_limit.saturating_sub(self.round)
+    }
+
+    /// Returns all boards of the game.
+    pub fn boards(&self) -> &[Board] {
+        &self.boards
+    }
+
+    /// Returns an iterator over the indices and boards that are not solved yet.
+    pub fn unsolved(&self) -> impl Iterator<Item = (usize, &Board)> {
+        self.boards
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| !b.is_solved())
+    }
+
+    /// Returns the number of guesses played so far.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// Returns `true` if all boards are solved.
+    pub fn is_won(&self) -> bool {
+        self.boards.iter().all(Board::is_solved)
+    }
+
+    /// Returns `true` if all guesses are used up and not all boards are solved.
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && self.rounds_left() == 0
+    }
+
+    /// Applies the feedback for one guess. `constraints` contains one [`ConstraintSet`]
+    /// for each board that is not solved yet, in the order of the boards. Returns
+    /// [`Error::IncorrectBoardCount`] and leaves the game unchanged if the number of
+    /// constraint sets does not match the number of unsolved boards.
+    pub fn apply(&mut self, guess: &str, constraints: Vec<ConstraintSet>) -> Result<(), Error> {
+        let indices: Vec<_> = self.unsolved().map(|(i, _)| i).collect();
+
+        if indices.len() != constraints.len() {
+            return Err(Error::IncorrectBoardCount(indices.len()));
+        }
+
+        self.round += 1;
+
+        for (i, constraints) in indices.into_iter().zip(constraints) {
+            let board = &mut self.boards[i];
+
+            if constraints.correct_word() {
+                board.solved_in = Some(self.round);
+                board.candidates = Wordlist::from_iter([Word::from(guess)]);
+            } else {
+                let candidates = std::mem::take(&mut board.candidates);
+                board.candidates = candidates.filter(&constraints).collect();
+                board.candidates.remove(guess);
+            }
+
+            board.history.push(constraints);
+        }
+
+        Ok(())
+    }
+
+    /// Ranks the words in `guesses` by their combined [`filter_potential`] over the
+    /// candidates of all unsolved boards and returns pairs of word references and scores
+    /// sorted by the score in descending order. Words that can solve a board come first
+    /// among words with the same score, then the words are sorted alphabetically.
+    ///
+    /// [`filter_potential`]: Word::filter_potential
+    pub fn rank_words<'a>(&self, guesses: &'a Wordlist) -> Vec<(&'a Word, usize)> {
+        let mut ranked: Vec<_> = guesses
+            .iter()
+            .map(|w| {
+                let score = self
+                    .unsolved()
+                    .map(|(_, b)| w.filter_potential(&b.candidates))
+                    .sum::<usize>();
+                let hits = self
+                    .unsolved()
+                    .filter(|(_, b)| b.candidates.contains(&w.to_string()))
+                    .count();
+
+                (w, score, hits)
+            })
+            .collect();
+
+        ranked.sort_by_key(|&(w, score, hits)| (std::cmp::Reverse((score, hits)), w));
+
+        ranked.into_iter().map(|(w, score, _)| (w, score)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wordlist(words: &[&str]) -> Wordlist {
+        words.iter().map(Word::from).collect()
+    }
+
+    #[test]
+    fn test_apply() {
+        let words = wordlist(&["crate", "trace", "react", "spoon"]);
+        let mut game = MultiGame::new(2, &words);
+
+        let c1 = ConstraintSet::try_from(("crate", "GGGGG")).unwrap();
+        let c2 = ConstraintSet::from_guess(&Word::from("crate"), &Word::from("trace"));
+        game.apply("crate", vec![c1, c2]).unwrap();
+
+        assert_eq!(game.round(), 1);
+        assert_eq!(game.boards()[0].solved_in(), Some(1));
+        assert!(game.boards()[1].candidates().contains("trace"));
+        assert!(!game.boards()[1].candidates().contains("spoon"));
+        assert!(!game.is_won());
+
+        let c = ConstraintSet::try_from(("trace", "GGGGG")).unwrap();
+        assert_eq!(
+            game.apply("trace", vec![c.clone(), c.clone()]),
+            Err(Error::IncorrectBoardCount(1))
+        );
+        assert_eq!(game.round(), 1);
+
+        game.apply("trace", vec![c]).unwrap();
+
+        assert!(game.is_won());
+        assert_eq!(game.boards()[1].solved_in(), Some(2));
+    }
+
+    #[test]
+    fn test_round_limit() {
+        let words = wordlist(&["crate", "trace"]);
+        let mut game = MultiGame::new(8, &words).with_round_limit(1);
+
+        assert_eq!(default_round_limit(8), 13);
+        assert_eq!(game.rounds_left(), 1);
+
+        let constraints = vec![ConstraintSet::try_from(("crate", "GGGGG")).unwrap(); 8];
+        game.apply("crate", constraints).unwrap();
+
+        assert!(game.is_won());
+        assert!(!game.is_lost());
+    }
+
+    #[test]
+    fn test_rank_words() {
+        let words = wordlist(&["crate", "trace", "spoon"]);
+        let game = MultiGame::new(4, &words);
+
+        let ranked = game.rank_words(&words);
+
+        assert_eq!(ranked.len(), 3);
+        assert_eq!(ranked[0].1, 12);
+    }
+}
@@ -0,0 +1,189 @@
+//! Equations for [Nerdle](https://nerdlegame.com/), the Wordle variant in which the
+//! mystery "word" is a calculation like `12+46=58`.
+use crate::{Word, Wordlist};
+
+/// Symbols that can be used in an equation.
+pub const SYMBOLS: &str = "0123456789+-*/=";
+
+/// Operators that can be used on the left-hand side of an equation.
+const OPERATORS: [char; 4] = ['+', '-', '*', '/'];
+
+/// Returns `true` if `s` is a valid Nerdle equation: a calculation consisting of positive
+/// numbers without leading zeros and at least one operator, followed by `=` and the
+/// non-negative whole-number result. The usual order of operations applies.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::nerdle::is_valid_equation;
+/// assert!(is_valid_equation("12+46=58"));
+/// assert!(is_valid_equation("3+8/4=5"));
+/// assert!(!is_valid_equation("12+46=59"));
+/// assert!(!is_valid_equation("58=12+46"));
+/// assert!(!is_valid_equation("01+46=47"));
+/// ```
+pub fn is_valid_equation(s: &str) -> bool {
+    let Some((lhs, rhs)) = s.split_once('=') else {
+        return false;
+    };
+
+    if !lhs.contains(OPERATORS) || !(rhs == "0" || is_number(rhs)) {
+        return false;
+    }
+
+    match (evaluate(lhs), rhs.parse::<i64>()) {
+        (Some((num, den)), Ok(result)) => num == result * den,
+        _ => false,
+    }
+}
+
+/// Returns `true` if `s` is a positive whole number without leading zeros.
+fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.len() <= 9 && s.chars().all(|c| c.is_ascii_digit()) && !s.starts_with('0')
+}
+
+/// Evaluates the calculation `s` and returns the result as a fraction `(numerator,
+/// denominator)` with a positive denominator. Returns `None` if `s` is not well-formed
+/// or contains a division by zero.
+fn evaluate(s: &str) -> Option<(i64, i64)> {
+    let mut numbers = vec![];
+
+    for part in s.split(OPERATORS) {
+        if !is_number(part) {
+            return None;
+        }
+
+        numbers.push(part.parse::<i64>().ok()?);
+    }
+
+    let operators = s.chars().filter(|c| OPERATORS.contains(c));
+
+    // Multiplications and divisions are applied first, the resulting terms are summed up.
+    let mut terms = vec![(numbers[0], 1_i64)];
+    let mut signs = vec![1_i64];
+
+    for (op, n) in operators.zip(numbers.into_iter().skip(1)) {
+        let (num, den) = terms.last_mut()?;
+
+        match op {
+            '*' => *num = num.checked_mul(n)?,
+            '/' if n == 0 => return None,
+            '/' => *den = den.checked_mul(n)?,
+            '+' | '-' => {
+                terms.push((n, 1));
+                signs.push(if op == '+' { 1 } else { -1 });
+            }
+            _ => return None,
+        }
+    }
+
+    let mut result = (0_i64, 1_i64);
+
+    for ((num, den), sign) in terms.into_iter().zip(signs) {
+        result = (
+            (result.0.checked_mul(den)?).checked_add(sign * num.checked_mul(result.1)?)?,
+            result.1.checked_mul(den)?,
+        );
+        let divisor = gcd(result.0.abs(), result.1);
+        result = (result.0 / divisor, result.1 / divisor);
+    }
+
+    Some(result)
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.max(1)
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Generates all valid equations (see [`is_valid_equation`]) with `len` symbols.
+pub fn equations(len: usize) -> Wordlist {
+    let mut equations = vec![];
+
+    for lhs_len in 3..len.saturating_sub(1) {
+        let mut lhs = String::with_capacity(lhs_len);
+        collect_calculations(&mut lhs, lhs_len, &mut |calculation| {
+            if !calculation.contains(OPERATORS) {
+                return;
+            }
+
+            if let Some((num, 1)) = evaluate(calculation) {
+                let equation = format!("{}={}", calculation, num);
+
+                if num >= 0 && equation.len() == len {
+                    equations.push(equation);
+                }
+            }
+        });
+    }
+
+    equations.sort();
+    equations.into_iter().map(Word::from).collect()
+}
+
+/// Calls `f` for every well-formed calculation with `len` symbols that starts with
+/// `prefix`.
+fn collect_calculations<F: FnMut(&str)>(prefix: &mut String, len: usize, f: &mut F) {
+    if prefix.len() == len {
+        if prefix.ends_with(|c: char| c.is_ascii_digit()) {
+            f(prefix);
+        }
+        return;
+    }
+
+    let after_digit = prefix.ends_with(|c: char| c.is_ascii_digit());
+
+    for c in SYMBOLS.chars().filter(|c| *c != '=') {
+        // Numbers must not start with a zero.
+        let allowed = match c {
+            '0' => after_digit,
+            c if c.is_ascii_digit() => true,
+            _ => after_digit && prefix.len() + 1 < len,
+        };
+
+        if allowed {
+            prefix.push(c);
+            collect_calculations(prefix, len, f);
+            prefix.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        input,
+        valid,
+        case("12+46=58", true),
+        case("3+8/4=5", true),
+        case("20/8*4=10", true),
+        case("9-10+1=0", true),
+        case("1+0+3=4", false),
+        case("5-2*3=-1", false),
+        case("12+46=58=", false),
+        case("+12+46=58", false),
+        case("12+46=058", false),
+        case("1/0+1=1", false),
+        case("123=123", false)
+    )]
+    fn test_is_valid_equation(input: &str, valid: bool) {
+        assert_eq!(is_valid_equation(input), valid);
+    }
+
+    #[test]
+    fn test_equations() {
+        let equations = equations(8);
+
+        assert_eq!(equations.len(), 17723);
+        assert!(equations.contains("12+46=58"));
+        assert!(equations.iter().all(|w| is_valid_equation(&w.to_string())));
+    }
+}
@@ -0,0 +1,152 @@
+//! A compact notation for a guess and Wordle's feedback, used for command line arguments.
+//!
+//! A [`Turn`] is written as the guessed word followed by either
+//!
+//! * `=` and the color code with `G` = Green, `Y` = Yellow and `_` = Gray (or one of the
+//!   [`GRAY_ALIASES`]), e.g. `crate=Y_G_G`, or
+//! * `/` and one digit per character with `2` = Green, `1` = Yellow and `0` = Gray, e.g.
+//!   `crate/10202`.
+use std::{fmt, str::FromStr};
+
+use crate::{nerdle, normalize_code, normalize_input, ConstraintSet, Error, GRAY_ALIASES};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A guess and Wordle's color code for it
+pub struct Turn {
+    guess: String,
+    code: String,
+    /// The constraints that follow from the color code, derived when the turn is created.
+    constraints: ConstraintSet,
+}
+
+impl Turn {
+    /// Returns the guessed word in lower case.
+    pub fn guess(&self) -> &str {
+        &self.guess
+    }
+
+    /// Returns the color code with `G`, `Y` and `_`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the constraints that follow from the color code.
+    pub fn constraints(&self) -> ConstraintSet {
+        self.constraints.clone()
+    }
+}
+
+impl FromStr for Turn {
+    type Err = Error;
+
+    /// Parses a turn in the notation `crate=Y_G_G` or `crate/10202`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{notation::Turn, Error};
+    /// let turn: Turn = "crate/10202".parse().unwrap();
+    ///
+    /// assert_eq!(turn, "CRATE=y-g-g".parse().unwrap());
+    /// assert_eq!(turn.to_string(), "crate=Y_G_G");
+    /// assert_eq!("crate/10302".parse::<Turn>(), Err(Error::InvalidDigitCode('3')));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidNotation(s.to_string());
+
+        // The guess may contain `=` and `/` itself (e.g. in Nerdle), but the code does not.
+        let (guess, code) = match s.rfind(['=', '/']) {
+            Some(i) if s[i..].starts_with('=') => {
+                let keep = format!("_{}", GRAY_ALIASES);
+                let code = normalize_input(&s[i + 1..], &keep);
+
+                (&s[..i], normalize_code(&code, GRAY_ALIASES)?)
+            }
+            Some(i) => {
+                let code = normalize_input(&s[i + 1..], "")
+                    .chars()
+                    .map(|d| match d {
+                        '2' => Ok('G'),
+                        '1' => Ok('Y'),
+                        '0' => Ok('_'),
+                        d => Err(Error::InvalidDigitCode(d)),
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                (&s[..i], code)
+            }
+            None => return Err(invalid()),
+        };
+
+        // `=` and `/` are kept in the guess for Nerdle.
+        let guess = normalize_input(guess, nerdle::SYMBOLS);
+
+        if guess.is_empty() || code.is_empty() {
+            return Err(invalid());
+        }
+
+        let len = guess.chars().count();
+
+        if code.chars().count() != len {
+            return Err(Error::IncorrectColorCodeLength { input: code, len });
+        }
+
+        let constraints = ConstraintSet::try_from((guess.as_str(), code.as_str()))?;
+
+        Ok(Self {
+            guess,
+            code,
+            constraints,
+        })
+    }
+}
+
+impl From<&ConstraintSet> for Turn {
+    fn from(constraints: &ConstraintSet) -> Self {
+        Self {
+            guess: constraints.word(),
+            code: constraints.code(),
+            constraints: constraints.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Turn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.guess, self.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        input,
+        expected,
+        case("crate=Y_G_G", Ok(("crate", "Y_G_G"))),
+        case("crate=ybgxg", Ok(("crate", "Y_G_G"))),
+        case("Crate/10202", Ok(("crate", "Y_G_G"))),
+        case("nerdle/222222", Ok(("nerdle", "GGGGGG"))),
+        case("8/4+1=03=GGGGGGGG", Ok(("8/4+1=03", "GGGGGGGG"))),
+        case("8/4+1=03/22222222", Ok(("8/4+1=03", "GGGGGGGG"))),
+        case(" c r a t e = y _ g _ g ", Ok(("crate", "Y_G_G"))),
+        case("crate!/10202.", Ok(("crate", "Y_G_G"))),
+        case("crate=Y_G_", Err(Error::IncorrectColorCodeLength { input: "Y_G_".to_string(), len: 5 })),
+        case("crate/1020", Err(Error::IncorrectColorCodeLength { input: "Y_G_".to_string(), len: 5 })),
+        case("crate=Y_Q_G", Err(Error::InvalidColorCode { input: 'Q', gray_aliases: GRAY_ALIASES.to_string() })),
+        case("crate/10203", Err(Error::InvalidDigitCode('3'))),
+        case("crate", Err(Error::InvalidNotation("crate".to_string()))),
+        case("=Y_G_G", Err(Error::InvalidNotation("=Y_G_G".to_string())))
+    )]
+    fn test_from_str(input: &str, expected: Result<(&str, &str), Error>) {
+        let turn = input.parse::<Turn>();
+
+        assert_eq!(
+            turn.as_ref().map(|t| (t.guess(), t.code())),
+            expected.as_ref().map(|(g, c)| (*g, *c))
+        );
+    }
+}
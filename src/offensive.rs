@@ -0,0 +1,39 @@
+//! Words that should not be suggested in a family-friendly setting.
+//!
+//! Some valid guesses and even a few answers of the original Wordle are vulgar or offensive,
+//! which is awkward when `prompter` is used in a classroom or on a stream. The words are
+//! only left out of the suggestions: they may still be the mystery word, so they are never
+//! removed from the candidates.
+use crate::Word;
+
+/// Vulgar or offensive words, sorted alphabetically.
+pub const WORDS: &[&str] = &[
+    "bitch", "bitchy", "boner", "boobs", "booby", "crotch", "dildo", "dildos", "erotic", "gypsy",
+    "hooker", "horny", "hussy", "kinky", "lynch", "nipple", "orgasm", "penis", "porno", "prick",
+    "pubic", "pussy", "rapist", "semen", "sexed", "slave", "sluts", "slutty", "sperm", "titty",
+    "wanker", "wench", "whore", "whores",
+];
+
+/// Returns `true` if `word` should not be suggested in a family-friendly setting.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{offensive, Word};
+/// assert!(offensive::is_offensive(&Word::from("wench")));
+/// assert!(!offensive::is_offensive(&Word::from("crate")));
+/// ```
+pub fn is_offensive(word: &Word) -> bool {
+    WORDS.binary_search(&&*word.0).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words() {
+        assert!(WORDS.windows(2).all(|w| w[0] < w[1]));
+        assert!(WORDS.iter().all(|w| is_offensive(&Word::from(*w))));
+    }
+}
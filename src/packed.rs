@@ -0,0 +1,316 @@
+//! Loading very large wordlists with bounded memory.
+//!
+//! Dictionaries of other languages or generated candidates (e.g. for Nerdle) can have
+//! hundreds of thousands of words. Instead of reading all lines into strings first, the
+//! [`Loader`] cleans up and validates one word at a time, keeps only the words of the
+//! requested length and packs each of them into a single `u128`: every symbol is stored as
+//! its position in the alphabet with as few bits as the alphabet needs (5 bits for the
+//! letters `a` to `z`, so words with up to 25 letters fit).
+use std::{
+    collections::{BTreeSet, HashSet},
+    io::BufRead,
+};
+
+use crate::{alphabet::Alphabet, Cleanup, Error, Word, Wordlist};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Words of the same length, each packed into a `u128`
+pub struct PackedWordlist {
+    /// The symbols of the alphabet, in the order of their numbers.
+    symbols: Vec<char>,
+    /// Number of bits per symbol.
+    bits: u32,
+    /// Number of symbols per word.
+    word_len: usize,
+    /// The packed words, the first symbol in the most significant bits.
+    words: Vec<u128>,
+}
+
+impl PackedWordlist {
+    /// Returns an empty list for words with `word_len` symbols of `alphabet`, or an error if
+    /// such words do not fit into a `u128`.
+    pub fn new(alphabet: &Alphabet, word_len: usize) -> Result<Self, Error> {
+        let symbols: Vec<char> = alphabet.symbols().chars().collect();
+        let bits = usize::BITS - symbols.len().saturating_sub(1).leading_zeros();
+
+        if word_len == 0 || bits.max(1) as usize * word_len > u128::BITS as usize {
+            return Err(Error::UnsupportedWordLength(word_len));
+        }
+
+        Ok(Self {
+            symbols,
+            bits: bits.max(1),
+            word_len,
+            words: vec![],
+        })
+    }
+
+    /// Reads a wordlist with one word per line and keeps the words with `word_len` symbols
+    /// (see [`Loader`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{alphabet::Alphabet, packed::PackedWordlist, Cleanup};
+    /// let (words, cleanup) =
+    ///     PackedWordlist::from_reader("Crate\ntrace\ncrate\nchoice\n".as_bytes(), &Alphabet::LATIN, 5)
+    ///         .unwrap();
+    ///
+    /// assert_eq!(words.iter().map(|w| w.to_string()).collect::<Vec<_>>(), ["crate", "trace"]);
+    /// assert_eq!(cleanup, Cleanup { normalized: 1, blank: 0, duplicates: 1 });
+    /// ```
+    pub fn from_reader<R: BufRead>(
+        mut reader: R,
+        alphabet: &Alphabet,
+        word_len: usize,
+    ) -> Result<(Self, Cleanup), Error> {
+        let mut loader = Loader::new(alphabet, word_len)?;
+        let mut line = String::new();
+
+        loop {
+            line.clear();
+
+            let read = reader
+                .read_line(&mut line)
+                .map_err(|e| Error::UnreadableWordlist {
+                    path: "-".to_string(),
+                    reason: e.to_string(),
+                })?;
+
+            if read == 0 {
+                break;
+            }
+
+            loader.push(line.trim_end_matches(['\n', '\r']));
+        }
+
+        loader.finish()
+    }
+
+    /// Returns the number of words in the list.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns `true` if no words are in the list.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the number of symbols per word.
+    pub fn word_len(&self) -> usize {
+        self.word_len
+    }
+
+    /// Returns `word` packed into a `u128`, or `None` if it does not have the length of the
+    /// words in the list or contains symbols that are not in the alphabet.
+    fn pack(&self, word: &str) -> Option<u128> {
+        let mut packed = 0;
+        let mut len = 0;
+
+        for c in word.chars() {
+            let i = self.symbols.iter().position(|&s| s == c)?;
+            packed = packed << self.bits | i as u128;
+            len += 1;
+        }
+
+        (len == self.word_len).then_some(packed)
+    }
+
+    /// Returns the word packed into `packed`.
+    fn unpack(&self, packed: u128) -> Word {
+        let mask = (1 << self.bits) - 1;
+
+        let word: String = (0..self.word_len)
+            .rev()
+            .map(|i| self.symbols[(packed >> (i as u32 * self.bits) & mask) as usize])
+            .collect();
+
+        Word::from(word)
+    }
+
+    /// Returns `true` if `word` is in the list.
+    pub fn contains(&self, word: &str) -> bool {
+        self.pack(word)
+            .is_some_and(|packed| self.words.contains(&packed))
+    }
+
+    /// Returns an iterator over the words in the list.
+    pub fn iter(&self) -> impl Iterator<Item = Word> + '_ {
+        self.words.iter().map(|&packed| self.unpack(packed))
+    }
+
+    /// Sorts the words alphabetically.
+    pub fn sort(&mut self) {
+        let words = std::mem::take(&mut self.words);
+
+        self.words = words
+            .into_iter()
+            .map(|packed| (self.unpack(packed), packed))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|(_, packed)| packed)
+            .collect();
+    }
+}
+
+impl From<PackedWordlist> for Wordlist {
+    fn from(packed: PackedWordlist) -> Self {
+        packed.iter().collect()
+    }
+}
+
+#[derive(Debug)]
+/// Cleans up, validates and packs the words of a wordlist one at a time
+///
+/// Like [`Wordlist::clean`], the loader trims and lowercases the words, skips blank lines
+/// and duplicates and collects all invalid words with their line numbers. Words with a
+/// different length are left out, but their lengths are remembered for the error message
+/// if no word has the requested length.
+pub struct Loader<'a> {
+    /// The alphabet that the words are validated against.
+    alphabet: &'a Alphabet,
+    /// The words that have been kept so far.
+    words: PackedWordlist,
+    /// The packed words that have been kept so far, to find duplicates.
+    seen: HashSet<u128>,
+    /// What has been cleaned up so far.
+    cleanup: Cleanup,
+    /// Invalid words and their line numbers.
+    invalid: Vec<(usize, String)>,
+    /// Lengths of the valid words.
+    lengths: BTreeSet<usize>,
+    /// Number of lines pushed so far.
+    line: usize,
+}
+
+impl<'a> Loader<'a> {
+    /// Returns a loader for words with `word_len` symbols of `alphabet`.
+    pub fn new(alphabet: &'a Alphabet, word_len: usize) -> Result<Self, Error> {
+        Ok(Self {
+            alphabet,
+            words: PackedWordlist::new(alphabet, word_len)?,
+            seen: HashSet::new(),
+            cleanup: Cleanup::default(),
+            invalid: vec![],
+            lengths: BTreeSet::new(),
+            line: 0,
+        })
+    }
+
+    /// Adds the word in the next line of the wordlist.
+    pub fn push(&mut self, line: &str) {
+        self.line += 1;
+
+        let word = self.alphabet.normalize(line.trim());
+
+        if word != line {
+            self.cleanup.normalized += 1;
+        }
+
+        if word.is_empty() {
+            self.cleanup.blank += 1;
+            return;
+        }
+
+        if self.alphabet.validate(&word).is_err() {
+            self.invalid.push((self.line, word));
+            return;
+        }
+
+        self.lengths.insert(word.chars().count());
+
+        if let Some(packed) = self.words.pack(&word) {
+            if self.seen.insert(packed) {
+                self.words.words.push(packed);
+            } else {
+                self.cleanup.duplicates += 1;
+            }
+        }
+    }
+
+    /// Returns the words with the requested length and what has been cleaned up, or an
+    /// error if a word was invalid or no word has the requested length.
+    pub fn finish(self) -> Result<(PackedWordlist, Cleanup), Error> {
+        if !self.invalid.is_empty() {
+            return Err(Error::InvalidWordlistEntries(self.invalid));
+        }
+
+        if self.lengths.is_empty() {
+            return Err(Error::EmptyWordlist);
+        }
+
+        if self.words.is_empty() {
+            return Err(Error::NoWordsOfLength {
+                len: self.words.word_len,
+                available: self.lengths.into_iter().collect(),
+            });
+        }
+
+        Ok((self.words, self.cleanup))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preset::Preset;
+
+    fn load(input: &str, word_len: usize) -> Result<(PackedWordlist, Cleanup), Error> {
+        PackedWordlist::from_reader(input.as_bytes(), &Alphabet::LATIN, word_len)
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let wordlist = Preset::Wordle.wordlist();
+        let input = wordlist
+            .iter()
+            .map(|w| format!("{}\n", w))
+            .collect::<String>();
+
+        let (packed, cleanup) = load(&input, 5).unwrap();
+
+        assert!(cleanup.is_empty());
+        assert_eq!(packed.len(), wordlist.len());
+        assert!(packed.iter().eq(wordlist.iter().cloned()));
+        assert!(packed.contains("crate"));
+        assert!(!packed.contains("craate"));
+    }
+
+    #[test]
+    fn test_nerdle() {
+        let alphabet = Preset::Nerdle.alphabet();
+        let (mut packed, _) =
+            PackedWordlist::from_reader("12+46=58\n10-2*3=4\n".as_bytes(), &alphabet, 8).unwrap();
+
+        packed.sort();
+
+        assert_eq!(
+            packed.iter().map(|w| w.to_string()).collect::<Vec<_>>(),
+            ["10-2*3=4", "12+46=58"]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            load("crate\nchoice\n", 7).err(),
+            Some(Error::NoWordsOfLength {
+                len: 7,
+                available: vec![5, 6]
+            })
+        );
+        assert_eq!(
+            load("crate\n\ntr4ce\nw0rd\n", 5).err(),
+            Some(Error::InvalidWordlistEntries(vec![
+                (3, "tr4ce".to_string()),
+                (4, "w0rd".to_string())
+            ]))
+        );
+        assert_eq!(load("\n \n", 5).err(), Some(Error::EmptyWordlist));
+        assert_eq!(
+            PackedWordlist::new(&Alphabet::LATIN, 26).err(),
+            Some(Error::UnsupportedWordLength(26))
+        );
+    }
+}
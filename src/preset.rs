@@ -0,0 +1,246 @@
+//! Presets for the different variants of Wordle.
+use std::{fmt, str::FromStr};
+
+use crate::{alphabet::Alphabet, nerdle, primel, Word, Wordlist};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// A variant of Wordle that determines the word length, the number of rounds and the
+/// wordlist
+pub enum Preset {
+    #[default]
+    /// The original Wordle with 5-letter words and 6 rounds.
+    Wordle,
+    /// Wordle with 6-letter words and 7 rounds.
+    Wordle6,
+    /// Wordle with 7-letter words and 8 rounds.
+    Wordle7,
+    /// Nerdle, where an equation with 8 symbols has to be guessed in 6 rounds.
+    Nerdle,
+    /// Primel, where a prime number with 5 digits has to be guessed in 6 rounds.
+    Primel,
+}
+
+impl Preset {
+    /// All available presets.
+    pub const ALL: [Preset; 5] = [
+        Preset::Wordle,
+        Preset::Wordle6,
+        Preset::Wordle7,
+        Preset::Nerdle,
+        Preset::Primel,
+    ];
+
+    /// Returns the name used to select the preset.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Preset::Wordle => "wordle",
+            Preset::Wordle6 => "wordle6",
+            Preset::Wordle7 => "wordle7",
+            Preset::Nerdle => "nerdle",
+            Preset::Primel => "primel",
+        }
+    }
+
+    /// Returns the name of the game, e.g. for the header of the share grid.
+    pub fn title(&self) -> &'static str {
+        match self {
+            Preset::Wordle | Preset::Wordle6 | Preset::Wordle7 => "Wordle",
+            Preset::Nerdle => "Nerdle",
+            Preset::Primel => "Primel",
+        }
+    }
+
+    /// Returns the length of the words to be guessed.
+    pub fn word_len(&self) -> usize {
+        match self {
+            Preset::Wordle | Preset::Primel => 5,
+            Preset::Wordle6 => 6,
+            Preset::Wordle7 => 7,
+            Preset::Nerdle => 8,
+        }
+    }
+
+    /// Returns the number of rounds to play.
+    pub fn rounds(&self) -> usize {
+        self.rounds_for(self.word_len())
+    }
+
+    /// Returns the number of rounds to play with words of `word_len` characters, e.g. from
+    /// a wordlist loaded instead of the preset's wordlist.
+    pub fn rounds_for(&self, word_len: usize) -> usize {
+        match self {
+            Preset::Nerdle | Preset::Primel => 6,
+            _ => word_len + 1,
+        }
+    }
+
+    /// Returns the preset of the same game with words of `word_len` characters, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::preset::Preset;
+    /// assert_eq!(Preset::Wordle.with_word_len(6), Some(Preset::Wordle6));
+    /// assert_eq!(Preset::Primel.with_word_len(6), None);
+    /// ```
+    pub fn with_word_len(&self, word_len: usize) -> Option<Preset> {
+        Preset::ALL
+            .into_iter()
+            .find(|preset| preset.title() == self.title() && preset.word_len() == word_len)
+    }
+
+    /// Returns the symbols that guesses consist of and the rule they have to satisfy.
+    pub fn alphabet(&self) -> Alphabet {
+        match self {
+            Preset::Nerdle => Alphabet::new(nerdle::SYMBOLS, nerdle::is_valid_equation),
+            Preset::Primel => Alphabet::new(primel::SYMBOLS, primel::is_valid_prime),
+            _ => Alphabet::LATIN,
+        }
+    }
+
+    /// Returns the wordlist of the preset. The lists of words are embedded, the lists of
+    /// equations for Nerdle and primes for Primel are generated.
+    pub fn wordlist(&self) -> Wordlist {
+        let words = match self {
+            Preset::Wordle => include_str!("words.txt"),
+            Preset::Wordle6 => include_str!("words6.txt"),
+            Preset::Wordle7 => include_str!("words7.txt"),
+            Preset::Nerdle => return nerdle::equations(self.word_len()),
+            Preset::Primel => return primel::primes(self.word_len()),
+        };
+
+        words.lines().map(Word::from).collect()
+    }
+
+    /// Returns the wordlist of the preset in the revision `list`. Only the answers of the
+    /// original Wordle have been revised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::preset::{AnswerList, Preset};
+    /// let classic = Preset::Wordle.answers(AnswerList::Classic);
+    /// let nyt = Preset::Wordle.answers(AnswerList::Nyt);
+    ///
+    /// assert!(classic.contains("fibre"));
+    /// assert!(!nyt.contains("fibre"));
+    /// ```
+    pub fn answers(&self, list: AnswerList) -> Wordlist {
+        match (self, list) {
+            (Preset::Wordle, AnswerList::Nyt) => self
+                .wordlist()
+                .into_iter()
+                .filter(|w| !NYT_REMOVED.contains(&w.to_string().as_str()))
+                .collect(),
+            _ => self.wordlist(),
+        }
+    }
+}
+
+/// Words of the original list of answers that the NYT removed after taking over Wordle
+/// ("slave" is missing from the embedded list anyway).
+const NYT_REMOVED: [&str; 5] = ["agora", "fibre", "lynch", "pupal", "wench"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// A revision of the list of answers
+pub enum AnswerList {
+    #[default]
+    /// The answers of the original Wordle before the NYT took it over.
+    Classic,
+    /// The answers curated by the NYT, without words that it considered offensive,
+    /// obscure or British spellings.
+    Nyt,
+}
+
+impl AnswerList {
+    /// All available revisions.
+    pub const ALL: [AnswerList; 2] = [AnswerList::Classic, AnswerList::Nyt];
+
+    /// Returns the name used to select the revision.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AnswerList::Classic => "classic",
+            AnswerList::Nyt => "nyt",
+        }
+    }
+}
+
+impl FromStr for AnswerList {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AnswerList::ALL
+            .into_iter()
+            .find(|list| list.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = AnswerList::ALL.iter().map(|l| l.name()).collect();
+                format!("unknown list '{}' (available: {})", s, names.join(", "))
+            })
+    }
+}
+
+impl fmt::Display for AnswerList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Preset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Preset::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = Preset::ALL.iter().map(|p| p.name()).collect();
+                format!("unknown preset '{}' (available: {})", s, names.join(", "))
+            })
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        preset,
+        case(Preset::Wordle),
+        case(Preset::Wordle6),
+        case(Preset::Wordle7),
+        case(Preset::Nerdle),
+        case(Preset::Primel)
+    )]
+    fn test_wordlist(preset: Preset) {
+        let wordlist = preset.wordlist();
+
+        assert!(!wordlist.is_empty());
+        assert!(wordlist
+            .iter()
+            .all(|w| w.chars().count() == preset.word_len()));
+        assert!(wordlist
+            .iter()
+            .all(|w| preset.alphabet().validate(&w.to_string()).is_ok()));
+    }
+
+    #[test]
+    fn test_answers() {
+        let classic = Preset::Wordle.answers(AnswerList::Classic);
+        let nyt = Preset::Wordle.answers(AnswerList::Nyt);
+
+        assert_eq!(classic.len() - nyt.len(), NYT_REMOVED.len());
+        assert_eq!(
+            Preset::Wordle6.answers(AnswerList::Nyt).len(),
+            Preset::Wordle6.wordlist().len()
+        );
+        assert_eq!("NYT".parse(), Ok(AnswerList::Nyt));
+    }
+}
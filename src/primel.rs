@@ -0,0 +1,72 @@
+//! Primes for [Primel](https://converged.yt/primel/), the Wordle variant in which the
+//! mystery "word" is a prime number like `12347`.
+use crate::{Word, Wordlist};
+
+/// Symbols that can be used in a number.
+pub const SYMBOLS: &str = "0123456789";
+
+/// Returns `true` if `n` is a prime number.
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+/// Returns `true` if `s` is a prime number without leading zeros.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::primel::is_valid_prime;
+/// assert!(is_valid_prime("12347"));
+/// assert!(!is_valid_prime("12345"));
+/// assert!(!is_valid_prime("02347"));
+/// ```
+pub fn is_valid_prime(s: &str) -> bool {
+    !s.starts_with('0') && s.parse().is_ok_and(is_prime)
+}
+
+/// Generates all prime numbers with `len` digits.
+pub fn primes(len: usize) -> Wordlist {
+    let len = len as u32;
+    let min = if len > 1 { 10_u64.pow(len - 1) } else { 0 };
+    let max = 10_u64.pow(len);
+
+    (min..max)
+        .filter(|n| is_prime(*n))
+        .map(|n| Word::from(n.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(
+        input,
+        valid,
+        case("12347", true),
+        case("10007", true),
+        case("99991", true),
+        case("12345", false),
+        case("+1234", false),
+        case("1", false),
+        case("2", true)
+    )]
+    fn test_is_valid_prime(input: &str, valid: bool) {
+        assert_eq!(is_valid_prime(input), valid);
+    }
+
+    #[test]
+    fn test_primes() {
+        let primes = primes(5);
+
+        assert_eq!(primes.len(), 8363);
+        assert!(primes.contains("10007"));
+        assert!(primes.iter().all(|w| is_valid_prime(&w.to_string())));
+    }
+}
@@ -0,0 +1,231 @@
+//! Prior probabilities of the words being the mystery word.
+//!
+//! By default, each word is equally likely to be the mystery word. When a large dictionary
+//! is used instead of a list of likely answers, common words should be preferred over
+//! obscure ones. A frequency file assigns a weight to each word, and the probability of a
+//! word is its weight divided by the total weight of the candidates.
+//!
+//! The weights of some words can also be multiplied by a factor, e.g. to make the words in
+//! a list of likely answers more probable than words that are only valid guesses, or the
+//! answers of previous games less probable.
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+    sync::Arc,
+};
+#[cfg(feature = "std-fs")]
+use std::{fs, path::Path};
+
+use crate::{alphabet::lowercase_word, Error, Word, Wordlist};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// The weight of each word, uniform unless loaded from a frequency file
+pub struct Prior {
+    /// Weights of the words in the frequency file.
+    weights: HashMap<String, f64>,
+    /// Weight of the words that are not in the frequency file.
+    default: Option<f64>,
+    /// Sets of words and the factors by which their weights are multiplied.
+    factors: Vec<(HashSet<Arc<str>>, f64)>,
+}
+
+impl Prior {
+    /// Returns the prior in which each word is equally likely.
+    pub fn uniform() -> Self {
+        Self::default()
+    }
+
+    /// Reads a frequency file with one word and its weight per line, separated by
+    /// whitespace or a comma. Empty lines and lines starting with `#` are skipped. Words
+    /// that are not in the file get the smallest weight in the file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{prior::Prior, Word};
+    /// let prior = Prior::from_reader("crate 120\ntrace,30\n".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(prior.weight(&Word::from("crate")), 120.0);
+    /// assert_eq!(prior.weight(&Word::from("react")), 30.0);
+    /// assert!(Prior::from_reader("crate lots\n".as_bytes()).is_err());
+    /// ```
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, Error> {
+        let mut weights = HashMap::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| Error::UnreadableFrequencies {
+                path: "-".to_string(),
+                reason: e.to_string(),
+            })?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || Error::InvalidFrequencyEntry {
+                line: i + 1,
+                entry: line.to_string(),
+            };
+
+            let (word, weight) = line
+                .split_once(|c: char| c == ',' || c.is_whitespace())
+                .ok_or_else(invalid)?;
+            let weight: f64 = weight
+                .trim_matches(|c: char| c == ',' || c.is_whitespace())
+                .parse()
+                .map_err(|_| invalid())?;
+
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(invalid());
+            }
+
+            weights.insert(lowercase_word(word), weight);
+        }
+
+        Ok(Self::from_weights(weights))
+    }
+
+    /// Returns the prior with the given weights of the words, e.g. from the frequencies in
+    /// an extended wordlist (see [`Metadata::prior`](crate::metadata::Metadata::prior)).
+    /// Words without a weight get the smallest weight.
+    pub fn from_weights<I: IntoIterator<Item = (String, f64)>>(weights: I) -> Self {
+        let weights: HashMap<_, _> = weights.into_iter().collect();
+        let default = weights.values().copied().reduce(f64::min);
+
+        Self {
+            weights,
+            default,
+            factors: vec![],
+        }
+    }
+
+    /// Multiplies the weight of the words in `words` by `factor`, e.g. so that words from a
+    /// list of likely answers are preferred over words that are only valid guesses. A
+    /// factor of 1 leaves the prior unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{prior::Prior, Word, Wordlist};
+    /// let answers: Wordlist = ["crate"].iter().map(Word::from).collect();
+    /// let prior = Prior::uniform().with_factor(&answers, 4.0);
+    ///
+    /// assert_eq!(prior.weight(&Word::from("crate")), 4.0);
+    /// assert_eq!(prior.weight(&Word::from("craic")), 1.0);
+    /// ```
+    pub fn with_factor(mut self, words: &Wordlist, factor: f64) -> Self {
+        if factor != 1.0 {
+            let words = words.iter().map(|w| w.0.clone()).collect();
+            self.factors.push((words, factor));
+        }
+
+        self
+    }
+
+    /// Returns `true` if each word is equally likely.
+    pub fn is_uniform(&self) -> bool {
+        self.default.is_none() && self.factors.is_empty()
+    }
+
+    /// Returns the weight of `word`.
+    pub fn weight(&self, word: &Word) -> f64 {
+        let weight = match self.default {
+            Some(default) => self.weights.get(&*word.0).copied().unwrap_or(default),
+            None => 1.0,
+        };
+
+        self.factors
+            .iter()
+            .filter(|(words, _)| words.contains(&*word.0))
+            .fold(weight, |weight, (_, factor)| weight * factor)
+    }
+
+    /// Returns the probability of each word in `wordlist` being the mystery word, in the
+    /// order of the wordlist. If all words have weight 0, they are equally likely.
+    pub fn probabilities<'a>(&self, wordlist: &'a Wordlist) -> Vec<(&'a Word, f64)> {
+        let total: f64 = wordlist.iter().map(|w| self.weight(w)).sum();
+
+        wordlist
+            .iter()
+            .map(|w| {
+                if total > 0.0 {
+                    (w, self.weight(w) / total)
+                } else {
+                    (w, 1.0 / wordlist.len() as f64)
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "std-fs")]
+impl Prior {
+    /// Loads a frequency file (see [`Prior::from_reader`]) from `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| Error::UnreadableFrequencies {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+
+        Self::from_reader(content.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probabilities() {
+        let wordlist: Wordlist = ["crate", "trace", "react"].iter().map(Word::from).collect();
+
+        let uniform = Prior::uniform();
+        assert!(uniform.is_uniform());
+        assert!(uniform
+            .probabilities(&wordlist)
+            .iter()
+            .all(|(_, p)| (p - 1.0 / 3.0).abs() < 1e-9));
+
+        let prior = Prior::from_reader("# word weight\nCRATE 3\n\ntrace 1\n".as_bytes()).unwrap();
+        let probabilities: Vec<_> = prior
+            .probabilities(&wordlist)
+            .into_iter()
+            .map(|(_, p)| p)
+            .collect();
+        assert_eq!(probabilities, [0.6, 0.2, 0.2]);
+
+        assert_eq!(
+            Prior::from_reader("crate 1\ntrace -1\n".as_bytes()),
+            Err(Error::InvalidFrequencyEntry {
+                line: 2,
+                entry: "trace -1".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_factor() {
+        let wordlist: Wordlist = ["crate", "craic", "trace"].iter().map(Word::from).collect();
+        let answers: Wordlist = ["crate", "trace"].iter().map(Word::from).collect();
+
+        assert!(Prior::uniform().with_factor(&answers, 1.0).is_uniform());
+
+        let prior = Prior::from_reader(
+            "crate 2
+craic 1
+trace 1
+"
+            .as_bytes(),
+        )
+        .unwrap()
+        .with_factor(&answers, 3.0);
+        let probabilities: Vec<_> = prior
+            .probabilities(&wordlist)
+            .into_iter()
+            .map(|(_, p)| p)
+            .collect();
+        assert_eq!(probabilities, [0.6, 0.1, 0.3]);
+    }
+}
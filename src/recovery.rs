@@ -0,0 +1,116 @@
+//! Finding the most likely typo in the feedback when no word is left.
+//!
+//! If no word matches the feedback of all rounds, a color has most likely been entered
+//! wrongly. The near misses are the words that would match again if a single color in one
+//! round were different. Each such change is a [`Fix`], and the fix that brings back the
+//! most words is the most likely correction.
+use crate::{feedback::Feedback, ConstraintSet, Wordlist};
+
+/// The colors that a mistyped color may have been meant to be.
+const COLORS: [Feedback; 3] = [Feedback::Green, Feedback::Yellow, Feedback::Gray];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A change of a single color in the feedback of one round
+pub struct Fix {
+    /// Index of the round in the history.
+    pub round: usize,
+    /// Position of the changed color in the word.
+    pub position: usize,
+    /// The constraints of the round with the changed color.
+    pub constraints: ConstraintSet,
+    /// Number of words that match all rounds after the change.
+    pub candidates: usize,
+}
+
+/// Returns the changes of a single color in `history` after which words of `wordlist`
+/// match all rounds again, the fixes that bring back the most words first (and among
+/// them, those of later rounds first). Changes that Wordle could never show (see
+/// [`ConstraintSet::validate`]) or that would make a guess correct are left out.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{recovery, ConstraintSet, Word, Wordlist};
+/// let wordlist: Wordlist = ["horse", "spoon", "trace"].iter().map(Word::from).collect();
+/// let history = [
+///     ConstraintSet::try_from(("crate", "_Y__G")).unwrap(),
+///     // The `s` of "horse" should have been yellow.
+///     ConstraintSet::try_from(("sport", "G_YY_")).unwrap(),
+/// ];
+///
+/// let fixes = recovery::fixes(&wordlist, &history);
+/// assert_eq!((fixes[0].round, fixes[0].position), (1, 0));
+/// assert_eq!(fixes[0].constraints.code(), "Y_YY_");
+/// assert_eq!(fixes[0].candidates, 1);
+/// ```
+pub fn fixes(wordlist: &Wordlist, history: &[ConstraintSet]) -> Vec<Fix> {
+    let mut fixes = vec![];
+
+    for (round, constraints) in history.iter().enumerate() {
+        let word = constraints.word();
+        let feedback = constraints.feedback();
+
+        for (position, &color) in feedback.iter().enumerate() {
+            for other in COLORS.into_iter().filter(|&c| c != color) {
+                let mut changed = feedback.clone();
+                changed[position] = other;
+
+                let fixed = ConstraintSet::from_feedback(&word, changed);
+
+                if fixed.correct_word() || fixed.validate().is_err() {
+                    continue;
+                }
+
+                let candidates = wordlist
+                    .iter()
+                    .filter(|w| {
+                        history
+                            .iter()
+                            .enumerate()
+                            .all(|(i, c)| if i == round { &fixed } else { c }.is_match(w))
+                    })
+                    .count();
+
+                if candidates > 0 {
+                    fixes.push(Fix {
+                        round,
+                        position,
+                        constraints: fixed,
+                        candidates,
+                    });
+                }
+            }
+        }
+    }
+
+    fixes.sort_by_key(|fix| (std::cmp::Reverse((fix.candidates, fix.round)), fix.position));
+
+    fixes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Word;
+
+    #[test]
+    fn test_fixes() {
+        let wordlist: Wordlist = ["horse", "spoon", "trace"].iter().map(Word::from).collect();
+
+        // The "e" of "horse" should have been green.
+        let history = [ConstraintSet::try_from(("crate", "_Y__Y")).unwrap()];
+        let found: Vec<_> = fixes(&wordlist, &history)
+            .iter()
+            .map(|fix| (fix.position, fix.constraints.code(), fix.candidates))
+            .collect();
+        assert_eq!(found, [(4, "_Y__G".to_string(), 1)]);
+
+        // No single color brings back a word.
+        let history = [ConstraintSet::try_from(("zzzzz", "GGGG_")).unwrap()];
+        assert_eq!(fixes(&wordlist, &history), []);
+
+        // "trace" would be correct, which would have ended the game.
+        let history = [ConstraintSet::try_from(("trace", "GGGG_")).unwrap()];
+        assert_eq!(fixes(&wordlist, &history), []);
+    }
+}
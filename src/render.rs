@@ -0,0 +1,216 @@
+//! Rendering of guesses and their color codes for the terminal.
+#[cfg(not(target_arch = "wasm32"))]
+use crossterm::style::Stylize;
+
+use crate::{feedback::Feedback, Constraint, ConstraintSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The way in which Wordle's feedback is presented
+pub enum Style {
+    /// Letters are shown on a green, yellow or gray background, like on the Wordle site.
+    #[default]
+    Color,
+    /// Letters are marked with symbols only: `[A]` = Green, `(a)` = Yellow, `.a.` = Gray.
+    /// Usable on monochrome terminals, by colorblind users and with screen readers.
+    Ascii,
+}
+
+/// Returns a string representing the guess encoded in `constraints` together with
+/// Wordle's feedback in the given `style`.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{render::{render, Style}, ConstraintSet};
+/// let constraints = ConstraintSet::try_from(("crate", "Y_G_G")).unwrap();
+///
+/// assert_eq!(render(&constraints, Style::Ascii), "(c) .r. [A] .t. [E]");
+/// ```
+pub fn render(constraints: &ConstraintSet, style: Style) -> String {
+    // Without a terminal, colors cannot be shown.
+    let style = if cfg!(target_arch = "wasm32") {
+        Style::Ascii
+    } else {
+        style
+    };
+
+    let cells: Vec<_> = constraints
+        .iter()
+        .map(|constraint| match *constraint {
+            Constraint::AtPos(_, c) | Constraint::NotAtPos(_, c) | Constraint::Absent(c) => {
+                render_char(c, constraint.feedback(), style)
+            }
+        })
+        .collect();
+
+    match style {
+        Style::Color => cells.concat(),
+        Style::Ascii => cells.join(" "),
+    }
+}
+
+/// Returns a string representing the whole board, i.e. all guesses in `history` with
+/// their feedback, one row per round.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{render::{render_board, Style}, ConstraintSet};
+/// let history = vec![
+///     ConstraintSet::try_from(("trace", "G_Y__")).unwrap(),
+///     ConstraintSet::try_from(("today", "GGGGG")).unwrap(),
+/// ];
+///
+/// assert_eq!(
+///     render_board(&history, Style::Ascii),
+///     "[T] .r. (a) .c. .e.\n[T] [O] [D] [A] [Y]"
+/// );
+/// ```
+pub fn render_board(history: &[ConstraintSet], style: Style) -> String {
+    history
+        .iter()
+        .map(|constraints| render(constraints, style))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the spoiler-free emoji grid of a game for sharing, like Wordle's share button:
+/// a header with `title`, the number of rounds needed (`X` if the game was lost) and
+/// `rounds`, followed by one row of colored squares per guess in `history`. An asterisk
+/// marks games played in hard mode.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{render::share, ConstraintSet};
+/// let history = vec![
+///     ConstraintSet::try_from(("trace", "G_Y__")).unwrap(),
+///     ConstraintSet::try_from(("today", "GGGGG")).unwrap(),
+/// ];
+///
+/// assert_eq!(share("Wordle", &history, 6, false), "Wordle 2/6\n\n🟩⬛🟨⬛⬛\n🟩🟩🟩🟩🟩");
+/// assert_eq!(share("Wordle", &history[..1], 1, true), "Wordle X/1*\n\n🟩⬛🟨⬛⬛");
+/// ```
+pub fn share(title: &str, history: &[ConstraintSet], rounds: usize, hard: bool) -> String {
+    let score = match history.last() {
+        Some(last) if last.correct_word() => history.len().to_string(),
+        _ => "X".to_string(),
+    };
+
+    let rows: Vec<String> = history
+        .iter()
+        .map(|constraints| {
+            constraints
+                .iter()
+                .map(|constraint| constraint.feedback().emoji())
+                .collect()
+        })
+        .collect();
+
+    format!(
+        "{} {}/{}{}\n\n{}",
+        title,
+        score,
+        rounds,
+        if hard { "*" } else { "" },
+        rows.join("\n")
+    )
+}
+
+/// Maximum number of characters of a bar in [`render_distribution`].
+const BAR_WIDTH: usize = 30;
+
+/// Returns the guess distribution like in Wordle's statistics: one row per number of
+/// rounds with a bar whose length is proportional to the number of games won in that many
+/// rounds (`distribution[0]` being the games won in 1 round). The bar of the number of
+/// rounds in `highlight`, e.g. that of the last game, is green, or marked with `<` without
+/// colors.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::render::{render_distribution, Style};
+/// assert_eq!(
+///     render_distribution(&[0, 1, 2], Some(2), Style::Ascii),
+///     "1 # 0\n2 ############### 1 <\n3 ############################## 2"
+/// );
+/// ```
+pub fn render_distribution(
+    distribution: &[usize],
+    highlight: Option<usize>,
+    style: Style,
+) -> String {
+    // Without a terminal, colors cannot be shown.
+    let style = if cfg!(target_arch = "wasm32") {
+        Style::Ascii
+    } else {
+        style
+    };
+
+    let most = distribution
+        .iter()
+        .copied()
+        .max()
+        .unwrap_or_default()
+        .max(1);
+    let label_width = distribution.len().to_string().len();
+
+    distribution
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            let rounds = i + 1;
+            let width = (count * BAR_WIDTH / most).max(1);
+            let highlighted = highlight == Some(rounds);
+
+            let bar = match style {
+                #[cfg(not(target_arch = "wasm32"))]
+                Style::Color => {
+                    // Like on the Wordle site, the count is shown at the end of the bar.
+                    let bar = format!(" {:>width$} ", count, width = width);
+
+                    if highlighted {
+                        bar.black().on_green().bold().to_string()
+                    } else {
+                        bar.white().on_dark_grey().bold().to_string()
+                    }
+                }
+                _ => format!(
+                    "{} {}{}",
+                    "#".repeat(width),
+                    count,
+                    if highlighted { " <" } else { "" }
+                ),
+            };
+
+            format!(
+                "{:>label_width$} {}",
+                rounds,
+                bar,
+                label_width = label_width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders a single character `c` with its `color`.
+fn render_char(c: char, color: Feedback, style: Style) -> String {
+    match style {
+        #[cfg(not(target_arch = "wasm32"))]
+        Style::Color => {
+            let cell = format!(" {} ", c.to_ascii_uppercase());
+
+            match color {
+                Feedback::Green => cell.black().on_green().bold().to_string(),
+                Feedback::Yellow => cell.black().on_yellow().bold().to_string(),
+                Feedback::Gray => cell.white().on_dark_grey().bold().to_string(),
+            }
+        }
+        _ => match color {
+            Feedback::Green => format!("[{}]", c.to_ascii_uppercase()),
+            Feedback::Yellow => format!("({})", c.to_ascii_lowercase()),
+            Feedback::Gray => format!(".{}.", c.to_ascii_lowercase()),
+        },
+    }
+}
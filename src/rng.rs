@@ -0,0 +1,72 @@
+//! A small seedable random number generator for reproducible games and simulations.
+//!
+//! The generator (SplitMix64) is not suitable for cryptography, but it is fast, has no
+//! dependencies and gives the same numbers for the same seed on every platform and in
+//! every version, so that a run can be repeated exactly.
+
+/// Scrambles the bits of `x` (SplitMix64), so that similar inputs give unrelated outputs.
+pub fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A random number generator that gives the same sequence of numbers for the same seed
+pub struct Rng {
+    /// State of the generator, advanced by a constant for each number.
+    state: u64,
+}
+
+impl Rng {
+    /// Returns a generator that starts with `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next random number.
+    pub fn next_u64(&mut self) -> u64 {
+        let x = self.state;
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        splitmix64(x)
+    }
+
+    /// Returns a random number smaller than `n`, or 0 if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::rng::Rng;
+    /// let mut rng = Rng::new(42);
+    /// let dice: Vec<_> = (0..100).map(|_| rng.below(6)).collect();
+    ///
+    /// assert!(dice.iter().all(|&n| n < 6));
+    /// assert_eq!(dice, (0..100).map({ let mut rng = Rng::new(42); move |_| rng.below(6) }).collect::<Vec<_>>());
+    /// assert_eq!(rng.below(0), 0);
+    /// ```
+    pub fn below(&mut self, n: usize) -> usize {
+        // Multiplying instead of taking the remainder keeps the numbers evenly distributed.
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(0);
+
+        assert_eq!(rng.next_u64(), splitmix64(0));
+        assert_ne!(rng.next_u64(), splitmix64(0));
+
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            counts[rng.below(3)] += 1;
+        }
+        assert!(counts.iter().all(|&n| n > 900));
+    }
+}
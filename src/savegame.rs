@@ -0,0 +1,129 @@
+//! Interrupted games, saved to a TOML file with `:save FILE` to be continued later with
+//! `prompter play --resume FILE`.
+//!
+//! The file contains the settings that decide which words are candidates and the turns
+//! played so far:
+//!
+//! ```toml
+//! preset = "wordle"
+//! len = 5
+//! list = "classic"
+//! spelling = "both"
+//! hard = true
+//! turns = ["crate=Y_G_G", "spoil=___G_"]
+//! ```
+use std::{fmt, fs, path::Path, str::FromStr};
+
+use prompter::{
+    notation::Turn,
+    preset::{AnswerList, Preset},
+    spelling::Spelling,
+};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+/// The state of an interrupted game
+pub struct SavedGame {
+    /// Variant of Wordle.
+    #[serde(serialize_with = "display", deserialize_with = "from_str")]
+    pub preset: Preset,
+    /// Number of characters per word.
+    pub len: usize,
+    /// Revision of Wordle's answers.
+    #[serde(serialize_with = "display", deserialize_with = "from_str")]
+    pub list: AnswerList,
+    /// Spelling of the words.
+    #[serde(serialize_with = "display", deserialize_with = "from_str")]
+    pub spelling: Spelling,
+    /// Whether only guesses that match all previous hints are accepted.
+    pub hard: bool,
+    /// The guesses and their color codes.
+    #[serde(serialize_with = "display_all", deserialize_with = "from_str_all")]
+    pub turns: Vec<Turn>,
+}
+
+impl SavedGame {
+    /// Loads the game saved at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Saves the game to `path`, replacing the file if it exists.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), String> {
+        let path = path.as_ref();
+        let content = toml::to_string(self).map_err(|e| e.to_string())?;
+
+        fs::write(path, content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+/// Serializes a value as a string with its `Display` implementation.
+fn display<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    serializer.collect_str(value)
+}
+
+/// Serializes a list of values as strings with their `Display` implementation.
+fn display_all<S, T>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    serializer.collect_seq(values.iter().map(T::to_string))
+}
+
+/// Deserializes a value from a string with its `FromStr` implementation.
+fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let s = String::deserialize(deserializer)?;
+
+    s.parse().map_err(de::Error::custom)
+}
+
+/// Deserializes a list of values from strings with their `FromStr` implementation.
+fn from_str_all<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|s| s.parse().map_err(de::Error::custom))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let game = SavedGame {
+            preset: Preset::Wordle6,
+            len: 6,
+            list: AnswerList::Classic,
+            spelling: Spelling::British,
+            hard: true,
+            turns: vec!["planet=Y_G__G".parse().unwrap()],
+        };
+
+        let content = toml::to_string(&game).unwrap();
+
+        assert!(content.contains("preset = \"wordle6\""));
+        assert!(content.contains("turns = [\"planet=Y_G__G\"]"));
+        assert_eq!(toml::from_str::<SavedGame>(&content).unwrap(), game);
+        assert!(toml::from_str::<SavedGame>(&content.replace("Y_G__G", "Y_Q__G")).is_err());
+    }
+}
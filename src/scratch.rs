@@ -0,0 +1,172 @@
+//! Reusable buffers for scoring guesses.
+//!
+//! Ranking the words for a guess sorts the candidates into the buckets of each word, i.e.
+//! thousands of times for the first guess. A [`Scratch`] holds the buffers for this, so
+//! that they are allocated once per ranking (or simulated game) instead of once per word.
+use std::{collections::HashMap, hash::Hash, ops::AddAssign};
+
+use crate::feedback::{FeedbackRule, Pattern};
+
+/// Maximum length of the words whose buckets are kept in an array rather than in a map.
+/// The array then has (3⁹ - 1) / 2 = 9841 entries, one for each pattern of up to 8
+/// characters.
+pub const MAX_ARRAY_LEN: usize = 8;
+
+#[derive(Debug, Clone)]
+/// A value for each bucket, e.g. the number of words in it, that is summed up while the
+/// words are sorted into the buckets of their codes `C` (see [`FeedbackRule`])
+pub struct Buckets<T, C = Pattern> {
+    /// The value of each bucket whose code has an index (see [`FeedbackRule::index`]), at
+    /// that index.
+    array: Vec<Option<T>>,
+    /// Indexes of the buckets in `array` that are not empty, in the order of their first
+    /// word.
+    used: Vec<usize>,
+    /// The value of each bucket whose code has no index.
+    map: HashMap<C, T>,
+}
+
+impl<T, C> Default for Buckets<T, C> {
+    fn default() -> Self {
+        Self {
+            array: vec![],
+            used: vec![],
+            map: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Copy + Default + AddAssign, C: Eq + Hash> Buckets<T, C> {
+    /// Empties all buckets, but keeps the memory for the next guess.
+    pub fn clear(&mut self) {
+        for &i in &self.used {
+            self.array[i] = None;
+        }
+
+        self.used.clear();
+        self.map.clear();
+    }
+
+    /// Adds `value` to the bucket of `code`, a code of `rule`, and returns the new value of
+    /// the bucket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{feedback::{FeedbackRule, WordleRule}, scratch::Buckets, Word};
+    /// let guess = Word::from("hatch");
+    /// let mut buckets = Buckets::default();
+    ///
+    /// for (target, sum) in [("batch", 1), ("hatch", 1), ("catch", 2)] {
+    ///     let code = WordleRule.code(&guess, &Word::from(target));
+    ///     assert_eq!(buckets.add(&WordleRule, code, 1), sum);
+    /// }
+    ///
+    /// assert_eq!(buckets.len(), 2);
+    /// ```
+    pub fn add<R: FeedbackRule<Code = C>>(&mut self, rule: &R, code: C, value: T) -> T {
+        let Some(i) = rule.index(&code) else {
+            let sum = self.map.entry(code).or_default();
+            *sum += value;
+            return *sum;
+        };
+
+        if i >= self.array.len() {
+            self.array.resize(i + 1, None);
+        }
+
+        let sum = self.array[i].get_or_insert_with(|| {
+            self.used.push(i);
+            T::default()
+        });
+        *sum += value;
+
+        *sum
+    }
+
+    /// Returns the number of buckets that are not empty.
+    pub fn len(&self) -> usize {
+        self.used.len() + self.map.len()
+    }
+
+    /// Returns `true` if all buckets are empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the values of the buckets that are not empty.
+    pub fn values(&self) -> impl Iterator<Item = T> + '_ {
+        self.used
+            .iter()
+            .filter_map(|&i| self.array[i])
+            .chain(self.map.values().copied())
+    }
+}
+
+/// Returns the index of a pattern in the array, or `None` if it has more than
+/// [`MAX_ARRAY_LEN`] characters. The patterns of each length follow those of the shorter
+/// ones, so that patterns of different lengths never share a bucket.
+pub(crate) fn index(pattern: Pattern) -> Option<usize> {
+    if pattern.len() > MAX_ARRAY_LEN {
+        return None;
+    }
+
+    let shorter = (3usize.pow(pattern.len() as u32) - 1) / 2;
+
+    Some(shorter + pattern.number() as usize)
+}
+
+#[derive(Debug, Clone)]
+/// The buffers used to score guesses with a [`FeedbackRule`] whose codes are `C`
+pub struct Scratch<C = Pattern> {
+    /// Number of words in each bucket.
+    pub counts: Buckets<usize, C>,
+    /// Probability of each bucket.
+    pub weights: Buckets<f64, C>,
+    /// Values of the buckets in the order in which they are summed up.
+    pub sorted: Vec<f64>,
+}
+
+impl<C> Default for Scratch<C> {
+    fn default() -> Self {
+        Self {
+            counts: Buckets::default(),
+            weights: Buckets::default(),
+            sorted: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        feedback::{Feedback, WordleRule},
+        Word,
+    };
+
+    #[test]
+    fn test_buckets() {
+        let mut buckets = Buckets::default();
+
+        // Patterns of different lengths and long patterns have buckets of their own.
+        let short: Pattern = [Feedback::Green].into_iter().collect();
+        let longer: Pattern = [Feedback::Gray, Feedback::Green].into_iter().collect();
+        let long = Word::from("abcdefghij").pattern(&Word::from("jihgfedcba"));
+
+        for _ in 0..3 {
+            for pattern in [short, longer, long] {
+                buckets.add(&WordleRule, pattern, 1);
+            }
+        }
+
+        assert_eq!(buckets.add(&WordleRule, long, 1), 4);
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(buckets.values().collect::<Vec<_>>(), [3, 3, 4]);
+
+        buckets.clear();
+        assert!(buckets.is_empty());
+        assert_eq!(buckets.add(&WordleRule, short, 2), 2);
+        assert_eq!(buckets.values().collect::<Vec<_>>(), [2]);
+    }
+}
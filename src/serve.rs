@@ -0,0 +1,162 @@
+//! HTTP server that exposes the solver to web frontends and bots.
+//!
+//! * `POST /suggest` takes the guesses and color codes played so far and returns the
+//!   number of candidates left and the best-ranked words.
+//! * `GET /buckets/{word}` returns the buckets in which the words are sorted for `word`.
+use std::{collections::BTreeMap, net::SocketAddr, sync::Arc};
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use prompter::{
+    alphabet::lowercase_word,
+    feedback::{FeedbackRule, WordleRule},
+    preset::Preset,
+    ConstraintSet, Error, Word, Wordlist,
+};
+use serde::{Deserialize, Serialize};
+
+/// Number of suggestions returned if the request does not specify it.
+const DEFAULT_LIMIT: usize = 10;
+
+#[derive(Deserialize)]
+struct Turn {
+    guess: String,
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct SuggestRequest {
+    #[serde(default)]
+    history: Vec<Turn>,
+    limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct Suggestion {
+    word: String,
+    score: usize,
+}
+
+#[derive(Serialize)]
+struct SuggestResponse {
+    candidates: usize,
+    suggestions: Vec<Suggestion>,
+}
+
+#[derive(Serialize)]
+struct BucketsResponse {
+    word: String,
+    buckets: BTreeMap<String, Vec<String>>,
+}
+
+/// An error that is returned as `400 Bad Request` with a JSON body.
+struct ApiError(String);
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        Self(error.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({ "error": self.0 });
+        (StatusCode::BAD_REQUEST, Json(body)).into_response()
+    }
+}
+
+struct AppState {
+    preset: Preset,
+    wordlist: Wordlist,
+}
+
+/// Starts the server on `addr` and serves requests until the process is stopped.
+pub fn serve(addr: SocketAddr, preset: Preset) -> std::io::Result<()> {
+    let state = Arc::new(AppState {
+        preset,
+        wordlist: preset.wordlist(),
+    });
+
+    let app = Router::new()
+        .route("/suggest", post(suggest))
+        .route("/buckets/:word", get(buckets))
+        .with_state(state);
+
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        println!("Listening on http://{}", listener.local_addr()?);
+        axum::serve(listener, app).await
+    })
+}
+
+async fn suggest(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<SuggestRequest>,
+) -> Result<Json<SuggestResponse>, ApiError> {
+    let mut wordlist = state.wordlist.clone();
+
+    for turn in &request.history {
+        let len = state.preset.word_len();
+
+        if turn.guess.chars().count() != len {
+            let input = turn.guess.clone();
+            return Err(Error::IncorrectWordLength { input, len }.into());
+        }
+
+        if turn.code.chars().count() != len {
+            let input = turn.code.clone();
+            return Err(Error::IncorrectColorCodeLength { input, len }.into());
+        }
+
+        let constraints = ConstraintSet::try_from((turn.guess.as_str(), turn.code.as_str()))?;
+        constraints.validate()?;
+        wordlist = wordlist.filter(&constraints).collect();
+        wordlist.remove(&lowercase_word(&turn.guess));
+    }
+
+    let suggestions = wordlist
+        .rank_words()
+        .take(request.limit.unwrap_or(DEFAULT_LIMIT))
+        .map(|(w, score)| Suggestion {
+            word: w.to_string(),
+            score,
+        })
+        .collect();
+
+    Ok(Json(SuggestResponse {
+        candidates: wordlist.len(),
+        suggestions,
+    }))
+}
+
+async fn buckets(
+    State(state): State<Arc<AppState>>,
+    Path(word): Path<String>,
+) -> Result<Json<BucketsResponse>, ApiError> {
+    let len = state.preset.word_len();
+
+    if word.chars().count() != len {
+        return Err(Error::IncorrectWordLength { input: word, len }.into());
+    }
+
+    let word = Word::from(lowercase_word(&word));
+
+    let buckets = WordleRule
+        .buckets(&word, &state.wordlist)
+        .into_iter()
+        .map(|(code, words)| {
+            let words = words.iter().map(|w| w.to_string()).collect();
+            (code.to_string(), words)
+        })
+        .collect();
+
+    Ok(Json(BucketsResponse {
+        word: word.to_string(),
+        buckets,
+    }))
+}
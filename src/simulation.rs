@@ -0,0 +1,274 @@
+//! Simulated games in which the solver plays against a known target word.
+//!
+//! The functions in this module only compute the course of a game. Presenting it is left
+//! to the caller, so they can be used without a terminal.
+use crate::{
+    feedback::Feedback, prior::Prior, scratch::Scratch, strategy::Strategy, ConstraintSet, Error,
+    Word, Wordlist,
+};
+
+#[derive(Debug, Clone)]
+/// One round of a simulated game
+pub struct Round {
+    /// Number of words that were possible at the start of the round.
+    pub candidates: usize,
+    /// The word guessed in the round.
+    pub guess: Word,
+    /// Wordle's feedback for the guess.
+    pub feedback: Vec<Feedback>,
+    /// The constraints that follow from the color code.
+    pub constraints: ConstraintSet,
+}
+
+#[derive(Debug, Clone, Default)]
+/// The course and result of a simulated game
+pub struct Game {
+    /// All rounds played.
+    pub rounds: Vec<Round>,
+    /// Number of candidates left after the last round.
+    pub candidates_left: usize,
+    /// The score of the game, i.e. the number of rounds needed to guess the target word or
+    /// the number of rounds survived, or `None` if the game was lost.
+    pub score: Option<usize>,
+}
+
+impl Game {
+    /// Returns the feedback of all rounds.
+    pub fn history(&self) -> Vec<ConstraintSet> {
+        self.rounds.iter().map(|r| r.constraints.clone()).collect()
+    }
+
+    fn play(
+        &mut self,
+        wordlist: &mut Wordlist,
+        guess: &Word,
+        target: &Word,
+    ) -> Result<bool, Error> {
+        let len = target.chars().count();
+
+        if guess.chars().count() != len {
+            return Err(Error::IncorrectWordLength {
+                input: guess.to_string(),
+                len,
+            });
+        }
+
+        let constraints = ConstraintSet::from_guess(guess, target);
+        let correct = constraints.correct_word();
+        let candidates = wordlist.len();
+
+        *wordlist = Wordlist::from_iter(std::mem::take(wordlist).filter(&constraints));
+        wordlist.remove(&guess.to_string());
+
+        self.rounds.push(Round {
+            candidates,
+            guess: guess.clone(),
+            feedback: constraints.feedback(),
+            constraints,
+        });
+        self.candidates_left = wordlist.len();
+
+        Ok(correct)
+    }
+}
+
+/// Returns an error if `target` is not in `wordlist`, because the solver could never find it.
+fn check_target(wordlist: &Wordlist, target: &Word) -> Result<(), Error> {
+    if !wordlist.contains(&target.0) {
+        return Err(Error::UnknownWord {
+            input: target.to_string(),
+            suggestions: vec![],
+        });
+    }
+
+    Ok(())
+}
+
+/// Simulates a game that starts with `start` and continues with the best-ranked candidate
+/// in each round until `target` is found or all `rounds` are used up. The score is the
+/// number of rounds needed. Returns an error if `start` and `target` differ in length or
+/// if `target` is not in `wordlist`.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{preset::Preset, simulation, Word};
+/// let wordlist = Preset::Wordle.wordlist();
+/// let game = simulation::solve(&wordlist, &Word::from("crate"), &Word::from("horse"), 6);
+///
+/// assert_eq!(game.unwrap().score, Some(3));
+/// ```
+pub fn solve(
+    wordlist: &Wordlist,
+    start: &Word,
+    target: &Word,
+    rounds: usize,
+) -> Result<Game, Error> {
+    solve_with(
+        wordlist,
+        start,
+        target,
+        rounds,
+        Strategy::Solve,
+        &Prior::uniform(),
+    )
+}
+
+/// Simulates a game like [`solve`], but continues with the candidate that `strategy`
+/// ranks best with the probabilities from `prior` in each round.
+pub fn solve_with(
+    wordlist: &Wordlist,
+    start: &Word,
+    target: &Word,
+    rounds: usize,
+    strategy: Strategy,
+    prior: &Prior,
+) -> Result<Game, Error> {
+    check_target(wordlist, target)?;
+
+    let mut wordlist = wordlist.clone();
+    let mut game = Game::default();
+    let mut guess = start.clone();
+    let mut scratch = Scratch::default();
+
+    for i in 1..=rounds {
+        if game.play(&mut wordlist, &guess, target)? {
+            game.score = Some(i);
+            break;
+        }
+
+        match strategy.best_guess_with(&wordlist, &wordlist, prior, &mut scratch) {
+            Some((best, _)) => guess = best.clone(),
+            None => break,
+        }
+    }
+
+    Ok(game)
+}
+
+/// Simulates a Survivle game that starts with `start` and continues with the candidate
+/// that keeps the most words possible until `target` has to be guessed. The score is the
+/// number of rounds survived. Returns an error if `start` and `target` differ in length or
+/// if `target` is not in `wordlist`.
+pub fn survive(wordlist: &Wordlist, start: &Word, target: &Word) -> Result<Game, Error> {
+    check_target(wordlist, target)?;
+
+    let mut wordlist = wordlist.clone();
+    let mut game = Game::default();
+    let mut guess = start.clone();
+    let mut scratch = Scratch::default();
+
+    for i in 1.. {
+        if game.play(&mut wordlist, &guess, target)? {
+            game.score = Some(i - 1);
+            break;
+        }
+
+        match Strategy::Survive.best_guess_with(
+            &wordlist,
+            &wordlist,
+            &Prior::uniform(),
+            &mut scratch,
+        ) {
+            Some((best, _)) => guess = best.clone(),
+            None => break,
+        }
+    }
+
+    Ok(game)
+}
+
+/// Lets `strategy` solve every word of `wordlist` like [`solve_with`], starting with
+/// `start`, and returns the words that it does not find within `rounds` rounds together
+/// with their games.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{prior::Prior, simulation, strategy::Strategy, Word, Wordlist};
+/// let wordlist: Wordlist = ["crate", "trace", "react", "cater"].iter().map(Word::from).collect();
+/// let start = Word::from("crate");
+/// let prior = Prior::uniform();
+///
+/// // The feedback for "crate" tells the other words apart.
+/// assert!(simulation::verify(&wordlist, &start, 2, Strategy::Solve, &prior).unwrap().is_empty());
+/// assert_eq!(simulation::verify(&wordlist, &start, 1, Strategy::Solve, &prior).unwrap().len(), 3);
+/// ```
+pub fn verify(
+    wordlist: &Wordlist,
+    start: &Word,
+    rounds: usize,
+    strategy: Strategy,
+    prior: &Prior,
+) -> Result<Vec<(Word, Game)>, Error> {
+    let mut failures = vec![];
+
+    for target in wordlist.iter() {
+        let game = solve_with(wordlist, start, target, rounds, strategy, prior)?;
+
+        if game.score.is_none() {
+            failures.push((target.clone(), game));
+        }
+    }
+
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let wordlist: Wordlist = ["crate", "trace", "spoon"].iter().map(Word::from).collect();
+        let (start, target) = (Word::from("spoon"), Word::from("crate"));
+
+        let game = solve(&wordlist, &start, &target, 6).unwrap();
+
+        assert_eq!(game.rounds.len(), 2);
+        assert_eq!(game.rounds[0].candidates, 3);
+        assert_eq!(game.score, Some(2));
+
+        let game = solve(&wordlist, &start, &target, 1).unwrap();
+
+        assert_eq!(game.score, None);
+        assert_eq!(game.candidates_left, 2);
+
+        assert_eq!(
+            solve(&wordlist, &Word::from("spoons"), &target, 6).unwrap_err(),
+            Error::IncorrectWordLength {
+                input: "spoons".to_string(),
+                len: 5
+            }
+        );
+        assert_eq!(
+            solve(&wordlist, &start, &Word::from("aaaaa"), 6).unwrap_err(),
+            Error::UnknownWord {
+                input: "aaaaa".to_string(),
+                suggestions: vec![]
+            }
+        );
+    }
+
+    #[test]
+    fn test_solve_with() {
+        let wordlist: Wordlist = ["crate", "trace", "spoon"].iter().map(Word::from).collect();
+        let (start, target) = (Word::from("spoon"), Word::from("trace"));
+
+        for strategy in Strategy::ALL {
+            let game =
+                solve_with(&wordlist, &start, &target, 6, strategy, &Prior::uniform()).unwrap();
+            assert!(game.score.is_some());
+        }
+    }
+
+    #[test]
+    fn test_survive() {
+        let wordlist: Wordlist = ["crate", "trace", "spoon"].iter().map(Word::from).collect();
+
+        let game = survive(&wordlist, &Word::from("spoon"), &Word::from("trace")).unwrap();
+
+        assert_eq!(game.history().len(), game.rounds.len());
+        assert!(game.score.is_some());
+    }
+}
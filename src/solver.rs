@@ -0,0 +1,202 @@
+//! A solver that keeps track of the candidate words during a game.
+use crate::{
+    alphabet::lowercase_word,
+    feedback::{FeedbackRule, WordleRule},
+    prior::Prior,
+    strategy::Strategy,
+    ConstraintSet, Error, Word, Wordlist,
+};
+
+#[derive(Debug, Clone)]
+/// Keeps track of the words that are still possible after the feedback of each round and
+/// suggests the next guesses. The feedback is Wordle's unless another [`FeedbackRule`] is
+/// given.
+pub struct Solver<R: FeedbackRule = WordleRule> {
+    /// The rule that determines the feedback.
+    rule: R,
+    /// Length of the words in the game.
+    word_len: usize,
+    /// Words that are still possible.
+    candidates: Wordlist,
+    /// Wordle's feedback for all guesses played so far.
+    history: Vec<ConstraintSet>,
+    /// Whether the last guess was the mystery word.
+    solved: bool,
+}
+
+impl Solver {
+    /// Creates a solver in which the mystery word is one of the words in `wordlist`, which
+    /// all have `word_len` characters.
+    pub fn new(wordlist: Wordlist, word_len: usize) -> Self {
+        Self::with_rule(wordlist, word_len, WordleRule)
+    }
+
+    /// Returns Wordle's feedback for all guesses played so far with [`Solver::apply`].
+    pub fn history(&self) -> &[ConstraintSet] {
+        &self.history
+    }
+
+    /// Applies Wordle's color `code` (e.g. `Y_G_G`) for `guess` and removes all candidates
+    /// that do not match it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{preset::Preset, solver::Solver};
+    /// let mut solver = Solver::new(Preset::Wordle.wordlist(), 5);
+    ///
+    /// solver.apply("crate", "_Y__G").unwrap();
+    /// solver.apply("horse", "_YY_G").unwrap();
+    ///
+    /// assert_eq!(solver.candidates().len(), 1);
+    /// assert_eq!(
+    ///     solver.apply("crate", "_Y_").unwrap_err().to_string(),
+    ///     "Color code must be 5 characters long, but '_Y_' has 3"
+    /// );
+    /// assert_eq!(
+    ///     solver.apply("übel", "_Y__").unwrap_err().to_string(),
+    ///     "Word must be 5 characters long, but 'übel' has 4"
+    /// );
+    /// assert!(solver.apply("spell", "____Y").is_err());
+    /// ```
+    pub fn apply(&mut self, guess: &str, code: &str) -> Result<(), Error> {
+        if guess.chars().count() != self.word_len {
+            return Err(Error::IncorrectWordLength {
+                input: guess.to_string(),
+                len: self.word_len,
+            });
+        }
+
+        if code.chars().count() != self.word_len {
+            return Err(Error::IncorrectColorCodeLength {
+                input: code.to_string(),
+                len: self.word_len,
+            });
+        }
+
+        let constraints = ConstraintSet::try_from((guess, code))?;
+        constraints.validate()?;
+
+        let candidates = std::mem::take(&mut self.candidates);
+        self.candidates = candidates.filter(&constraints).collect();
+        self.solved = constraints.correct_word();
+
+        if !self.solved {
+            self.candidates.remove(&lowercase_word(guess));
+        }
+
+        self.history.push(constraints);
+
+        Ok(())
+    }
+}
+
+impl<R: FeedbackRule> Solver<R> {
+    /// Creates a solver like [`Solver::new`] for a game whose feedback is determined by
+    /// `rule`.
+    pub fn with_rule(wordlist: Wordlist, word_len: usize, rule: R) -> Self {
+        Self {
+            rule,
+            word_len,
+            candidates: wordlist,
+            history: vec![],
+            solved: false,
+        }
+    }
+
+    /// Returns the words that are still possible.
+    pub fn candidates(&self) -> &Wordlist {
+        &self.candidates
+    }
+
+    /// Returns `true` if the last guess was the mystery word.
+    pub fn is_solved(&self) -> bool {
+        self.solved
+    }
+
+    /// Applies the feedback `code` of the solver's rule for `guess` and removes all
+    /// candidates that would have got a different code. Unlike [`Solver::apply`], the guess
+    /// is not checked and not added to the [`history`](Solver::history).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{feedback::FeedbackRule, solver::Solver, Word, Wordlist};
+    /// /// Only reveals the characters at the correct position.
+    /// struct GreenOnly;
+    ///
+    /// impl FeedbackRule for GreenOnly {
+    ///     type Code = Vec<bool>;
+    ///
+    ///     fn code(&self, guess: &Word, target: &Word) -> Vec<bool> {
+    ///         guess.chars().zip(target.chars()).map(|(c1, c2)| c1 == c2).collect()
+    ///     }
+    /// }
+    ///
+    /// let wordlist: Wordlist = ["crate", "trace", "spoon"].iter().map(Word::from).collect();
+    /// let mut solver = Solver::with_rule(wordlist, 5, GreenOnly);
+    ///
+    /// solver.apply_code(&Word::from("crate"), &vec![false, true, true, false, true]);
+    ///
+    /// assert_eq!(solver.suggest(10), [(&Word::from("trace"), 1)]);
+    /// assert!(!solver.is_solved());
+    /// ```
+    pub fn apply_code(&mut self, guess: &Word, code: &R::Code) {
+        let candidates = std::mem::take(&mut self.candidates);
+        self.candidates = self.rule.filter(candidates, guess, code);
+        self.solved = code == &self.rule.code(guess, guess);
+
+        if !self.solved {
+            self.candidates.remove(&guess.to_string());
+        }
+    }
+
+    /// Returns the `n` best-ranked candidates and their scores (see
+    /// [`FeedbackRule::rank_words`]).
+    pub fn suggest(&self, n: usize) -> Vec<(&Word, usize)> {
+        self.suggest_with(n, Strategy::Solve, &Prior::uniform())
+    }
+
+    /// Returns the `n` candidates ranked best by `strategy` with the probabilities from
+    /// `prior` and their scores (see [`Strategy::rank_with`]).
+    pub fn suggest_with(&self, n: usize, strategy: Strategy, prior: &Prior) -> Vec<(&Word, usize)> {
+        let mut ranked =
+            strategy.rank_guesses_by(&self.rule, &self.candidates, &self.candidates, prior);
+        ranked.truncate(n);
+        ranked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GRAY_ALIASES;
+
+    #[test]
+    fn test_apply() {
+        let wordlist: Wordlist = ["crate", "trace", "spoon"].iter().map(Word::from).collect();
+        let mut solver = Solver::new(wordlist, 5);
+
+        solver.apply("crate", "YGGYG").unwrap();
+
+        assert_eq!(solver.candidates().len(), 1);
+        assert_eq!(solver.suggest(10)[0].0, &Word::from("trace"));
+        assert_eq!(
+            solver.suggest_with(10, Strategy::Minimax, &Prior::uniform()),
+            solver.suggest(10)
+        );
+        assert!(!solver.is_solved());
+
+        solver.apply("trace", "GGGGG").unwrap();
+
+        assert!(solver.is_solved());
+        assert_eq!(solver.history().len(), 2);
+        assert_eq!(
+            solver.apply("crate", "YGGQG"),
+            Err(Error::InvalidColorCode {
+                input: 'Q',
+                gray_aliases: GRAY_ALIASES.to_string()
+            })
+        );
+    }
+}
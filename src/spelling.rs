@@ -0,0 +1,168 @@
+//! British and American spellings of the same words.
+//!
+//! Wordle clones for different audiences use different dictionaries: the original Wordle
+//! had both "fibre" and "favor" as answers, while a British clone would rather use
+//! "favour" and an American one "fiber". The spellings are taken from a list of pairs, so
+//! words that are spelled the same in both variants (or whose other spelling is a different
+//! word, like "tire" and "tyre") are never removed.
+use std::{fmt, str::FromStr};
+
+use crate::Wordlist;
+
+/// Pairs of British and American spellings of the same word.
+pub const PAIRS: &[(&str, &str)] = &[
+    ("aeon", "eon"),
+    ("analyse", "analyze"),
+    ("ardour", "ardor"),
+    ("armour", "armor"),
+    ("axe", "ax"),
+    ("behaviour", "behavior"),
+    ("calibre", "caliber"),
+    ("candour", "candor"),
+    ("catalyse", "catalyze"),
+    ("centre", "center"),
+    ("clamour", "clamor"),
+    ("colour", "color"),
+    ("cosy", "cozy"),
+    ("defence", "defense"),
+    ("favour", "favor"),
+    ("fervour", "fervor"),
+    ("fibre", "fiber"),
+    ("flavour", "flavor"),
+    ("harbour", "harbor"),
+    ("honour", "honor"),
+    ("humour", "humor"),
+    ("jewellery", "jewelry"),
+    ("labour", "labor"),
+    ("litre", "liter"),
+    ("lustre", "luster"),
+    ("manoeuvre", "maneuver"),
+    ("meagre", "meager"),
+    ("mitre", "miter"),
+    ("mould", "mold"),
+    ("moult", "molt"),
+    ("neighbour", "neighbor"),
+    ("ochre", "ocher"),
+    ("odour", "odor"),
+    ("offence", "offense"),
+    ("paralyse", "paralyze"),
+    ("parlour", "parlor"),
+    ("plough", "plow"),
+    ("pretence", "pretense"),
+    ("pyjama", "pajama"),
+    ("rigour", "rigor"),
+    ("rumour", "rumor"),
+    ("sabre", "saber"),
+    ("saviour", "savior"),
+    ("savour", "savor"),
+    ("sceptic", "skeptic"),
+    ("sceptre", "scepter"),
+    ("smoulder", "smolder"),
+    ("sombre", "somber"),
+    ("spectre", "specter"),
+    ("splendour", "splendor"),
+    ("theatre", "theater"),
+    ("tumour", "tumor"),
+    ("valour", "valor"),
+    ("vapour", "vapor"),
+    ("vigour", "vigor"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// The spellings that the words of a game may use
+pub enum Spelling {
+    #[default]
+    /// Both British and American spellings, as in the original Wordle.
+    Both,
+    /// Only British spellings (e.g. "fibre" and "favour").
+    British,
+    /// Only American spellings (e.g. "fiber" and "favor").
+    American,
+}
+
+impl Spelling {
+    /// All available spellings.
+    pub const ALL: [Spelling; 3] = [Spelling::Both, Spelling::British, Spelling::American];
+
+    /// Returns the name used to select the spelling.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Spelling::Both => "both",
+            Spelling::British => "british",
+            Spelling::American => "american",
+        }
+    }
+
+    /// Returns `true` if `word` is spelled in the other variant.
+    pub fn excludes(&self, word: &str) -> bool {
+        match self {
+            Spelling::Both => false,
+            Spelling::British => PAIRS.iter().any(|(_, american)| *american == word),
+            Spelling::American => PAIRS.iter().any(|(british, _)| *british == word),
+        }
+    }
+
+    /// Returns the words in `wordlist` that are not spelled in the other variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{spelling::Spelling, Word, Wordlist};
+    /// let wordlist: Wordlist = ["fibre", "favor", "crate"].iter().map(Word::from).collect();
+    ///
+    /// assert!(Spelling::British.filter(wordlist.clone()).contains("fibre"));
+    /// assert!(!Spelling::British.filter(wordlist.clone()).contains("favor"));
+    /// assert_eq!(Spelling::American.filter(wordlist).len(), 2);
+    /// ```
+    pub fn filter(&self, wordlist: Wordlist) -> Wordlist {
+        if *self == Spelling::Both {
+            return wordlist;
+        }
+
+        wordlist
+            .into_iter()
+            .filter(|w| !self.excludes(&w.to_string()))
+            .collect()
+    }
+}
+
+impl FromStr for Spelling {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Spelling::ALL
+            .into_iter()
+            .find(|spelling| spelling.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = Spelling::ALL.iter().map(|s| s.name()).collect();
+                format!("unknown spelling '{}' (available: {})", s, names.join(", "))
+            })
+    }
+}
+
+impl fmt::Display for Spelling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs() {
+        assert!(PAIRS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(PAIRS.iter().all(|(british, american)| british != american));
+    }
+
+    #[test]
+    fn test_excludes() {
+        assert!(Spelling::British.excludes("color"));
+        assert!(!Spelling::British.excludes("colour"));
+        assert!(Spelling::American.excludes("colour"));
+        assert!(!Spelling::American.excludes("crate"));
+        assert!(!Spelling::Both.excludes("colour"));
+        assert_eq!("British".parse(), Ok(Spelling::British));
+    }
+}
@@ -0,0 +1,848 @@
+//! Persistent statistics about the games played with `prompter`.
+//!
+//! Every finished game of `play` is recorded with the time at which it was finished. Streaks
+//! are counted in days, which start at midnight UTC.
+//!
+//! # Format
+//!
+//! The stats file is an append-only log in the JSON Lines format. The first line is a
+//! header with the version of the format, and each following line records one game together
+//! with a checksum of the record:
+//!
+//! ```json
+//! {"format":"prompter-stats","version":1}
+//! {"record":{"timestamp":1700000000,"won":true,"total_ms":42195,"round_ms":[12000,30195],"speedrun":false,"puzzle":880},"checksum":"…"}
+//! ```
+//!
+//! * `total_ms` and `round_ms` are the times needed for the whole game and each round in
+//!   milliseconds, and `puzzle` is the number of the daily puzzle or `null`.
+//! * `checksum` is the 64-bit FNV-1a hash of the `record` exactly as written, in 16
+//!   lowercase hex digits.
+//!
+//! Records are never changed once they are written, so the stats can always be rebuilt from
+//! the log. A line that was only partially written, e.g. because `prompter` crashed, fails
+//! the checksum and is skipped without affecting the other records.
+//!
+//! Older versions of `prompter` wrote a CSV file with one record per line (see
+//! [`GameRecord`]'s `FromStr` implementation). Its records are copied to the log when the
+//! first game is appended to it.
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{self, BufRead},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+#[cfg(feature = "std-fs")]
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufReader, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+
+use crate::{cache::fnv1a64, daily};
+
+/// Name of the format in the header of the stats log.
+const FORMAT: &str = "prompter-stats";
+
+/// Version of the format of the stats log.
+pub const LOG_VERSION: u32 = 1;
+
+/// Maximum number of squares of a bar in [`Stats::share`].
+const SHARE_BAR_WIDTH: usize = 10;
+
+/// Number of seconds per day.
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Returns the number of seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Returns the number of the current day (see [`GameRecord::day`]).
+pub fn today() -> u64 {
+    now() / SECONDS_PER_DAY
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The record of a single game
+pub struct GameRecord {
+    /// Seconds since the Unix epoch at which the game was finished.
+    pub timestamp: u64,
+    /// `true` if the target word was found within the round limit.
+    pub won: bool,
+    /// Time needed for the whole game.
+    pub total: Duration,
+    /// Time needed for each round.
+    pub rounds: Vec<Duration>,
+    /// `true` if the game was played as a speedrun.
+    pub speedrun: bool,
+    /// Number of the daily puzzle that was played, if known (see [`crate::daily`]).
+    pub puzzle: Option<u64>,
+}
+
+impl GameRecord {
+    /// Creates a record for a game that has just been finished.
+    pub fn new(won: bool, speedrun: bool, total: Duration, rounds: Vec<Duration>) -> Self {
+        Self {
+            timestamp: now(),
+            won,
+            total,
+            rounds,
+            speedrun,
+            puzzle: None,
+        }
+    }
+
+    /// Returns the number of the day on which the game was finished, counted in days since
+    /// the Unix epoch.
+    pub fn day(&self) -> u64 {
+        self.timestamp / SECONDS_PER_DAY
+    }
+}
+
+/// The header line of the stats log
+#[derive(Serialize, Deserialize)]
+struct LogHeader {
+    format: String,
+    version: u32,
+}
+
+/// A game as it is stored in the stats log
+#[derive(Serialize, Deserialize)]
+struct LogRecord {
+    timestamp: u64,
+    won: bool,
+    total_ms: u64,
+    round_ms: Vec<u64>,
+    speedrun: bool,
+    puzzle: Option<u64>,
+}
+
+/// A line of the stats log. The record is kept exactly as written, so that the checksum is
+/// computed over the same bytes as when the line was written.
+#[derive(Deserialize)]
+struct LogLine<'a> {
+    #[serde(borrow)]
+    record: &'a RawValue,
+    checksum: String,
+}
+
+/// Returns the 64-bit FNV-1a hash of `s` in hex digits.
+fn checksum(s: &str) -> String {
+    format!("{:016x}", fnv1a64(s.as_bytes()))
+}
+
+impl GameRecord {
+    /// Returns the record as a line of the stats log (without the line break).
+    pub fn to_log_line(&self) -> String {
+        let record = LogRecord {
+            timestamp: self.timestamp,
+            won: self.won,
+            total_ms: self.total.as_millis() as u64,
+            round_ms: self.rounds.iter().map(|d| d.as_millis() as u64).collect(),
+            speedrun: self.speedrun,
+            puzzle: self.puzzle,
+        };
+        let json = serde_json::to_string(&record).expect("records can always be serialized");
+
+        format!(r#"{{"record":{},"checksum":"{}"}}"#, json, checksum(&json))
+    }
+
+    /// Parses a line of the stats log. Returns `None` if the line is damaged, i.e. it is no
+    /// valid JSON or the checksum does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::stats::GameRecord;
+    /// # use std::time::Duration;
+    /// let record = GameRecord::new(true, false, Duration::from_secs(30), vec![Duration::from_secs(30)]);
+    /// let line = record.to_log_line();
+    ///
+    /// assert_eq!(GameRecord::from_log_line(&line), Some(record));
+    /// assert_eq!(GameRecord::from_log_line(&line.replace("true", "false")), None);
+    /// assert_eq!(GameRecord::from_log_line(&line[..line.len() / 2]), None);
+    /// ```
+    pub fn from_log_line(line: &str) -> Option<Self> {
+        let LogLine {
+            record,
+            checksum: sum,
+        } = serde_json::from_str(line).ok()?;
+
+        if checksum(record.get()) != sum {
+            return None;
+        }
+
+        let record: LogRecord = serde_json::from_str(record.get()).ok()?;
+
+        Some(Self {
+            timestamp: record.timestamp,
+            won: record.won,
+            total: Duration::from_millis(record.total_ms),
+            rounds: record
+                .round_ms
+                .into_iter()
+                .map(Duration::from_millis)
+                .collect(),
+            speedrun: record.speedrun,
+            puzzle: record.puzzle,
+        })
+    }
+}
+
+impl fmt::Display for GameRecord {
+    /// Formats the record as a line of the CSV file written by older versions.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rounds = self
+            .rounds
+            .iter()
+            .map(|d| d.as_millis().to_string())
+            .collect::<Vec<_>>()
+            .join(";");
+
+        write!(
+            f,
+            "{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.won,
+            self.rounds.len(),
+            self.total.as_millis(),
+            rounds,
+            self.speedrun,
+            self.puzzle.map(|n| n.to_string()).unwrap_or_default()
+        )
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = io::Error;
+
+    /// Parses a line of the CSV file written by older versions, with the columns
+    /// `Timestamp,Won,Rounds,Total time (ms),Round times (ms),Speedrun,Puzzle`. Lines
+    /// without the speedrun column were written when
+    /// only speedruns were recorded, and those without the puzzle column before puzzles were
+    /// tagged.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid record '{}'", s),
+            )
+        };
+
+        let fields: Vec<_> = s.split(',').collect();
+
+        if !(5..=7).contains(&fields.len()) {
+            return Err(invalid());
+        }
+
+        let timestamp = fields[0].parse().map_err(|_| invalid())?;
+        let won = fields[1].parse().map_err(|_| invalid())?;
+        let total = fields[3].parse().map_err(|_| invalid())?;
+        let rounds = fields[4]
+            .split(';')
+            .filter(|ms| !ms.is_empty())
+            .map(|ms| ms.parse().map(Duration::from_millis))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid())?;
+        let speedrun = match fields.get(5) {
+            Some(speedrun) => speedrun.parse().map_err(|_| invalid())?,
+            None => true,
+        };
+        let puzzle = match fields.get(6) {
+            Some(n) if !n.is_empty() => Some(n.parse().map_err(|_| invalid())?),
+            _ => None,
+        };
+
+        Ok(Self {
+            timestamp,
+            won,
+            total: Duration::from_millis(total),
+            rounds,
+            speedrun,
+            puzzle,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Numbers of consecutive days on which a game was won
+pub struct Streaks {
+    /// The streak that ends today or yesterday, 0 if no game was won on either day.
+    pub current: usize,
+    /// The longest streak.
+    pub max: usize,
+}
+
+#[derive(Debug, Default)]
+/// The collection of all [`GameRecord`]s stored in the stats file
+pub struct Stats {
+    /// The records in the order in which they were written.
+    records: Vec<GameRecord>,
+    /// Number of damaged lines that were skipped.
+    skipped: usize,
+}
+
+impl Stats {
+    #[cfg(feature = "std-fs")]
+    /// Returns the default location of the stats file, or that of the given profile, so
+    /// that e.g. several players on the same machine keep separate stats.
+    pub fn default_path(profile: Option<&str>) -> Option<PathBuf> {
+        let dir = dirs::data_dir()?.join("prompter");
+
+        Some(match profile {
+            Some(name) => dir.join("profiles").join(name).join("stats.jsonl"),
+            None => dir.join("stats.jsonl"),
+        })
+    }
+
+    /// Reads the stats log (see the [module documentation](self)). Damaged lines are
+    /// skipped and counted (see [`Stats::skipped`]), but a missing or unknown header is an
+    /// error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::stats::{GameRecord, Stats};
+    /// # use std::time::Duration;
+    /// let record = GameRecord::new(true, false, Duration::ZERO, vec![Duration::ZERO; 3]);
+    /// let log = format!(
+    ///     "{{\"format\":\"prompter-stats\",\"version\":1}}\n{}\n{{\"record\":{{\"times",
+    ///     record.to_log_line()
+    /// );
+    /// let stats = Stats::from_log(log.as_bytes()).unwrap();
+    ///
+    /// assert_eq!((stats.len(), stats.skipped()), (1, 1));
+    /// assert!(Stats::from_log("1700000000,true,3,0,0;0;0".as_bytes()).is_err());
+    /// ```
+    pub fn from_log<R: BufRead>(reader: R) -> io::Result<Self> {
+        let mut lines = reader.lines();
+        let mut stats = Self::default();
+
+        let header = match lines.next() {
+            Some(line) => line?,
+            None => return Ok(stats),
+        };
+
+        match serde_json::from_str::<LogHeader>(&header) {
+            Ok(h) if h.format == FORMAT && h.version == LOG_VERSION => {}
+            Ok(h) if h.format == FORMAT => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Unsupported version {} of the stats log", h.version),
+                ))
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "The file is no stats log",
+                ))
+            }
+        }
+
+        for line in lines {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match GameRecord::from_log_line(&line) {
+                Some(record) => stats.records.push(record),
+                None => stats.skipped += 1,
+            }
+        }
+
+        Ok(stats)
+    }
+
+    #[cfg(feature = "std-fs")]
+    /// Loads the records from the stats log at `path`. If it does not exist yet, the CSV
+    /// file of older versions in the same directory is read instead, and if that does not
+    /// exist either, the stats are empty.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+
+        match File::open(path) {
+            Ok(file) => Self::from_log(BufReader::new(file)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::load_csv(legacy_path(path)),
+            Err(e) => Err(e),
+        }
+    }
+
+    #[cfg(feature = "std-fs")]
+    /// Loads the records from the CSV file of older versions at `path`.
+    fn load_csv(path: PathBuf) -> io::Result<Self> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+
+        let mut stats = Self::default();
+
+        for line in BufReader::new(file).lines().skip(1) {
+            let line = line?;
+
+            if !line.trim().is_empty() {
+                stats.records.push(line.parse()?);
+            }
+        }
+
+        Ok(stats)
+    }
+
+    #[cfg(feature = "std-fs")]
+    /// Appends `record` to the stats log at `path`. A new log starts with the header and
+    /// the records of the CSV file of older versions, if there is one.
+    pub fn append<P: AsRef<Path>>(path: P, record: &GameRecord) -> io::Result<()> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+
+            let header = LogHeader {
+                format: FORMAT.to_string(),
+                version: LOG_VERSION,
+            };
+            let mut content = serde_json::to_string(&header)? + "\n";
+
+            for old in Self::load_csv(legacy_path(path))?.iter().chain([record]) {
+                content.push_str(&old.to_log_line());
+                content.push('\n');
+            }
+
+            return OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(path)?
+                .write_all(content.as_bytes());
+        }
+
+        let mut file = OpenOptions::new().read(true).append(true).open(path)?;
+
+        // After a partial write, the new record has to start on a line of its own.
+        let mut last = [b'\n'];
+        if file.seek(SeekFrom::End(-1)).is_ok() {
+            file.read_exact(&mut last)?;
+        }
+
+        let separator = if last[0] == b'\n' { "" } else { "\n" };
+
+        // A single write, so that a crash leaves at most a partial line.
+        file.write_all(format!("{}{}\n", separator, record.to_log_line()).as_bytes())
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if there are no records.
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the number of damaged lines of the stats log that were skipped.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Returns an iterator over the records.
+    pub fn iter(&self) -> ::std::slice::Iter<'_, GameRecord> {
+        self.records.iter()
+    }
+
+    /// Returns `true` if a game of the daily puzzle with number `puzzle` has been recorded.
+    pub fn has_puzzle(&self, puzzle: u64) -> bool {
+        self.iter().any(|r| r.puzzle == Some(puzzle))
+    }
+
+    /// Returns an iterator over the records of speedruns.
+    pub fn speedruns(&self) -> impl Iterator<Item = &GameRecord> {
+        self.iter().filter(|r| r.speedrun)
+    }
+
+    /// Returns the speedrun with the shortest total time among the won ones.
+    pub fn personal_best(&self) -> Option<&GameRecord> {
+        self.speedruns().filter(|r| r.won).min_by_key(|r| r.total)
+    }
+
+    /// Returns the fastest round time ever needed in a speedrun for round number `round`
+    /// (starting at 1).
+    pub fn best_round(&self, round: usize) -> Option<Duration> {
+        self.speedruns()
+            .filter_map(|r| r.rounds.get(round.checked_sub(1)?))
+            .min()
+            .copied()
+    }
+
+    /// Returns the average total time of all won speedruns.
+    pub fn average_time(&self) -> Option<Duration> {
+        let won: Vec<_> = self.speedruns().filter(|r| r.won).collect();
+
+        if won.is_empty() {
+            return None;
+        }
+
+        Some(won.iter().map(|r| r.total).sum::<Duration>() / won.len() as u32)
+    }
+
+    /// Returns the number of games won.
+    pub fn won(&self) -> usize {
+        self.iter().filter(|r| r.won).count()
+    }
+
+    /// Returns the percentage of games won, rounded to a whole number (0 if no games have
+    /// been played).
+    pub fn win_percentage(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        (self.won() as f64 * 100.0 / self.len() as f64).round() as usize
+    }
+
+    /// Returns whether a game was won on each day on which games were played.
+    pub fn daily_results(&self) -> BTreeMap<u64, bool> {
+        let mut results = BTreeMap::new();
+
+        for record in self.iter() {
+            *results.entry(record.day()).or_insert(false) |= record.won;
+        }
+
+        results
+    }
+
+    /// Returns the current and the longest streak of days on which a game was won. The
+    /// current streak is not broken as long as no game has been played on `today`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::stats::{Stats, Streaks, SECONDS_PER_DAY};
+    /// let mut stats = Stats::default();
+    ///
+    /// stats.extend(
+    ///     [(1, true), (2, true), (3, false), (5, true), (6, true), (6, false)]
+    ///         .iter()
+    ///         .map(|(day, won)| format!("{},{},6,0,", day * SECONDS_PER_DAY, won).parse().unwrap()),
+    /// );
+    ///
+    /// assert_eq!(stats.streaks(7), Streaks { current: 2, max: 2 });
+    /// assert_eq!(stats.streaks(8), Streaks { current: 0, max: 2 });
+    /// ```
+    pub fn streaks(&self, today: u64) -> Streaks {
+        let mut streaks = Streaks::default();
+        let mut streak = 0;
+        let mut last_won = None;
+
+        for (day, won) in self.daily_results() {
+            if !won {
+                streak = 0;
+                continue;
+            }
+
+            streak = match last_won {
+                Some(last) if last + 1 == day && streak > 0 => streak + 1,
+                _ => 1,
+            };
+            last_won = Some(day);
+            streaks.max = streaks.max.max(streak);
+        }
+
+        if last_won.is_some_and(|last| last + 1 >= today) {
+            streaks.current = streak;
+        }
+
+        streaks
+    }
+
+    /// Returns a compact summary of the stats for sharing, e.g. in a chat: the number of
+    /// games played, the percentage won, the streaks and the guess distribution with a bar
+    /// of green squares per number of rounds (see [`Stats::distribution`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::stats::{Stats, SECONDS_PER_DAY};
+    /// let mut stats = Stats::default();
+    ///
+    /// stats.extend(vec![
+    ///     format!("{},true,3,0,1;1;1", SECONDS_PER_DAY).parse().unwrap(),
+    ///     format!("{},true,2,0,1;1", 2 * SECONDS_PER_DAY).parse().unwrap(),
+    ///     format!("{},true,3,0,1;1;1", 3 * SECONDS_PER_DAY).parse().unwrap(),
+    ///     format!("{},false,4,0,1;1;1;1", 3 * SECONDS_PER_DAY).parse().unwrap(),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     stats.share("Wordle", 4, 3),
+    ///     "Wordle stats\n\
+    ///      Played 4 | Win % 75 | Streak 3 (max 3)\n\n\
+    ///      1 ⬛ 0\n\
+    ///      2 🟩🟩🟩🟩🟩 1\n\
+    ///      3 🟩🟩🟩🟩🟩🟩🟩🟩🟩🟩 2\n\
+    ///      4 ⬛ 0"
+    /// );
+    /// ```
+    pub fn share(&self, title: &str, rounds: usize, today: u64) -> String {
+        let streaks = self.streaks(today);
+        let distribution = self.distribution(rounds);
+        let most = distribution
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or_default()
+            .max(1);
+
+        let rows: Vec<_> = distribution
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| {
+                let bar = match count * SHARE_BAR_WIDTH / most {
+                    0 if count == 0 => "⬛".to_string(),
+                    width => "🟩".repeat(width.max(1)),
+                };
+
+                format!("{} {} {}", i + 1, bar, count)
+            })
+            .collect();
+
+        format!(
+            "{} stats\nPlayed {} | Win % {} | Streak {} (max {})\n\n{}",
+            title,
+            self.len(),
+            self.win_percentage(),
+            streaks.current,
+            streaks.max,
+            rows.join("\n")
+        )
+    }
+
+    /// Returns the number of games won in each number of rounds, starting with 1 round,
+    /// for at least `rounds` rounds.
+    pub fn distribution(&self, rounds: usize) -> Vec<usize> {
+        let mut distribution = vec![0; rounds];
+
+        for record in self.iter().filter(|r| r.won && !r.rounds.is_empty()) {
+            let i = record.rounds.len() - 1;
+
+            if i >= distribution.len() {
+                distribution.resize(i + 1, 0);
+            }
+
+            distribution[i] += 1;
+        }
+
+        distribution
+    }
+}
+
+/// Parses the texts that Wordle's share button creates, e.g. pasted into a file from a chat,
+/// and returns a record for each game. A share text starts with a line like
+/// `Wordle 1,234 3/6*` with the number of the puzzle and the number of rounds needed (`X` if
+/// the game was lost), followed by the grid of colored squares. All other lines are
+/// ignored.
+///
+/// Since the time of the game is unknown, it is recorded at midnight UTC of the puzzle's day
+/// with times of 0. A lost game is recorded with the number of rows of its grid.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::stats;
+/// let text = "Wordle 1,234 3/6*\n\n⬛🟨⬛⬛⬛\n🟨🟩⬛⬛🟩\n🟩🟩🟩🟩🟩\n\nnice!\nWordle 1.235 X/6\n";
+/// let records = stats::parse_shares(text).unwrap();
+///
+/// assert_eq!(records.len(), 2);
+/// assert_eq!((records[0].puzzle, records[0].won, records[0].rounds.len()), (Some(1234), true, 3));
+/// assert_eq!((records[1].puzzle, records[1].won, records[1].rounds.len()), (Some(1235), false, 6));
+/// assert!(stats::parse_shares("Wordle 1,236 7/6").is_err());
+/// ```
+pub fn parse_shares(text: &str) -> io::Result<Vec<GameRecord>> {
+    let lines: Vec<_> = text.lines().map(str::trim).collect();
+    let mut records = vec![];
+
+    for (i, line) in lines.iter().enumerate() {
+        let Some(header) = line.strip_prefix("Wordle ") else {
+            continue;
+        };
+
+        let mut tokens = header.split_whitespace();
+
+        // Thousands are separated by a comma, a period or a space, depending on the locale.
+        let number: String = tokens
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .filter(|c| !matches!(c, ',' | '.' | '\u{a0}' | '\u{202f}'))
+            .collect();
+
+        let Ok(puzzle) = number.parse::<u64>() else {
+            continue;
+        };
+
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid share text in line {}: '{}'", i + 1, line),
+            )
+        };
+
+        let score = tokens.next().ok_or_else(invalid)?.trim_end_matches('*');
+        let (score, limit) = score.split_once('/').ok_or_else(invalid)?;
+        let limit: usize = limit.parse().map_err(|_| invalid())?;
+
+        let rows = lines[i + 1..]
+            .iter()
+            .skip_while(|l| l.is_empty())
+            .take_while(|l| !l.is_empty() && l.chars().all(is_square))
+            .count();
+
+        let (won, rounds) = match score {
+            "X" | "x" => (false, if rows > 0 { rows } else { limit }),
+            _ => {
+                let rounds: usize = score.parse().map_err(|_| invalid())?;
+
+                if rounds == 0 || rounds > limit {
+                    return Err(invalid());
+                }
+
+                (true, rounds)
+            }
+        };
+
+        records.push(GameRecord {
+            timestamp: (daily::WORDLE_FIRST_DAY + puzzle) * SECONDS_PER_DAY,
+            won,
+            total: Duration::ZERO,
+            rounds: vec![Duration::ZERO; rounds],
+            speedrun: false,
+            puzzle: Some(puzzle),
+        });
+    }
+
+    Ok(records)
+}
+
+/// Returns `true` if `c` is one of the squares in a shared grid, including those of the
+/// high-contrast and dark modes.
+fn is_square(c: char) -> bool {
+    matches!(c, '🟩' | '🟨' | '⬛' | '⬜' | '🟧' | '🟦')
+}
+
+#[cfg(feature = "std-fs")]
+/// Returns the path of the CSV file of older versions next to the stats log at `path`.
+fn legacy_path(path: &Path) -> PathBuf {
+    path.with_extension("csv")
+}
+
+impl Extend<GameRecord> for Stats {
+    fn extend<I: IntoIterator<Item = GameRecord>>(&mut self, iter: I) {
+        self.records.extend(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_roundtrip() {
+        let record = GameRecord {
+            timestamp: 1700000000,
+            won: true,
+            total: Duration::from_millis(42195),
+            rounds: vec![Duration::from_millis(12000), Duration::from_millis(30195)],
+            speedrun: false,
+            puzzle: Some(880),
+        };
+
+        let line = record.to_string();
+
+        assert_eq!(line, "1700000000,true,2,42195,12000;30195,false,880");
+        assert_eq!(line.parse::<GameRecord>().unwrap(), record);
+
+        // Records without the speedrun column are speedruns.
+        let old: GameRecord = "1700000000,true,2,42195,12000;30195".parse().unwrap();
+        assert!(old.speedrun);
+        assert_eq!(old.puzzle, None);
+
+        let mut stats = Stats::default();
+        stats.extend(vec![old, record]);
+
+        assert!(stats.has_puzzle(880));
+        assert!(!stats.has_puzzle(881));
+    }
+
+    #[test]
+    fn test_log_line_checksum() {
+        // The checksum covers the record exactly as written, including its whitespace.
+        let json = r#"{"timestamp": 1700000000, "won": true, "total_ms": 30000, "round_ms": [30000], "speedrun": false, "puzzle": null}"#;
+        let line = format!(
+            r#"{{"record": {}, "checksum": "{}"}}"#,
+            json,
+            checksum(json)
+        );
+
+        let record = GameRecord::from_log_line(&line).unwrap();
+        assert_eq!(record.timestamp, 1700000000);
+        assert_eq!(record.rounds, [Duration::from_millis(30000)]);
+
+        let compact = json.replace(": ", ":").replace(", ", ",");
+        let line = format!(
+            r#"{{"record": {}, "checksum": "{}"}}"#,
+            json,
+            checksum(&compact)
+        );
+        assert_eq!(GameRecord::from_log_line(&line), None);
+    }
+
+    #[test]
+    fn test_personal_best() {
+        let mut stats = Stats::default();
+
+        stats.extend(vec![
+            "1,true,3,9000,3000;3000;3000".parse().unwrap(),
+            "2,false,6,1000,1;1;1;1;1;995".parse().unwrap(),
+            "3,true,2,8000,6000;2000".parse().unwrap(),
+        ]);
+
+        assert_eq!(stats.personal_best().unwrap().timestamp, 3);
+        assert_eq!(stats.best_round(1), Some(Duration::from_millis(1)));
+        assert_eq!(stats.best_round(3), Some(Duration::from_millis(1)));
+        assert_eq!(stats.average_time(), Some(Duration::from_millis(8500)));
+
+        stats.extend(vec!["4,true,1,10,10,false".parse().unwrap()]);
+
+        assert_eq!(stats.personal_best().unwrap().timestamp, 3);
+        assert_eq!(stats.average_time(), Some(Duration::from_millis(8500)));
+    }
+
+    #[test]
+    fn test_distribution() {
+        let mut stats = Stats::default();
+
+        stats.extend(vec![
+            "1,true,3,0,1;1;1,false".parse().unwrap(),
+            "2,false,6,0,1;1;1;1;1;1,false".parse().unwrap(),
+            "3,true,3,0,1;1;1,false".parse().unwrap(),
+            "4,true,7,0,1;1;1;1;1;1;1,false".parse().unwrap(),
+        ]);
+
+        assert_eq!(stats.won(), 3);
+        assert_eq!(stats.win_percentage(), 75);
+        assert_eq!(stats.distribution(6), [0, 0, 2, 0, 0, 0, 1]);
+        assert_eq!(Stats::default().distribution(6), [0; 6]);
+        assert_eq!(Stats::default().win_percentage(), 0);
+    }
+}
@@ -0,0 +1,984 @@
+//! Strategies that decide which words to suggest.
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    cache::fnv1a64,
+    feedback::{FeedbackRule, WordleRule},
+    freq::Frequencies,
+    lookahead::Lookahead,
+    prior::Prior,
+    rng::splitmix64,
+    scratch::Scratch,
+    Word, Wordlist,
+};
+
+/// Number of words ranked best by `Solve` that the `lookahead` strategy looks at more
+/// closely if not configured otherwise.
+pub const DEFAULT_LOOKAHEAD_K: usize = 10;
+
+/// Number of rounds that the `lookahead` strategy looks ahead if not configured otherwise.
+pub const DEFAULT_LOOKAHEAD_DEPTH: usize = 2;
+
+/// Number of follow-up guesses tried in each bucket by the `lookahead` strategy.
+const DEFAULT_LOOKAHEAD_WIDTH: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The objective that the ranking of candidate words follows
+pub enum Strategy {
+    #[default]
+    /// Find the word in as few rounds as possible. Words that split the candidates into
+    /// the most buckets are preferred.
+    Solve,
+    /// Avoid the word for as many rounds as possible while only playing words that match
+    /// all hints (Survivle). Words that split the candidates into the fewest buckets are
+    /// preferred.
+    Survive,
+    /// Find the word in as few rounds as possible, weighting the buckets by the
+    /// probability of the words they contain (see [`Prior`]). Words whose buckets have the
+    /// highest entropy are preferred. With a uniform prior, this is close to `Solve`.
+    Entropy,
+    /// Keep the worst case small: words whose largest bucket has the fewest words are
+    /// preferred.
+    Minimax,
+    /// Find the word with a fast heuristic: words whose characters are common at their
+    /// positions among the candidates are preferred. Repeated characters count half. This
+    /// is much faster than counting buckets for large wordlists, but less accurate.
+    Positional,
+    /// Find the word in as few rounds as possible by looking `depth` rounds ahead: the `k`
+    /// words ranked best by `Solve` are ranked by the expected number of rounds needed if
+    /// the best follow-up guess is played in each of their buckets (see [`Lookahead`]).
+    Lookahead {
+        /// Number of words that are looked at more closely.
+        k: usize,
+        /// Number of rounds to look ahead.
+        depth: usize,
+    },
+    /// Play a candidate chosen at random, as a baseline for the other strategies. The
+    /// choice only depends on the seed and the candidates, so simulations can be repeated.
+    Random {
+        /// Seed of the random choice.
+        seed: u64,
+    },
+}
+
+impl Strategy {
+    /// All available strategies.
+    pub const ALL: [Strategy; 7] = [
+        Strategy::Solve,
+        Strategy::Survive,
+        Strategy::Entropy,
+        Strategy::Minimax,
+        Strategy::Positional,
+        Strategy::Lookahead {
+            k: DEFAULT_LOOKAHEAD_K,
+            depth: DEFAULT_LOOKAHEAD_DEPTH,
+        },
+        Strategy::Random { seed: 0 },
+    ];
+
+    /// Returns the name used to select the strategy.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::Solve => "solve",
+            Strategy::Survive => "survive",
+            Strategy::Entropy => "entropy",
+            Strategy::Minimax => "minimax",
+            Strategy::Positional => "positional",
+            Strategy::Lookahead { .. } => "lookahead",
+            Strategy::Random { .. } => "random",
+        }
+    }
+
+    /// Returns the strategy with `seed` as the seed of its random choices, unless it has a
+    /// seed of its own. Strategies without random choices are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::strategy::Strategy;
+    /// assert_eq!(Strategy::Random { seed: 0 }.with_seed(42), Strategy::Random { seed: 42 });
+    /// assert_eq!(Strategy::Random { seed: 7 }.with_seed(42), Strategy::Random { seed: 7 });
+    /// assert_eq!(Strategy::Solve.with_seed(42), Strategy::Solve);
+    /// ```
+    pub fn with_seed(self, seed: u64) -> Self {
+        match self {
+            // `random` and `random:seed=0` are the same strategy, see `Display`.
+            Strategy::Random { seed: 0 } => Strategy::Random { seed },
+            strategy => strategy,
+        }
+    }
+
+    /// Ranks the words in `wordlist` by their [`filter_potential`] and returns pairs of
+    /// word references and scores, best first. Words with the same score are sorted
+    /// alphabetically, so the ranking does not depend on the order of the wordlist.
+    ///
+    /// [`filter_potential`]: Word::filter_potential
+    pub fn rank<'a>(&self, wordlist: &'a Wordlist) -> Vec<(&'a Word, usize)> {
+        self.rank_with(wordlist, &Prior::uniform())
+    }
+
+    /// Ranks the words in `wordlist` like [`Strategy::rank`], with the probability of each
+    /// word being the mystery word taken from `prior`.
+    ///
+    /// The score depends on the strategy:
+    ///
+    /// * `Solve`, `Survive` and `Lookahead`: the number of buckets. `Lookahead` only
+    ///   reorders the first `k` words.
+    /// * `Entropy`: the effective number of buckets, i.e. 2 to the power of the entropy,
+    ///   which is the number of buckets if they are all equally likely.
+    /// * `Minimax`: the number of words in the largest bucket.
+    /// * `Positional`: the sum of the numbers of candidates that have each character at
+    ///   the same position.
+    /// * `Random`: always 0.
+    ///
+    /// Words that are equally good are sorted alphabetically, after the words that are more
+    /// likely to be the mystery word with `Entropy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{prior::Prior, strategy::Strategy, Word, Wordlist};
+    /// let wordlist: Wordlist = ["batch", "catch", "hatch", "latch"].iter().map(Word::from).collect();
+    /// let prior = Prior::from_reader("catch 10\nbatch 1\n".as_bytes()).unwrap();
+    ///
+    /// // Only the common word is worth a guess.
+    /// assert_eq!(Strategy::Entropy.best(&wordlist), Some(&Word::from("batch")));
+    /// assert_eq!(Strategy::Entropy.best_with(&wordlist, &prior), Some(&Word::from("catch")));
+    /// ```
+    pub fn rank_with<'a>(&self, wordlist: &'a Wordlist, prior: &Prior) -> Vec<(&'a Word, usize)> {
+        self.rank_guesses(wordlist, wordlist, prior)
+    }
+
+    /// Ranks the words in `guesses` like [`Strategy::rank_with`] by how well they sort the
+    /// words in `candidates` into buckets. The guesses do not need to be candidates.
+    pub fn rank_guesses<'a>(
+        &self,
+        guesses: &'a Wordlist,
+        candidates: &Wordlist,
+        prior: &Prior,
+    ) -> Vec<(&'a Word, usize)> {
+        self.rank_guesses_by(&WordleRule, guesses, candidates, prior)
+    }
+
+    /// Ranks the words in `guesses` like [`Strategy::rank_guesses`], but with the buckets
+    /// of the feedback of `rule` instead of Wordle's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{feedback::FeedbackRule, prior::Prior, strategy::Strategy, Word, Wordlist};
+    /// /// Only tells whether the guess is right.
+    /// struct RightOrWrong;
+    ///
+    /// impl FeedbackRule for RightOrWrong {
+    ///     type Code = bool;
+    ///
+    ///     fn code(&self, guess: &Word, target: &Word) -> bool {
+    ///         guess == target
+    ///     }
+    /// }
+    ///
+    /// let wordlist: Wordlist = ["crate", "trace", "spoon"].iter().map(Word::from).collect();
+    /// let ranked = Strategy::Solve.rank_guesses_by(&RightOrWrong, &wordlist, &wordlist, &Prior::uniform());
+    ///
+    /// assert_eq!(ranked[0], (&Word::from("crate"), 2));
+    /// ```
+    pub fn rank_guesses_by<'a, R: FeedbackRule>(
+        &self,
+        rule: &R,
+        guesses: &'a Wordlist,
+        candidates: &Wordlist,
+        prior: &Prior,
+    ) -> Vec<(&'a Word, usize)> {
+        let mut scratch = Scratch::default();
+
+        match self {
+            Strategy::Solve => rank_by_buckets(rule, guesses, candidates, &mut scratch),
+            Strategy::Survive => guesses
+                .iter()
+                .map(|w| (w, rule.filter_potential_with(w, candidates, &mut scratch)))
+                .sorted_by_key(|&(w, score)| (score, w))
+                .collect(),
+            Strategy::Entropy => {
+                let probabilities = prior.probabilities(candidates);
+                let likelihood: HashMap<_, _> = probabilities.iter().copied().collect();
+
+                // Among words with the same entropy, the one most likely to be the mystery
+                // word comes first.
+                guesses
+                    .iter()
+                    .map(|w| {
+                        let p = likelihood.get(w).copied().unwrap_or(0.0);
+                        (w, p, entropy(rule, w, &probabilities, &mut scratch))
+                    })
+                    .sorted_by(|(w1, p1, h1), (w2, p2, h2)| {
+                        h2.total_cmp(h1).then(p2.total_cmp(p1)).then(w1.cmp(w2))
+                    })
+                    .map(|(w, _, h)| (w, h.exp2().round() as usize))
+                    .collect()
+            }
+            Strategy::Minimax => guesses
+                .iter()
+                .map(|w| (w, largest_bucket(rule, w, candidates, &mut scratch)))
+                .sorted_by_key(|&(w, score)| (score, w))
+                .collect(),
+            Strategy::Positional => {
+                let freq = Frequencies::new(candidates);
+
+                guesses
+                    .iter()
+                    .map(|w| (w, positional_score(w, &freq)))
+                    .sorted_by(|(w1, s1), (w2, s2)| s2.cmp(s1).then(w1.cmp(w2)))
+                    .collect()
+            }
+            Strategy::Lookahead { k, depth } => {
+                let lookahead = Lookahead::new(*depth, DEFAULT_LOOKAHEAD_WIDTH);
+                let mut ranked = rank_by_buckets(rule, guesses, candidates, &mut scratch);
+                let top = (*k).min(ranked.len());
+
+                ranked[..top].sort_by_cached_key(|&(w, _)| {
+                    // Expected numbers of rounds are compared with a fixed precision, so
+                    // that the order of equally good words is stable.
+                    let turns = lookahead.expected_turns_by(rule, w, candidates);
+                    let turns = (turns * 1e9).round() as u64;
+
+                    (turns, w)
+                });
+
+                ranked
+            }
+            Strategy::Random { seed } => {
+                let round = splitmix64(seed ^ splitmix64(candidates.len() as u64));
+
+                guesses
+                    .iter()
+                    .sorted_by_key(|&w| (splitmix64(round ^ fnv1a64(w.0.as_bytes())), w))
+                    .map(|w| (w, 0))
+                    .collect()
+            }
+        }
+    }
+
+    /// Returns the best-ranked word in `wordlist`.
+    pub fn best<'a>(&self, wordlist: &'a Wordlist) -> Option<&'a Word> {
+        self.best_with(wordlist, &Prior::uniform())
+    }
+
+    /// Returns the best-ranked word in `wordlist` with the probabilities from `prior`.
+    pub fn best_with<'a>(&self, wordlist: &'a Wordlist, prior: &Prior) -> Option<&'a Word> {
+        self.best_guess(wordlist, wordlist, prior).map(|(w, _)| w)
+    }
+
+    /// Returns the word that [`Strategy::rank_guesses`] ranks first and its score.
+    ///
+    /// With `Solve`, `Survive` and `Minimax`, the buckets of a word are no longer filled as
+    /// soon as the word cannot beat the best word so far, e.g. with `Solve` once it cannot
+    /// reach as many buckets even if each word left got a bucket of its own. This is much
+    /// faster than ranking all words, especially for the first guess.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{prior::Prior, strategy::Strategy, Word, Wordlist};
+    /// let wordlist: Wordlist = ["batch", "catch", "hatch", "latch", "clubs"].iter().map(Word::from).collect();
+    /// let prior = Prior::uniform();
+    ///
+    /// for strategy in [Strategy::Solve, Strategy::Survive, Strategy::Minimax] {
+    ///     let ranked = strategy.rank_guesses(&wordlist, &wordlist, &prior);
+    ///     assert_eq!(strategy.best_guess(&wordlist, &wordlist, &prior), Some(ranked[0]));
+    /// }
+    /// ```
+    pub fn best_guess<'a>(
+        &self,
+        guesses: &'a Wordlist,
+        candidates: &Wordlist,
+        prior: &Prior,
+    ) -> Option<(&'a Word, usize)> {
+        self.best_guess_with(guesses, candidates, prior, &mut Scratch::default())
+    }
+
+    /// Returns the best word like [`Strategy::best_guess`] with the buffers in `scratch`,
+    /// e.g. to reuse them in each round of a simulated game.
+    pub fn best_guess_with<'a>(
+        &self,
+        guesses: &'a Wordlist,
+        candidates: &Wordlist,
+        prior: &Prior,
+        scratch: &mut Scratch,
+    ) -> Option<(&'a Word, usize)> {
+        self.best_guess_by(&WordleRule, guesses, candidates, prior, scratch)
+    }
+
+    /// Returns the best word like [`Strategy::best_guess_with`], but with the buckets of
+    /// the feedback of `rule` instead of Wordle's (see [`Strategy::rank_guesses_by`]).
+    pub fn best_guess_by<'a, R: FeedbackRule>(
+        &self,
+        rule: &R,
+        guesses: &'a Wordlist,
+        candidates: &Wordlist,
+        prior: &Prior,
+        scratch: &mut Scratch<R::Code>,
+    ) -> Option<(&'a Word, usize)> {
+        match self {
+            Strategy::Solve | Strategy::Survive | Strategy::Minimax => {
+                best_by_buckets(rule, *self, guesses, candidates, scratch)
+            }
+            _ => self
+                .rank_guesses_by(rule, guesses, candidates, prior)
+                .first()
+                .copied(),
+        }
+    }
+}
+
+/// Reorders the words with the same score in `ranked` by `key`, smallest first. Words with
+/// different scores keep their places.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{strategy, Word};
+/// let (a, b, c) = (Word::from("aaaaa"), Word::from("bbbbb"), Word::from("ccccc"));
+/// let ranked = vec![(&a, 3), (&b, 2), (&c, 2)];
+///
+/// let reordered = strategy::break_ties(ranked, |w| *w != c);
+/// assert_eq!(reordered, [(&a, 3), (&c, 2), (&b, 2)]);
+/// ```
+pub fn break_ties<K, F>(ranked: Vec<(&Word, usize)>, mut key: F) -> Vec<(&Word, usize)>
+where
+    K: Ord,
+    F: FnMut(&Word) -> K,
+{
+    let mut run = 0;
+    let mut keys = Vec::with_capacity(ranked.len());
+
+    for (i, (w, score)) in ranked.iter().enumerate() {
+        if i > 0 && ranked[i - 1].1 != *score {
+            run += 1;
+        }
+
+        keys.push((run, key(w)));
+    }
+
+    keys.into_iter()
+        .zip(ranked)
+        .sorted_by(|(k1, _), (k2, _)| k1.cmp(k2))
+        .map(|(_, ranked)| ranked)
+        .collect()
+}
+
+/// Ranks the words in `guesses` by the number of buckets of `rule` into which they sort the
+/// words in `candidates`, most buckets first and alphabetically among words with the same
+/// number.
+fn rank_by_buckets<'a, R: FeedbackRule>(
+    rule: &R,
+    guesses: &'a Wordlist,
+    candidates: &Wordlist,
+    scratch: &mut Scratch<R::Code>,
+) -> Vec<(&'a Word, usize)> {
+    guesses
+        .iter()
+        .map(|w| (w, rule.filter_potential_with(w, candidates, scratch)))
+        .sorted_by(|(w1, s1), (w2, s2)| s2.cmp(s1).then(w1.cmp(w2)))
+        .collect()
+}
+
+/// Returns the word in `guesses` that `strategy` (`Solve`, `Survive` or `Minimax`) ranks
+/// first for the words in `candidates` with the buckets of `rule` and its score (see
+/// [`Strategy::best_guess`]).
+fn best_by_buckets<'a, R: FeedbackRule>(
+    rule: &R,
+    strategy: Strategy,
+    guesses: &'a Wordlist,
+    candidates: &Wordlist,
+    scratch: &mut Scratch<R::Code>,
+) -> Option<(&'a Word, usize)> {
+    let counts = &mut scratch.counts;
+    let mut best: Option<(&Word, usize)> = None;
+
+    'guesses: for guess in guesses {
+        counts.clear();
+        let mut largest = 0;
+
+        for (i, w) in candidates.iter().enumerate() {
+            largest = largest.max(counts.add(rule, rule.code(guess, w), 1));
+
+            let Some(best) = best else { continue };
+
+            // The best score that the guess can still get: each word left may get a bucket
+            // of its own, but buckets never disappear and never get smaller.
+            let reachable = match strategy {
+                Strategy::Solve => {
+                    let left = candidates.len() - i - 1;
+
+                    // Only when few words are left can the guess fall behind.
+                    if left >= best.1 {
+                        continue;
+                    }
+
+                    counts.len() + left
+                }
+                Strategy::Survive => counts.len(),
+                _ => largest,
+            };
+
+            if !ranks_before(strategy, (guess, reachable), best) {
+                continue 'guesses;
+            }
+        }
+
+        let score = match strategy {
+            Strategy::Solve | Strategy::Survive => counts.len(),
+            _ => largest,
+        };
+
+        if best.map_or(true, |best| ranks_before(strategy, (guess, score), best)) {
+            best = Some((guess, score));
+        }
+    }
+
+    best
+}
+
+/// Returns `true` if `strategy` ranks a word with a score before another, comparing the
+/// words alphabetically if the scores are equal.
+fn ranks_before(strategy: Strategy, (w1, s1): (&Word, usize), (w2, s2): (&Word, usize)) -> bool {
+    match strategy {
+        Strategy::Solve => (Reverse(s1), w1) < (Reverse(s2), w2),
+        _ => (s1, w1) < (s2, w2),
+    }
+}
+
+/// Returns the entropy in bits of the buckets of `rule` for `guess`, weighted by the
+/// probabilities of the words they contain.
+fn entropy<R: FeedbackRule>(
+    rule: &R,
+    guess: &Word,
+    probabilities: &[(&Word, f64)],
+    scratch: &mut Scratch<R::Code>,
+) -> f64 {
+    let buckets = &mut scratch.weights;
+    buckets.clear();
+
+    for (w, p) in probabilities {
+        buckets.add(rule, rule.code(guess, w), *p);
+    }
+
+    // The buckets are summed up in a fixed order, so that words with the same buckets get
+    // exactly the same entropy.
+    let sorted = &mut scratch.sorted;
+    sorted.clear();
+    sorted.extend(buckets.values().filter(|&p| p > 0.0));
+    sorted.sort_by(f64::total_cmp);
+
+    sorted.iter().map(|p| -p * p.log2()).sum()
+}
+
+/// Returns the number of words in the largest bucket of `rule` for `guess`.
+fn largest_bucket<R: FeedbackRule>(
+    rule: &R,
+    guess: &Word,
+    wordlist: &Wordlist,
+    scratch: &mut Scratch<R::Code>,
+) -> usize {
+    scratch.counts.clear();
+
+    wordlist
+        .iter()
+        .map(|w| scratch.counts.add(rule, rule.code(guess, w), 1))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the sum of the positional frequencies of the characters of `word`, where
+/// repeated characters count half.
+fn positional_score(word: &Word, freq: &Frequencies) -> usize {
+    let mut seen = HashSet::new();
+
+    word.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if seen.insert(c) {
+                freq.at(i, c)
+            } else {
+                freq.at(i, c) / 2
+            }
+        })
+        .sum()
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    /// Parses the name of a strategy, optionally followed by parameters (e.g.
+    /// `random:seed=42` or `lookahead:k=5`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, params) = s.split_once(':').unwrap_or((s, ""));
+
+        let strategy = Strategy::ALL
+            .into_iter()
+            .find(|strategy| strategy.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                let names: Vec<_> = Strategy::ALL.iter().map(|s| s.name()).collect();
+                format!(
+                    "unknown strategy '{}' (available: {})",
+                    name,
+                    names.join(", ")
+                )
+            })?;
+
+        params
+            .split(',')
+            .filter(|param| !param.is_empty())
+            .try_fold(strategy, |strategy, param| {
+                let (key, value) = param
+                    .split_once('=')
+                    .ok_or_else(|| format!("parameter '{}' is not of the form KEY=VALUE", param))?;
+                let invalid = |_| format!("invalid value '{}' for {}", value, key);
+
+                match (strategy, key) {
+                    (Strategy::Random { .. }, "seed") => Ok(Strategy::Random {
+                        seed: value.parse().map_err(invalid)?,
+                    }),
+                    (Strategy::Lookahead { depth, .. }, "k") => {
+                        match value.parse().map_err(invalid)? {
+                            0 => Err(format!("invalid value '{}' for {}", value, key)),
+                            k => Ok(Strategy::Lookahead { k, depth }),
+                        }
+                    }
+                    (Strategy::Lookahead { k, .. }, "depth") => Ok(Strategy::Lookahead {
+                        k,
+                        depth: value.parse().map_err(invalid)?,
+                    }),
+                    _ => Err(format!("unknown parameter '{}' for {}", key, name)),
+                }
+            })
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Strategy::Random { seed } if seed != 0 => write!(f, "{}:seed={}", self.name(), seed),
+            Strategy::Lookahead { k, depth } => {
+                let params = [
+                    ("k", k, DEFAULT_LOOKAHEAD_K),
+                    ("depth", depth, DEFAULT_LOOKAHEAD_DEPTH),
+                ]
+                .into_iter()
+                .filter(|&(_, value, default)| value != default)
+                .map(|(key, value, _)| format!("{}={}", key, value))
+                .join(",");
+
+                if params.is_empty() {
+                    write!(f, "{}", self.name())
+                } else {
+                    write!(f, "{}:{}", self.name(), params)
+                }
+            }
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The words from which the suggestions are taken
+pub enum Pool {
+    #[default]
+    /// Only words that may still be the mystery word, as in hard mode.
+    Candidates,
+    /// All words of the game, including probes that have already been ruled out but sort
+    /// the candidates into more buckets.
+    Probes,
+    /// All words of the game like `Probes`, but among equally good words, candidates come
+    /// first because they may be the mystery word.
+    Mixed,
+}
+
+impl Pool {
+    /// All available pools.
+    pub const ALL: [Pool; 3] = [Pool::Candidates, Pool::Probes, Pool::Mixed];
+
+    /// Returns the name used to select the pool.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pool::Candidates => "candidates",
+            Pool::Probes => "probes",
+            Pool::Mixed => "mixed",
+        }
+    }
+
+    /// Ranks the words of the pool with `strategy` by how well they sort `candidates` into
+    /// buckets. `dictionary` contains all words of the game. `Survive` always ranks the
+    /// candidates because only they may be played in Survivle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{prior::Prior, strategy::{Pool, Strategy}, Word, Wordlist};
+    /// let dictionary: Wordlist = ["batch", "catch", "hatch", "latch", "clubs"].iter().map(Word::from).collect();
+    /// let candidates: Wordlist = ["batch", "catch", "hatch", "latch"].iter().map(Word::from).collect();
+    /// let prior = Prior::uniform();
+    ///
+    /// // "clubs" has been ruled out, but tells all candidates apart.
+    /// let ranked = Pool::Probes.rank(Strategy::Solve, &dictionary, &candidates, &prior);
+    /// assert_eq!(ranked[0], (&Word::from("clubs"), 4));
+    ///
+    /// let ranked = Pool::Candidates.rank(Strategy::Solve, &dictionary, &candidates, &prior);
+    /// assert_eq!(ranked[0], (&Word::from("batch"), 2));
+    /// ```
+    pub fn rank<'a>(
+        &self,
+        strategy: Strategy,
+        dictionary: &'a Wordlist,
+        candidates: &'a Wordlist,
+        prior: &Prior,
+    ) -> Vec<(&'a Word, usize)> {
+        match self {
+            _ if strategy == Strategy::Survive => strategy.rank_with(candidates, prior),
+            Pool::Candidates => strategy.rank_with(candidates, prior),
+            Pool::Probes => strategy.rank_guesses(dictionary, candidates, prior),
+            Pool::Mixed => {
+                let ranked = strategy.rank_guesses(dictionary, candidates, prior);
+                let is_candidate: HashSet<&Word> = candidates.iter().collect();
+
+                break_ties(ranked, |w| !is_candidate.contains(w))
+            }
+        }
+    }
+
+    /// Returns the word that [`Pool::rank`] ranks first and its score, stopping early where
+    /// possible (see [`Strategy::best_guess`]).
+    pub fn best<'a>(
+        &self,
+        strategy: Strategy,
+        dictionary: &'a Wordlist,
+        candidates: &'a Wordlist,
+        prior: &Prior,
+    ) -> Option<(&'a Word, usize)> {
+        match self {
+            _ if strategy == Strategy::Survive => {
+                strategy.best_guess(candidates, candidates, prior)
+            }
+            Pool::Candidates => strategy.best_guess(candidates, candidates, prior),
+            Pool::Probes => strategy.best_guess(dictionary, candidates, prior),
+            Pool::Mixed => self
+                .rank(strategy, dictionary, candidates, prior)
+                .first()
+                .copied(),
+        }
+    }
+}
+
+impl FromStr for Pool {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Pool::ALL
+            .into_iter()
+            .find(|pool| pool.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = Pool::ALL.iter().map(|p| p.name()).collect();
+                format!("unknown pool '{}' (available: {})", s, names.join(", "))
+            })
+    }
+}
+
+impl fmt::Display for Pool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The order of equally good suggestions, before they are sorted alphabetically
+pub enum TieBreak {
+    #[default]
+    /// Equally good words are sorted alphabetically.
+    Alphabetical,
+    /// Among equally good words, the words that are more likely to be the mystery word
+    /// (see [`Prior`]) come first and words that have been ruled out come last.
+    Likely,
+}
+
+impl TieBreak {
+    /// All available tie-breaks.
+    pub const ALL: [TieBreak; 2] = [TieBreak::Alphabetical, TieBreak::Likely];
+
+    /// Returns the name used to select the tie-break.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TieBreak::Alphabetical => "alphabetical",
+            TieBreak::Likely => "likely",
+        }
+    }
+
+    /// Reorders the words with the same score in `ranked` (see [`break_ties`]). Words that
+    /// are equal under the tie-break keep their order, so a ranking from [`Strategy`] stays
+    /// alphabetical among them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::{prior::Prior, strategy::{Strategy, TieBreak}, Word, Wordlist};
+    /// let wordlist: Wordlist = ["batch", "catch", "hatch"].iter().map(Word::from).collect();
+    /// let prior = Prior::from_reader("catch 10\nbatch 1\nhatch 1\n".as_bytes()).unwrap();
+    /// let ranked = Strategy::Solve.rank(&wordlist);
+    ///
+    /// let words = |ranked: Vec<(&Word, usize)>| ranked.iter().map(|(w, _)| w.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(words(TieBreak::Alphabetical.apply(ranked.clone(), &wordlist, &prior)), ["batch", "catch", "hatch"]);
+    /// assert_eq!(words(TieBreak::Likely.apply(ranked, &wordlist, &prior)), ["catch", "batch", "hatch"]);
+    /// ```
+    pub fn apply<'a>(
+        &self,
+        ranked: Vec<(&'a Word, usize)>,
+        candidates: &Wordlist,
+        prior: &Prior,
+    ) -> Vec<(&'a Word, usize)> {
+        match self {
+            TieBreak::Alphabetical => ranked,
+            TieBreak::Likely => {
+                let probabilities: HashMap<_, _> =
+                    prior.probabilities(candidates).into_iter().collect();
+
+                // Probabilities are never negative, so their bits are ordered like them.
+                break_ties(ranked, |w| {
+                    std::cmp::Reverse(probabilities.get(w).map(|p| p.to_bits()))
+                })
+            }
+        }
+    }
+}
+
+impl FromStr for TieBreak {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TieBreak::ALL
+            .into_iter()
+            .find(|tiebreak| tiebreak.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = TieBreak::ALL.iter().map(|t| t.name()).collect();
+                format!(
+                    "unknown tie-break '{}' (available: {})",
+                    s,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for TieBreak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank() {
+        let wordlist: Wordlist = ["aaaab", "aaaac", "aaada", "bcdea"]
+            .iter()
+            .map(Word::from)
+            .collect();
+
+        assert_eq!(Strategy::Solve.best(&wordlist), Some(&Word::from("aaaab")));
+        assert_eq!(
+            Strategy::Survive.best(&wordlist),
+            Some(&Word::from("aaada"))
+        );
+
+        let scores: Vec<_> = Strategy::Survive
+            .rank(&wordlist)
+            .into_iter()
+            .map(|(_, score)| score)
+            .collect();
+        assert_eq!(scores, vec![3, 4, 4, 4]);
+    }
+
+    #[test]
+    fn test_best_guess() {
+        let dictionary: Wordlist = include_str!("words.txt")
+            .lines()
+            .step_by(7)
+            .map(Word::from)
+            .collect();
+        let candidates: Wordlist = dictionary.iter().step_by(3).cloned().collect();
+        let prior = Prior::uniform();
+
+        for strategy in Strategy::ALL {
+            for pool in Pool::ALL {
+                let ranked = pool.rank(strategy, &dictionary, &candidates, &prior);
+
+                assert_eq!(
+                    pool.best(strategy, &dictionary, &candidates, &prior),
+                    ranked.first().copied(),
+                    "{} with {}",
+                    strategy,
+                    pool
+                );
+            }
+        }
+
+        assert_eq!(
+            Strategy::Solve.best_guess(&dictionary, &Wordlist::default(), &prior),
+            Some((dictionary.iter().min().unwrap(), 0))
+        );
+        assert_eq!(Strategy::Minimax.best(&Wordlist::default()), None);
+    }
+
+    #[test]
+    fn test_rank_order() {
+        let words = [
+            "batch", "catch", "hatch", "latch", "crate", "trace", "react",
+        ];
+        let wordlist: Wordlist = words.iter().map(Word::from).collect();
+        let reversed: Wordlist = words.iter().rev().map(Word::from).collect();
+
+        for strategy in Strategy::ALL {
+            let ranked = strategy.rank(&wordlist);
+
+            assert_eq!(strategy.rank(&reversed), ranked, "{}", strategy);
+        }
+
+        // Words with the same number of buckets are sorted alphabetically.
+        let ranked = Strategy::Solve.rank(&reversed);
+        assert!(ranked
+            .windows(2)
+            .all(|w| w[0].1 > w[1].1 || w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_minimax() {
+        let wordlist: Wordlist = ["batch", "catch", "hatch", "latch", "trace"]
+            .iter()
+            .map(Word::from)
+            .collect();
+
+        let ranked = Strategy::Minimax.rank(&wordlist);
+
+        // The four "-atch" words all get the same colors for "trace".
+        assert_eq!(ranked[0], (&Word::from("batch"), 3));
+        assert_eq!(ranked.last(), Some(&(&Word::from("trace"), 4)));
+    }
+
+    #[test]
+    fn test_positional() {
+        let wordlist: Wordlist = ["crate", "trace", "grate", "crane", "geese"]
+            .iter()
+            .map(Word::from)
+            .collect();
+
+        let ranked = Strategy::Positional.rank(&wordlist);
+
+        // "crate" has the most frequent character at each position (like "grate").
+        assert_eq!(ranked[0], (&Word::from("crate"), 2 + 4 + 4 + 2 + 5));
+        assert_eq!(ranked.last().unwrap().0, &Word::from("geese"));
+    }
+
+    #[test]
+    fn test_lookahead() {
+        let wordlist: Wordlist = [
+            "batch", "catch", "hatch", "latch", "match", "crate", "trace",
+        ]
+        .iter()
+        .map(Word::from)
+        .collect();
+
+        let solve = Strategy::Solve.rank(&wordlist);
+        let ranked = Strategy::Lookahead { k: 3, depth: 2 }.rank(&wordlist);
+
+        assert_eq!(ranked.len(), wordlist.len());
+        assert_eq!(ranked[3..], solve[3..]);
+
+        let turns = |w: &Word| Lookahead::new(2, 5).expected_turns(w, &wordlist);
+        assert!(ranked[..3]
+            .windows(2)
+            .all(|w| turns(w[0].0) <= turns(w[1].0)));
+    }
+
+    #[test]
+    fn test_random() {
+        let wordlist: Wordlist = ["aaaab", "aaaac", "aaada", "bcdea", "bcdeb", "bcdec"]
+            .iter()
+            .map(Word::from)
+            .collect();
+
+        let best: Vec<_> = (0..20)
+            .map(|seed| Strategy::Random { seed }.best(&wordlist).unwrap())
+            .collect();
+
+        assert_eq!(Strategy::Random { seed: 3 }.best(&wordlist), Some(best[3]));
+        assert!(best.iter().unique().count() > 1);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("Survive".parse(), Ok(Strategy::Survive));
+        assert_eq!("random".parse(), Ok(Strategy::Random { seed: 0 }));
+        assert_eq!("random:seed=42".parse(), Ok(Strategy::Random { seed: 42 }));
+        assert_eq!(Strategy::Random { seed: 42 }.to_string(), "random:seed=42");
+        assert!("random:seed=x".parse::<Strategy>().is_err());
+        assert!("solve:seed=1".parse::<Strategy>().is_err());
+        assert_eq!(
+            "lookahead:k=3,depth=1".parse(),
+            Ok(Strategy::Lookahead { k: 3, depth: 1 })
+        );
+        assert_eq!(
+            "lookahead".parse::<Strategy>().unwrap().to_string(),
+            "lookahead"
+        );
+        assert_eq!(
+            Strategy::Lookahead { k: 3, depth: 2 }.to_string(),
+            "lookahead:k=3"
+        );
+        assert!("lookahead:k=0".parse::<Strategy>().is_err());
+        assert!("lookahead:k".parse::<Strategy>().is_err());
+        assert!("guess".parse::<Strategy>().is_err());
+    }
+
+    #[test]
+    fn test_pool() {
+        let dictionary: Wordlist = ["clubs", "catch", "latch", "batch", "hatch", "lucky"]
+            .iter()
+            .map(Word::from)
+            .collect();
+        let candidates: Wordlist = ["batch", "hatch"].iter().map(Word::from).collect();
+        let prior = Prior::uniform();
+
+        // "clubs" and the two candidates tell the candidates apart.
+        let probes = Pool::Probes.rank(Strategy::Solve, &dictionary, &candidates, &prior);
+        let words: Vec<_> = probes.iter().map(|(w, _)| w.to_string()).collect();
+        assert_eq!(
+            words,
+            ["batch", "clubs", "hatch", "catch", "latch", "lucky"]
+        );
+
+        let mixed = Pool::Mixed.rank(Strategy::Solve, &dictionary, &candidates, &prior);
+        let words: Vec<_> = mixed.iter().map(|(w, _)| w.to_string()).collect();
+        assert_eq!(
+            words,
+            ["batch", "hatch", "clubs", "catch", "latch", "lucky"]
+        );
+
+        let likely = TieBreak::Likely.apply(probes, &candidates, &prior);
+        assert_eq!(likely, mixed);
+
+        let survive = Pool::Probes.rank(Strategy::Survive, &dictionary, &candidates, &prior);
+        assert_eq!(survive.len(), candidates.len());
+
+        assert_eq!("Probes".parse(), Ok(Pool::Probes));
+        assert_eq!("likely".parse(), Ok(TieBreak::Likely));
+        assert!("all".parse::<Pool>().is_err());
+    }
+}
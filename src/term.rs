@@ -0,0 +1,178 @@
+//! Terminal input and output for the interactive mode.
+//!
+//! When stdin is a terminal, input is read key by key in raw mode via `crossterm` so that
+//! line editing behaves the same on every platform. Otherwise (e.g. when input is piped
+//! into `prompter`), whole lines are read from stdin. If a script has been set with
+//! [`set_script`], the input is taken from the script instead.
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead, IsTerminal, Write},
+    sync::Mutex,
+};
+
+use crossterm::{
+    cursor::{MoveLeft, MoveToColumn},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Print, Stylize},
+    terminal::{self, Clear, ClearType},
+};
+use prompter::Wordlist;
+
+/// Remaining input of the script set with [`set_script`].
+static SCRIPT: Mutex<Option<VecDeque<String>>> = Mutex::new(None);
+
+/// Takes all further input from `script` instead of stdin (see [`script_inputs`]).
+pub fn set_script(script: &str) {
+    *SCRIPT.lock().unwrap() = Some(script_inputs(script));
+}
+
+/// Splits `script` into inputs. A line starting with `:` is a command with its argument
+/// (e.g. `:top 3`) and therefore a single input; any other line may hold several inputs
+/// separated by whitespace, e.g. a guess and its color code. Empty lines and lines
+/// starting with `#` are skipped.
+fn script_inputs(script: &str) -> VecDeque<String> {
+    script
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .flat_map(|line| {
+            if line.starts_with(':') {
+                vec![line]
+            } else {
+                line.split_whitespace().collect()
+            }
+        })
+        .map(String::from)
+        .collect()
+}
+
+/// Switches the terminal to raw mode and back to normal mode when dropped.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Prints `prompt` and reads a line of input. Returns `None` if the input has ended or
+/// the user pressed `Ctrl-C` or `Ctrl-D`.
+///
+/// While typing, the first word in `completions` that starts with the input is suggested
+/// inline. `Tab` completes the input as far as it is unambiguous and `→` accepts the
+/// suggestion.
+pub fn read_line(prompt: &str, completions: Option<&Wordlist>) -> io::Result<Option<String>> {
+    let mut stdout = io::stdout();
+
+    execute!(stdout, Print(prompt))?;
+
+    if let Some(script) = SCRIPT.lock().unwrap().as_mut() {
+        let input = script.pop_front();
+
+        if let Some(input) = &input {
+            println!("{}", input);
+        }
+
+        return Ok(input);
+    }
+
+    if !io::stdin().is_terminal() {
+        let mut buffer = String::new();
+
+        if io::stdin().lock().read_line(&mut buffer)? == 0 {
+            return Ok(None);
+        }
+
+        return Ok(Some(buffer.trim().to_string()));
+    }
+
+    let _raw_mode = RawMode::enable()?;
+    let mut buffer = String::new();
+    let start_column = prompt.chars().count() as u16;
+
+    loop {
+        let hint = completions
+            .and_then(|words| words.starting_with(&buffer).next())
+            .filter(|_| !buffer.is_empty())
+            .map(|w| w.to_string()[buffer.len()..].to_string())
+            .unwrap_or_default();
+
+        queue!(
+            stdout,
+            MoveToColumn(start_column),
+            Clear(ClearType::UntilNewLine),
+            Print(&buffer),
+            Print(hint.as_str().dark_grey())
+        )?;
+
+        if !hint.is_empty() {
+            queue!(stdout, MoveLeft(hint.chars().count() as u16))?;
+        }
+
+        stdout.flush()?;
+
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press | KeyEventKind::Repeat,
+            ..
+        }) = event::read()?
+        else {
+            continue;
+        };
+
+        match code {
+            KeyCode::Enter => {
+                execute!(stdout, Clear(ClearType::UntilNewLine), Print("\r\n"))?;
+                return Ok(Some(buffer.trim().to_string()));
+            }
+            KeyCode::Char('c' | 'd') if modifiers.contains(KeyModifiers::CONTROL) => {
+                execute!(stdout, Print("\r\n"))?;
+                return Ok(None);
+            }
+            KeyCode::Char('u') if modifiers.contains(KeyModifiers::CONTROL) => buffer.clear(),
+            KeyCode::Char(c) => buffer.push(c),
+            KeyCode::Backspace => {
+                buffer.pop();
+            }
+            KeyCode::Right | KeyCode::End => buffer.push_str(&hint),
+            KeyCode::Tab => {
+                if let Some(words) = completions {
+                    buffer = words.common_prefix(&buffer);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_inputs() {
+        let script = "# a game\n:top 3\ncrate _Y__G\n\n  :save  game.toml \nlist\nhorse\nGGGGG\n";
+
+        assert_eq!(
+            script_inputs(script),
+            [
+                ":top 3",
+                "crate",
+                "_Y__G",
+                ":save  game.toml",
+                "list",
+                "horse",
+                "GGGGG"
+            ]
+        );
+    }
+}
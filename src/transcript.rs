@@ -0,0 +1,218 @@
+//! Transcripts of interactive games, recorded with `--record DIR`.
+//!
+//! Each game is written to its own JSON file in the directory, named after the time at which
+//! the game was started (e.g. `game-1700000000.json`). The file is updated after every
+//! round, so a game that is interrupted is still recorded up to the last round played.
+//!
+//! # Format
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "started": 1700000000,
+//!   "preset": "wordle",
+//!   "word_len": 5,
+//!   "hard": false,
+//!   "rounds": [
+//!     {
+//!       "candidates": 2314,
+//!       "suggestions": [{ "word": "raise", "score": 132 }, { "word": "slate", "score": 131 }],
+//!       "guess": "crate",
+//!       "code": "Y_G__"
+//!     }
+//!   ],
+//!   "outcome": "unfinished"
+//! }
+//! ```
+//!
+//! * `started` is the time at which the game was started in seconds since the Unix epoch.
+//! * `rounds` lists the rounds in the order in which they were played. A round that was
+//!   undone is removed again. `candidates` is the number of words that were still possible
+//!   before the guess, `suggestions` are the words suggested to the player with their
+//!   scores (empty for the rounds of a resumed game), and `code` is Wordle's feedback with
+//!   `G` = Green, `Y` = Yellow and `_` = Gray.
+//! * `outcome` is one of `unfinished`, `won`, `lost` (all rounds played without finding the
+//!   word) and `no_words_left` (no word matches the feedback).
+#[cfg(feature = "std-fs")]
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use std::{
+    io,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Version of the transcript format.
+pub const VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// The record of one interactive game
+pub struct Transcript {
+    /// Version of the format.
+    pub version: u32,
+    /// Seconds since the Unix epoch at which the game was started.
+    pub started: u64,
+    /// Name of the preset that was played.
+    pub preset: String,
+    /// Number of characters per word.
+    pub word_len: usize,
+    /// Whether the game was played in hard mode.
+    pub hard: bool,
+    /// The rounds played so far.
+    pub rounds: Vec<Round>,
+    /// How the game ended.
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// One round of a recorded game
+pub struct Round {
+    /// Number of words that were possible before the guess.
+    pub candidates: usize,
+    /// The words suggested to the player.
+    pub suggestions: Vec<Suggestion>,
+    /// The word guessed.
+    pub guess: String,
+    /// Wordle's feedback for the guess, e.g. `Y_G__`.
+    pub code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A word suggested to the player together with its score
+pub struct Suggestion {
+    /// The suggested word.
+    pub word: String,
+    /// The score of the word.
+    pub score: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+/// How a recorded game ended
+pub enum Outcome {
+    #[default]
+    /// The game has not been finished (yet).
+    Unfinished,
+    /// The word was found.
+    Won,
+    /// All rounds were played without finding the word.
+    Lost,
+    /// No word matches the feedback.
+    NoWordsLeft,
+}
+
+impl Transcript {
+    /// Starts the transcript of a game that is started now.
+    pub fn new(preset: &str, word_len: usize, hard: bool) -> Self {
+        let started = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            version: VERSION,
+            started,
+            preset: preset.to_string(),
+            word_len,
+            hard,
+            rounds: vec![],
+            outcome: Outcome::default(),
+        }
+    }
+
+    /// Returns the transcript as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("transcripts can always be serialized")
+    }
+
+    /// Reads a transcript from JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use prompter::transcript::{Outcome, Transcript};
+    /// let mut transcript = Transcript::new("wordle", 5, false);
+    /// transcript.outcome = Outcome::Won;
+    ///
+    /// assert_eq!(Transcript::from_json(&transcript.to_json()).unwrap(), transcript);
+    /// assert!(Transcript::from_json("{}").is_err());
+    /// ```
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        let transcript: Self = serde_json::from_str(json)?;
+
+        if transcript.version != VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Unsupported transcript version {}", transcript.version),
+            ));
+        }
+
+        Ok(transcript)
+    }
+}
+
+#[cfg(feature = "std-fs")]
+impl Transcript {
+    /// Returns a path in `dir` for the transcript that does not exist yet.
+    pub fn new_path<P: AsRef<Path>>(&self, dir: P) -> PathBuf {
+        let dir = dir.as_ref();
+        let mut path = dir.join(format!("game-{}.json", self.started));
+
+        // Games started in the same second get a counter.
+        for i in 2.. {
+            if !path.exists() {
+                break;
+            }
+
+            path = dir.join(format!("game-{}-{}.json", self.started, i));
+        }
+
+        path
+    }
+
+    /// Loads the transcript at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Writes the transcript to `path`, creating its directory if necessary.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, self.to_json())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let mut transcript = Transcript::new("wordle", 5, true);
+        transcript.rounds.push(Round {
+            candidates: 2314,
+            suggestions: vec![Suggestion {
+                word: "raise".to_string(),
+                score: 132,
+            }],
+            guess: "crate".to_string(),
+            code: "Y_G__".to_string(),
+        });
+        transcript.outcome = Outcome::NoWordsLeft;
+
+        let json = transcript.to_json();
+
+        assert!(json.contains("\"outcome\": \"no_words_left\""));
+        assert!(json.contains("\"code\": \"Y_G__\""));
+        assert_eq!(Transcript::from_json(&json).unwrap(), transcript);
+        assert!(Transcript::from_json(&json.replace("\"version\": 1", "\"version\": 2")).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+//! [`wasm-bindgen`](https://rustwasm.github.io/wasm-bindgen/) wrappers for running the
+//! solver in the browser.
+//!
+//! Build with `wasm-pack build --target web -- --no-default-features --features wasm`.
+use wasm_bindgen::prelude::*;
+
+use crate::{preset::Preset, render};
+
+#[wasm_bindgen]
+/// A [`Solver`](crate::solver::Solver) with the embedded wordlist of a preset
+pub struct Solver {
+    inner: crate::solver::Solver,
+}
+
+#[wasm_bindgen]
+/// A suggested word and its score
+pub struct Suggestion {
+    word: String,
+    score: usize,
+}
+
+#[wasm_bindgen]
+impl Suggestion {
+    /// The suggested word.
+    #[wasm_bindgen(getter)]
+    pub fn word(&self) -> String {
+        self.word.clone()
+    }
+
+    /// The number of buckets into which the word splits the candidates.
+    #[wasm_bindgen(getter)]
+    pub fn score(&self) -> usize {
+        self.score
+    }
+}
+
+#[wasm_bindgen]
+impl Solver {
+    /// Creates a solver for the preset with the given name (e.g. `wordle`).
+    #[wasm_bindgen(constructor)]
+    pub fn new(preset: &str) -> Result<Solver, JsError> {
+        let preset: Preset = preset.parse().map_err(|e: String| JsError::new(&e))?;
+
+        Ok(Self {
+            inner: crate::solver::Solver::new(preset.wordlist(), preset.word_len()),
+        })
+    }
+
+    /// Applies Wordle's color `code` (e.g. `Y_G_G`) for `guess`.
+    pub fn apply(&mut self, guess: &str, code: &str) -> Result<(), JsError> {
+        self.inner.apply(guess, code).map_err(JsError::from)
+    }
+
+    /// Returns the number of words that are still possible.
+    #[wasm_bindgen(js_name = candidateCount)]
+    pub fn candidate_count(&self) -> usize {
+        self.inner.candidates().len()
+    }
+
+    /// Returns the words that are still possible.
+    pub fn candidates(&self) -> Vec<String> {
+        self.inner
+            .candidates()
+            .iter()
+            .map(|w| w.to_string())
+            .collect()
+    }
+
+    /// Returns the `n` best-ranked candidates.
+    pub fn suggest(&self, n: usize) -> Vec<Suggestion> {
+        self.inner
+            .suggest(n)
+            .into_iter()
+            .map(|(w, score)| Suggestion {
+                word: w.to_string(),
+                score,
+            })
+            .collect()
+    }
+
+    /// Returns the board with all guesses so far, marked with symbols.
+    pub fn board(&self) -> String {
+        render::render_board(self.inner.history(), render::Style::Ascii)
+    }
+
+    /// Returns `true` if the last guess was the mystery word.
+    #[wasm_bindgen(js_name = isSolved)]
+    pub fn is_solved(&self) -> bool {
+        self.inner.is_solved()
+    }
+}
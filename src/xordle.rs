@@ -0,0 +1,304 @@
+//! Games of Xordle, where two mystery words without common letters share a single board.
+//!
+//! Each position of Wordle's color code is the "best" color that the guessed character
+//! gets for either of the two words: green if it is at this position in one of the words,
+//! yellow if it is in one of the words at another position and gray otherwise.
+use crate::{
+    alphabet::lowercase_word,
+    feedback::{self, Feedback},
+    ConstraintSet, Error, Word, Wordlist,
+};
+
+/// Maximum length of the words, which is limited by the size of the color code bitmasks.
+pub const MAX_WORD_LEN: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// A color code as bitmasks of the positions that are green and of the positions that are
+/// green or yellow
+struct Code {
+    green: u32,
+    present: u32,
+}
+
+impl Code {
+    /// Combines the codes for the two mystery words.
+    fn union(self, other: Code) -> Code {
+        Code {
+            green: self.green | other.green,
+            present: self.present | other.present,
+        }
+    }
+
+    /// Returns a unique index for codes of words with `len` characters.
+    fn index(self, len: usize) -> usize {
+        (self.green | self.present << len) as usize
+    }
+}
+
+impl From<&[Feedback]> for Code {
+    fn from(feedback: &[Feedback]) -> Self {
+        let mut result = Code::default();
+
+        // Longer words are rejected by the game, so the positions beyond never count.
+        for (i, color) in feedback.iter().take(MAX_WORD_LEN).enumerate() {
+            match color {
+                Feedback::Green => {
+                    result.green |= 1 << i;
+                    result.present |= 1 << i;
+                }
+                Feedback::Yellow => result.present |= 1 << i,
+                Feedback::Gray => {}
+            }
+        }
+
+        result
+    }
+}
+
+impl From<&ConstraintSet> for Code {
+    fn from(constraints: &ConstraintSet) -> Self {
+        Code::from(constraints.feedback().as_slice())
+    }
+}
+
+/// Returns the color code that Xordle would present for the guess `w` if `first` and
+/// `second` are the mystery words, or [`Error::UnsupportedWordLength`] if `w` has more than
+/// [`MAX_WORD_LEN`] characters.
+///
+/// # Examples
+///
+/// ```
+/// # use prompter::{xordle, Error, Word};
+/// let guess = Word::from("crate");
+///
+/// assert_eq!(xordle::match_code(&guess, &Word::from("spoon"), &Word::from("track")).unwrap(), "YGGY_");
+/// assert_eq!(xordle::match_code(&guess, &Word::from("crate"), &Word::from("spoon")).unwrap(), "GGGGG");
+///
+/// let long = Word::from("abcdefghijklm");
+/// assert_eq!(xordle::match_code(&long, &long, &guess), Err(Error::UnsupportedWordLength(13)));
+/// ```
+pub fn match_code(w: &Word, first: &Word, second: &Word) -> Result<String, Error> {
+    let len = w.chars().count();
+
+    if len > MAX_WORD_LEN {
+        return Err(Error::UnsupportedWordLength(len));
+    }
+
+    let code =
+        Code::from(w.feedback(first).as_slice()).union(Code::from(w.feedback(second).as_slice()));
+
+    let feedback: Vec<_> = (0..len)
+        .map(|i| match (code.green >> i & 1, code.present >> i & 1) {
+            (1, _) => Feedback::Green,
+            (_, 1) => Feedback::Yellow,
+            _ => Feedback::Gray,
+        })
+        .collect();
+
+    Ok(feedback::to_code(&feedback))
+}
+
+#[derive(Debug, Clone)]
+/// A game of Xordle that keeps track of the pairs of words that are still possible
+pub struct Xordle {
+    /// Words that the pairs are made of.
+    words: Vec<Word>,
+    /// Pairs of indices into `words` that are still possible.
+    pairs: Vec<(usize, usize)>,
+    /// Mystery words that have been found.
+    found: Vec<String>,
+}
+
+impl Xordle {
+    /// Creates a game in which the mystery words are any two words in `wordlist` that do
+    /// not have any characters in common. Returns an error if a word is longer than
+    /// [`MAX_WORD_LEN`].
+    pub fn new(wordlist: &Wordlist) -> Result<Self, Error> {
+        if let Some(len) = wordlist
+            .iter()
+            .map(|w| w.chars().count())
+            .find(|&len| len > MAX_WORD_LEN)
+        {
+            return Err(Error::UnsupportedWordLength(len));
+        }
+
+        let words: Vec<_> = wordlist.iter().cloned().collect();
+        let mut pairs = vec![];
+
+        for (i, w1) in words.iter().enumerate() {
+            for (j, w2) in words.iter().enumerate().skip(i + 1) {
+                if !w1.chars().any(|c| w2.contains(c)) {
+                    pairs.push((i, j));
+                }
+            }
+        }
+
+        Ok(Self {
+            words,
+            pairs,
+            found: vec![],
+        })
+    }
+
+    /// Returns an iterator over the pairs of words that are still possible.
+    pub fn pairs(&self) -> impl Iterator<Item = (&Word, &Word)> {
+        self.pairs
+            .iter()
+            .map(|(i, j)| (&self.words[*i], &self.words[*j]))
+    }
+
+    /// Returns the number of pairs of words that are still possible.
+    pub fn pair_count(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns the mystery words that have been found so far.
+    pub fn found(&self) -> &[String] {
+        &self.found
+    }
+
+    /// Returns `true` if both mystery words have been found.
+    pub fn is_won(&self) -> bool {
+        self.found.len() == 2
+    }
+
+    /// Applies the feedback that Xordle presented for `guess`.
+    pub fn apply(&mut self, guess: &str, constraints: &ConstraintSet) {
+        let guess = Word::from(lowercase_word(guess));
+        let code = Code::from(constraints);
+        let codes = self.codes(&guess);
+
+        self.pairs
+            .retain(|(i, j)| codes[*i].union(codes[*j]) == code);
+
+        if constraints.correct_word() && !self.found.contains(&guess.to_string()) {
+            self.found.push(guess.to_string());
+        }
+    }
+
+    /// Ranks the words in `guesses` by the number of different color codes they can
+    /// get for the pairs that are still possible and returns pairs of word references and
+    /// scores sorted by the score in descending order. Words that can be one of the mystery
+    /// words come first among words with the same score, then the words are sorted
+    /// alphabetically.
+    pub fn rank_words<'a>(&self, guesses: &'a Wordlist) -> Vec<(&'a Word, usize)> {
+        let candidates: Vec<_> = self.candidates().collect();
+        let mut seen = vec![];
+
+        let mut ranked: Vec<_> = guesses
+            .iter()
+            .map(|w| {
+                // The codes of longer guesses only cover the characters of the words.
+                let len = w.chars().count().min(MAX_WORD_LEN);
+                let codes = self.codes(w);
+
+                seen.clear();
+                seen.resize(1 << (2 * len), false);
+
+                let mut score = 0;
+
+                for (i, j) in &self.pairs {
+                    let index = codes[*i].union(codes[*j]).index(len);
+
+                    if !seen[index] {
+                        seen[index] = true;
+                        score += 1;
+                    }
+                }
+
+                let hit = candidates.contains(&w);
+
+                (w, score, hit)
+            })
+            .collect();
+
+        ranked.sort_by_key(|&(w, score, hit)| (std::cmp::Reverse((score, hit)), w));
+
+        ranked.into_iter().map(|(w, score, _)| (w, score)).collect()
+    }
+
+    /// Returns an iterator over the words that are part of a possible pair and have not
+    /// been found yet.
+    fn candidates(&self) -> impl Iterator<Item = &Word> {
+        let mut used = vec![false; self.words.len()];
+
+        for (i, j) in &self.pairs {
+            used[*i] = true;
+            used[*j] = true;
+        }
+
+        self.words
+            .iter()
+            .zip(used)
+            .filter(|(w, used)| *used && !self.found.contains(&w.to_string()))
+            .map(|(w, _)| w)
+    }
+
+    /// Returns the codes for `guess` and each word.
+    fn codes(&self, guess: &Word) -> Vec<Code> {
+        self.words
+            .iter()
+            .map(|w| Code::from(guess.feedback(w).as_slice()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wordlist(words: &[&str]) -> Wordlist {
+        words.iter().map(Word::from).collect()
+    }
+
+    fn constraints(guess: &str, first: &str, second: &str) -> ConstraintSet {
+        let code = match_code(&Word::from(guess), &Word::from(first), &Word::from(second)).unwrap();
+        ConstraintSet::try_from((guess, code.as_str())).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let game = Xordle::new(&wordlist(&["crate", "trace", "spoon", "lumpy"])).unwrap();
+
+        // "spoon" and "lumpy" share the "p".
+        assert_eq!(game.pair_count(), 4);
+    }
+
+    #[test]
+    fn test_apply() {
+        let words = wordlist(&["crate", "trace", "spoon", "lumpy", "dingy"]);
+        let mut game = Xordle::new(&words).unwrap();
+
+        game.apply("spoon", &constraints("spoon", "crate", "lumpy"));
+
+        assert!(game
+            .pairs()
+            .any(|(w1, w2)| w1.to_string() == "crate" && w2.to_string() == "lumpy"));
+        assert!(game
+            .pairs()
+            .all(|(w1, w2)| !w1.contains('o') && !w2.contains('o')));
+
+        game.apply("crate", &constraints("crate", "crate", "lumpy"));
+        assert_eq!(game.found(), ["crate"]);
+        assert!(!game.is_won());
+
+        game.apply("lumpy", &constraints("lumpy", "crate", "lumpy"));
+        assert!(game.is_won());
+        assert_eq!(game.pair_count(), 1);
+    }
+
+    #[test]
+    fn test_rank_words() {
+        let words = wordlist(&["crate", "spoon", "lumpy", "dingy"]);
+        let game = Xordle::new(&words).unwrap();
+
+        let ranked = game.rank_words(&words);
+
+        assert_eq!(ranked.len(), 4);
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        // Guesses that are longer than the words are ranked without overflowing the codes.
+        let long = wordlist(&["abcdefghijklmnopqrstuvwxyzabcdefgh"]);
+        assert_eq!(game.rank_words(&long).len(), 1);
+    }
+}